@@ -0,0 +1,32 @@
+//! Helpers shared by all benchmarks
+
+use std::time::{Duration, Instant};
+
+/// Minimal time spent measuring every benchmark
+const MEASUREMENT_TIME: Duration = Duration::from_secs(2);
+
+/// Measures and prints average running time of `f`
+///
+/// Benchmark is skipped if a filter is passed via command line (eg. `cargo bench --bench vss --
+/// reconstruct`) and `name` doesn't contain it, in which case `None` is returned.
+pub fn bench<R>(name: &str, mut f: impl FnMut() -> R) -> Option<Duration> {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    if matches!(filter, Some(filter) if !name.contains(filter.as_str())) {
+        return None;
+    }
+    // Warm up
+    std::hint::black_box(f());
+
+    let mut iterations = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < MEASUREMENT_TIME {
+        std::hint::black_box(f());
+        iterations += 1;
+    }
+    let per_iteration = start.elapsed() / iterations;
+    println!(
+        "{:<40} {:>12?} ({} iterations)",
+        name, per_iteration, iterations
+    );
+    Some(per_iteration)
+}
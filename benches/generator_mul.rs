@@ -0,0 +1,46 @@
+//! Benchmarks fixed-base multiplication against variable-base multiplication of the generator
+//!
+//! TO RUN:
+//! cargo bench --bench generator_mul
+//!
+//! Optionally, pass a filter to run only matching benchmarks:
+//! cargo bench --bench generator_mul -- fixed_base
+
+use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use curv::elliptic::curves::*;
+use sha2::Sha256;
+
+mod common;
+use common::bench;
+
+fn bench_curve<E: Curve>() {
+    let scalar = E::Scalar::random();
+    let variable = bench(&format!("variable_base/{}", E::CURVE_NAME), || {
+        E::Point::generator().scalar_mul(&scalar)
+    });
+    let fixed = bench(&format!("fixed_base/{}", E::CURVE_NAME), || {
+        E::Point::generator_mul(&scalar)
+    });
+    if let (Some(variable), Some(fixed)) = (variable, fixed) {
+        println!(
+            "{:<40} {:>11.1}x",
+            format!("speedup/{}", E::CURVE_NAME),
+            variable.as_secs_f64() / fixed.as_secs_f64()
+        );
+    }
+
+    // Commitments to polynomial coefficients are computed via fixed-base multiplication
+    let secret = Scalar::<E>::random();
+    bench(&format!("vss_share/{}/33-of-100", E::CURVE_NAME), || {
+        VerifiableSS::<E, Sha256>::share(33, 100, &secret)
+    });
+}
+
+fn main() {
+    bench_curve::<Secp256k1>();
+    bench_curve::<Secp256r1>();
+    bench_curve::<Ed25519>();
+    bench_curve::<Ristretto>();
+    bench_curve::<Bls12_381_1>();
+    bench_curve::<Bls12_381_2>();
+}
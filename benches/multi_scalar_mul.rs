@@ -0,0 +1,35 @@
+//! Benchmarks serial multi scalar multiplication against the parallel one
+//!
+//! TO RUN:
+//! cargo bench --features parallel --bench multi_scalar_mul
+//!
+//! Without `parallel` feature both measurements are serial.
+
+use curv::elliptic::curves::{Curve, ECPoint, Ed25519, Point, Scalar, Secp256k1, Secp256r1};
+
+mod common;
+use common::bench;
+
+const N: usize = 512;
+
+fn bench_curve<E: Curve>() {
+    let points: Vec<Point<E>> = (0..N)
+        .map(|_| Point::generator() * Scalar::random())
+        .collect();
+    let scalars: Vec<Scalar<E>> = (0..N).map(|_| Scalar::random()).collect();
+    let raw_points: Vec<E::Point> = points.iter().map(|p| p.as_raw().clone()).collect();
+    let raw_scalars: Vec<E::Scalar> = scalars.iter().map(|s| s.as_raw().clone()).collect();
+
+    bench(&format!("serial/{}/{}", E::CURVE_NAME, N), || {
+        E::Point::multi_scalar_mul(&raw_points, &raw_scalars)
+    });
+    bench(&format!("parallel/{}/{}", E::CURVE_NAME, N), || {
+        Point::multi_scalar_mul(&points, &scalars)
+    });
+}
+
+fn main() {
+    bench_curve::<Secp256k1>();
+    bench_curve::<Secp256r1>();
+    bench_curve::<Ed25519>();
+}
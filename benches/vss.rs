@@ -0,0 +1,60 @@
+//! Benchmarks of Feldman VSS hot paths
+//!
+//! TO RUN:
+//! cargo bench --bench vss
+//!
+//! Optionally, pass a filter to run only matching benchmarks:
+//! cargo bench --bench vss -- reconstruct
+
+use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use curv::cryptographic_primitives::secret_sharing::Polynomial;
+use curv::elliptic::curves::{Scalar, Secp256k1};
+use sha2::Sha256;
+
+mod common;
+use common::bench;
+
+type Vss = VerifiableSS<Secp256k1, Sha256>;
+
+/// Threshold and number of parties
+const SIZES: &[(u16, u16)] = &[(5, 10), (33, 100), (67, 200)];
+
+/// Polynomial degree and number of evaluation points
+const EVALUATION_SIZES: &[(u16, u64)] = &[(500, 1000), (2000, 4000), (4000, 8000)];
+
+fn main() {
+    for &(t, n) in SIZES {
+        let secret = Scalar::<Secp256k1>::random();
+        let (vss, shares) = Vss::share(t, n, &secret);
+        let indices: Vec<u16> = (0..=t).collect();
+        let subset: Vec<_> = indices
+            .iter()
+            .map(|&i| shares[usize::from(i)].clone())
+            .collect();
+
+        bench(&format!("share/{}-of-{}", t, n), || {
+            Vss::share(t, n, &secret)
+        });
+        bench(&format!("reconstruct/{}-of-{}", t, n), || {
+            vss.reconstruct(&indices, &subset).unwrap()
+        });
+        bench(&format!("validate_share/{}-of-{}", t, n), || {
+            vss.validate_share(&shares[0], 1).unwrap()
+        });
+        bench(&format!("map_share_to_new_params/{}-of-{}", t, n), || {
+            Vss::map_share_to_new_params(&vss.parameters, 0, &indices)
+        });
+    }
+
+    for &(degree, n) in EVALUATION_SIZES {
+        let polynomial = Polynomial::<Secp256k1>::sample_exact(degree);
+        let points: Vec<_> = (1..=n).map(Scalar::from).collect();
+
+        bench(&format!("evaluate_horner/{}-at-{}", degree, n), || {
+            polynomial.evaluate_many(&points).collect::<Vec<_>>()
+        });
+        bench(&format!("evaluate_multi/{}-at-{}", degree, n), || {
+            Vss::evaluate_multi(polynomial.coefficients(), &points)
+        });
+    }
+}
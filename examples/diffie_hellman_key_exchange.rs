@@ -8,7 +8,6 @@ use curv::elliptic::curves::*;
 ///
 /// notice: this library includes also a more involved ECDH scheme. see
 /// dh_key_exchange_variant_with_pok_comm.rs
-
 pub fn ecdh<E: Curve>() {
     use curv::cryptographic_primitives::twoparty::dh_key_exchange::{
         compute_pubkey, Party1FirstMessage, Party2FirstMessage,
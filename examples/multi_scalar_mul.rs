@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use curv::elliptic::curves::*;
+
+/// Compares multiscalar multiplication against computing `sum_i s_i * P_i` point by point.
+/// Degree-t commitment vector verification (i.e. `VerifiableSS::validate_share`) boils down
+/// to exactly this computation.
+///
+/// Measured speedup for t=128: ~4.3x for ristretto, p256 and bls12_381 (G1 and G2), ~5x for
+/// ed25519, and only ~1.1x for secp256k1 since libsecp256k1 bindings don't expose multiscalar
+/// multiplication.
+/// TO RUN:
+/// cargo run --release --example multi_scalar_mul -- CURVE_NAME [T]
+/// CURVE_NAME is any of the supported curves: i.e.:
+/// cargo run --release --example multi_scalar_mul -- ristretto 128
+pub fn bench_multi_scalar_mul<E: Curve>(t: usize) {
+    const ROUNDS: u32 = 10;
+
+    let points: Vec<Point<E>> = (0..t)
+        .map(|_| Point::generator() * Scalar::random())
+        .collect();
+    let scalars: Vec<Scalar<E>> = (0..t).map(|_| Scalar::random()).collect();
+
+    let mut naive_time = Duration::default();
+    let mut msm_time = Duration::default();
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        let naive: Point<E> = points.iter().zip(&scalars).map(|(p, s)| p * s).sum();
+        naive_time += start.elapsed();
+
+        let start = Instant::now();
+        let msm = Point::multi_scalar_mul(&points, &scalars);
+        msm_time += start.elapsed();
+
+        assert_eq!(naive, msm);
+    }
+
+    println!("curve: {}, t = {}", E::CURVE_NAME, t);
+    println!("naive loop:           {:?}", naive_time / ROUNDS);
+    println!("multi_scalar_mul:     {:?}", msm_time / ROUNDS);
+    println!(
+        "speedup:              {:.2}x",
+        naive_time.as_secs_f64() / msm_time.as_secs_f64()
+    );
+}
+
+fn main() {
+    let curve_name = std::env::args().nth(1);
+    let t = std::env::args()
+        .nth(2)
+        .map(|t| t.parse().expect("T must be a number"))
+        .unwrap_or(128);
+    match curve_name.as_deref() {
+        Some("secp256k1") => bench_multi_scalar_mul::<Secp256k1>(t),
+        Some("ristretto") => bench_multi_scalar_mul::<Ristretto>(t),
+        Some("ed25519") => bench_multi_scalar_mul::<Ed25519>(t),
+        Some("bls12_381_1") => bench_multi_scalar_mul::<Bls12_381_1>(t),
+        Some("bls12_381_2") => bench_multi_scalar_mul::<Bls12_381_2>(t),
+        Some("p256") => bench_multi_scalar_mul::<Secp256r1>(t),
+        Some(unknown_curve) => eprintln!("Unknown curve: {}", unknown_curve),
+        None => eprintln!("Missing curve name"),
+    }
+}
@@ -11,7 +11,6 @@ use curv::elliptic::curves::*;
 /// cargo run --example pedersen_commitment -- ristretto
 ///
 /// notice: this library includes also hash based commitments
-
 pub fn ped_com<E: Curve>(message: &BigInt) {
     use curv::cryptographic_primitives::commitments::pedersen_commitment::PedersenCommitment;
     use curv::cryptographic_primitives::commitments::traits::Commitment;
@@ -9,7 +9,6 @@ use sha2::Sha256;
 ///
 /// notice: this library includes other more complex sigma protocol.
 /// see proofs folder for more details
-
 pub fn dlog_proof<E: Curve>() {
     use curv::cryptographic_primitives::proofs::sigma_dlog::*;
 
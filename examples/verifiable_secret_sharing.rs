@@ -4,14 +4,13 @@ use sha2::Sha256;
 /// secret_sharing_3_out_of_5
 /// Feldman VSS, based on  Paul Feldman. 1987. A practical scheme for non-interactive verifiable secret sharing.
 /// In Foundations of Computer Science, 1987., 28th Annual Symposium on.IEEE, 427–43
-
+///
 /// implementation details: The code is using FE and GE. Each party is given an index from 1,..,n and a secret share of type FE.
 /// The index of the party is also the point on the polynomial where we treat this number as u32 but converting it to FE internally.
 /// TO RUN:
 /// cargo run --example verifiable_secret_sharing -- CURVE_NAME
 /// CURVE_NAME is any of the supported curves: i.e.:
 /// cargo run --example verifiable_secret_sharing -- ed25519
-
 pub fn secret_sharing_3_out_of_5<E: Curve>() {
     use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
 
@@ -27,7 +26,7 @@ pub fn secret_sharing_3_out_of_5<E: Curve>() {
     ];
     //test reconstruction
 
-    let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 2, 4], &shares_vec);
+    let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 2, 4], &shares_vec).unwrap();
 
     assert_eq!(secret, secret_reconstructed);
     // test secret shares are verifiable
@@ -42,7 +41,7 @@ pub fn secret_sharing_3_out_of_5<E: Curve>() {
     assert!(valid1_public.is_ok());
 
     // test map (t,n) - (t',t')
-    let s = &vec![0, 1, 2, 3, 4];
+    let s = &[0, 1, 2, 3, 4];
     let l0 = VerifiableSS::<E, Sha256>::map_share_to_new_params(&vss_scheme.parameters, 0, s);
     let l1 = VerifiableSS::<E, Sha256>::map_share_to_new_params(&vss_scheme.parameters, 1, s);
     let l2 = VerifiableSS::<E, Sha256>::map_share_to_new_params(&vss_scheme.parameters, 2, s);
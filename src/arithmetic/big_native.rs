@@ -170,6 +170,9 @@ impl Modulo for BigInt {
     }
 
     fn mod_inv(a: &Self, modulus: &Self) -> Option<Self> {
+        if modulus.test_bit(0) && *modulus > BigInt::one() {
+            return super::binary_gcd::mod_inv_odd(a, modulus);
+        }
         ring_algorithms::modulo_inverse(a, modulus).map(|inv| inv.modulus(modulus))
     }
 
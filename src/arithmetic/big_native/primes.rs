@@ -503,7 +503,7 @@ fn is_bit_set(x: &BigUint, i: usize) -> bool {
 fn gen_biguint_below<R: Rng>(r: &mut R, upper: &BigUint) -> BigUint {
     loop {
         let bits = upper.bits();
-        let bytes = bits.div_ceil(&8);
+        let bytes = Integer::div_ceil(&bits, &8);
         let mut buf = vec![0u8; bytes as usize];
         r.fill_bytes(&mut buf);
 
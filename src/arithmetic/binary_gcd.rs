@@ -0,0 +1,122 @@
+//! Constant-time modular inversion via binary extended GCD
+
+use super::traits::*;
+use super::BigInt;
+
+/// Computes `a^-1 mod m` for odd `m > 1`, or returns `None` if `a` and `m` are not coprimes
+///
+/// Implements binary extended GCD by Niels Möller (the one behind GMP `mpn_sec_invert`). Numbers
+/// are kept in fixed amount of 64-bit limbs determined by bit length of `m`, and algorithm does a
+/// fixed number of iterations, each performing the same limb operations regardless of values. So
+/// running time depends only on bit length of `m`, but not on `a` (apart from its reduction modulo
+/// `m` which is done by the backend).
+pub(super) fn mod_inv_odd(a: &BigInt, m: &BigInt) -> Option<BigInt> {
+    debug_assert!(m.test_bit(0) && *m > BigInt::one());
+    let width = m.bit_length().div_ceil(64);
+    let modulus = to_limbs(m, width);
+
+    // a ≡ u·x (mod m) and b ≡ v·x (mod m) hold on every iteration, b is always odd
+    let mut a = to_limbs(&a.modulus(m), width);
+    let mut b = modulus.clone();
+    let mut u = to_limbs(&BigInt::one(), width);
+    let mut v = vec![0; width];
+
+    // Every iteration decreases total bit length of a and b at least by one, until a = 0
+    for _ in 0..2 * 64 * width {
+        let a_is_odd = (a[0] & 1).wrapping_neg();
+        let swap = a_is_odd & lt_mask(&a, &b);
+        cswap(&mut a, &mut b, swap);
+        cswap(&mut u, &mut v, swap);
+
+        // If a is odd, then a ≥ b at this point: (a, u) = (a - b, u - v)
+        sub_masked(&mut a, &b, a_is_odd);
+        let borrow = sub_masked(&mut u, &v, a_is_odd);
+        add_masked(&mut u, &modulus, borrow.wrapping_neg());
+
+        // (a, u) = (a / 2, u / 2 mod m)
+        shr1(&mut a, 0);
+        let u_is_odd = (u[0] & 1).wrapping_neg();
+        let carry = add_masked(&mut u, &modulus, u_is_odd);
+        shr1(&mut u, carry);
+    }
+
+    // a = 0, b = gcd(x, m)
+    if b[0] == 1 && b[1..].iter().all(|&limb| limb == 0) {
+        Some(from_limbs(&v))
+    } else {
+        None
+    }
+}
+
+/// Converts non-negative `x < 2^(64·width)` to little-endian limbs
+fn to_limbs(x: &BigInt, width: usize) -> Vec<u64> {
+    let bytes = x.to_bytes();
+    let mut limbs = vec![0; width];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        limbs[i / 8] |= u64::from(*byte) << (8 * (i % 8));
+    }
+    limbs
+}
+
+fn from_limbs(limbs: &[u64]) -> BigInt {
+    let bytes: Vec<u8> = limbs
+        .iter()
+        .rev()
+        .flat_map(|limb| limb.to_be_bytes())
+        .collect();
+    BigInt::from_bytes(&bytes)
+}
+
+/// Returns all-ones mask if `a < b`, and zero otherwise
+fn lt_mask(a: &[u64], b: &[u64]) -> u64 {
+    let mut borrow = 0;
+    for (x, y) in a.iter().zip(b) {
+        let (d, b1) = x.overflowing_sub(*y);
+        let (_, b2) = d.overflowing_sub(borrow);
+        borrow = u64::from(b1 | b2);
+    }
+    borrow.wrapping_neg()
+}
+
+/// Subtracts `b & mask` from `a`, returns borrow (0 or 1)
+fn sub_masked(a: &mut [u64], b: &[u64], mask: u64) -> u64 {
+    let mut borrow = 0;
+    for (x, y) in a.iter_mut().zip(b) {
+        let (d, b1) = x.overflowing_sub(y & mask);
+        let (d, b2) = d.overflowing_sub(borrow);
+        *x = d;
+        borrow = u64::from(b1 | b2);
+    }
+    borrow
+}
+
+/// Adds `b & mask` to `a`, returns carry (0 or 1)
+fn add_masked(a: &mut [u64], b: &[u64], mask: u64) -> u64 {
+    let mut carry = 0;
+    for (x, y) in a.iter_mut().zip(b) {
+        let (s, c1) = x.overflowing_add(y & mask);
+        let (s, c2) = s.overflowing_add(carry);
+        *x = s;
+        carry = u64::from(c1 | c2);
+    }
+    carry
+}
+
+/// Swaps `a` and `b` if `mask` is all-ones, leaves them intact if it's zero
+fn cswap(a: &mut [u64], b: &mut [u64], mask: u64) {
+    for (x, y) in a.iter_mut().zip(b.iter_mut()) {
+        let t = (*x ^ *y) & mask;
+        *x ^= t;
+        *y ^= t;
+    }
+}
+
+/// Shifts `a` right by one bit, `top_bit` (0 or 1) becomes the most significant bit
+fn shr1(a: &mut [u64], top_bit: u64) {
+    let mut carry = top_bit;
+    for limb in a.iter_mut().rev() {
+        let next_carry = *limb & 1;
+        *limb = (*limb >> 1) | (carry << 63);
+        carry = next_carry;
+    }
+}
@@ -14,6 +14,7 @@
     @license GPL-3.0+ <https://github.com/KZen-networks/cryptography-utils/blob/master/LICENSE>
 */
 
+mod binary_gcd;
 mod errors;
 mod macros;
 mod samplable;
@@ -40,7 +41,9 @@ pub use errors::{ParseBigIntError, TryFromBigIntError};
 pub use traits::*;
 
 #[cfg(test)]
+#[allow(non_local_definitions)]
 mod test {
+    use std::convert::TryFrom;
     use std::{fmt, ops::*};
 
     use proptest_derive::Arbitrary;
@@ -116,11 +119,9 @@ mod test {
     #[test]
     fn count_bits() {
         let mut n = BigInt::one();
-        let mut expected_bits = 1_usize;
-        for _ in 0..100 {
+        for expected_bits in 1..=100 {
             assert_eq!(n.bit_length(), expected_bits);
             n <<= 1;
-            expected_bits += 1;
         }
     }
 
@@ -250,6 +251,43 @@ mod test {
         }
     }
 
+    #[test]
+    fn sample_below_and_sample_range_cover_the_range_uniformly() {
+        // 10 is not a power of two, so rejection sampling is involved. Each value is expected
+        // to appear 2000 times, standard deviation is ~42, we allow 5 deviations.
+        const N: usize = 20_000;
+        let expected = N / 10;
+        let check_counts = |counts: &[usize]| {
+            for (value, &count) in counts.iter().enumerate() {
+                assert!(
+                    (expected - 212..=expected + 212).contains(&count),
+                    "value {} sampled {} times, expected ~{}",
+                    value,
+                    count,
+                    expected
+                );
+            }
+        };
+
+        let upper = BigInt::from(10);
+        let mut counts = [0usize; 10];
+        for _ in 0..N {
+            let n = BigInt::sample_below(&upper);
+            assert!(BigInt::zero() <= n && n < upper);
+            counts[u64::try_from(&n).unwrap() as usize] += 1;
+        }
+        check_counts(&counts);
+
+        let (lower, upper) = (BigInt::from(-3), BigInt::from(7));
+        let mut counts = [0usize; 10];
+        for _ in 0..N {
+            let n = BigInt::sample_range(&lower, &upper);
+            assert!(lower <= n && n < upper);
+            counts[u64::try_from(&(n - &lower)).unwrap() as usize] += 1;
+        }
+        check_counts(&counts);
+    }
+
     #[derive(Arbitrary, Debug, Copy, Clone)]
     enum ModOp {
         Add,
@@ -304,6 +342,125 @@ mod test {
         assert_eq!(one, BigInt::one());
     }
 
+    #[test]
+    fn mod_inv_known_values() {
+        let inv = BigInt::mod_inv(&BigInt::from(3), &BigInt::from(11));
+        assert_eq!(inv, Some(BigInt::from(4)));
+        let inv = BigInt::mod_inv(&BigInt::from(-3), &BigInt::from(11));
+        assert_eq!(inv, Some(BigInt::from(7)));
+        let inv = BigInt::mod_inv(&BigInt::from(17), &BigInt::from(3120));
+        assert_eq!(inv, Some(BigInt::from(2753)));
+    }
+
+    #[test]
+    fn mod_inv_returns_none_if_not_invertible() {
+        // gcd(a, m) > 1
+        assert_eq!(BigInt::mod_inv(&BigInt::from(6), &BigInt::from(9)), None);
+        assert_eq!(BigInt::mod_inv(&BigInt::from(10), &BigInt::from(4)), None);
+        assert_eq!(BigInt::mod_inv(&BigInt::from(21), &BigInt::from(21)), None);
+        assert_eq!(BigInt::mod_inv(&BigInt::zero(), &BigInt::from(7)), None);
+
+        let p = BigInt::from_str_radix("13756265695458089029", 10).unwrap();
+        let q = BigInt::from_str_radix("13496181268022124907", 10).unwrap();
+        assert_eq!(BigInt::mod_inv(&(&q * 5), &(&p * &q)), None);
+        assert!(BigInt::mod_inv(&(&q + 1), &(&p * &q)).is_some());
+    }
+
+    #[test]
+    fn mod_inv_of_large_odd_modulus() {
+        for bits in [64, 255, 2048] {
+            let mut m = BigInt::sample(bits);
+            m.set_bit(0, true);
+            m.set_bit(bits - 1, true);
+            for _ in 0..10 {
+                let a = BigInt::sample_below(&m);
+                let (gcd, _, _) = BigInt::egcd(&a, &m);
+                match BigInt::mod_inv(&a, &m) {
+                    Some(inv) => {
+                        assert_eq!(gcd, BigInt::one());
+                        assert!(BigInt::zero() <= inv && inv < m);
+                        assert_eq!(BigInt::mod_mul(&a, &inv, &m), BigInt::one());
+                    }
+                    None => assert_ne!(gcd, BigInt::one()),
+                }
+            }
+            // Inputs that are not reduced modulo m
+            let a = BigInt::sample(bits + 10);
+            if let Some(inv) = BigInt::mod_inv(&a, &m) {
+                assert_eq!(BigInt::mod_mul(&a, &inv, &m), BigInt::one());
+            }
+            assert_eq!(BigInt::mod_inv(&(&m * 3), &m), None);
+        }
+    }
+
+    #[test]
+    fn mod_pow_known_values() {
+        let mersenne_127 = (BigInt::one() << 127) - 1;
+        let vectors = [
+            (
+                BigInt::from(4),
+                BigInt::from(13),
+                BigInt::from(497),
+                BigInt::from(445),
+            ),
+            (
+                BigInt::from(3),
+                BigInt::from(200),
+                BigInt::from(1_000_003),
+                BigInt::from(333_986),
+            ),
+            // exponent zero
+            (
+                BigInt::from(0),
+                BigInt::from(0),
+                BigInt::from(7),
+                BigInt::from(1),
+            ),
+            (
+                BigInt::from(5),
+                BigInt::from(0),
+                BigInt::from(7),
+                BigInt::from(1),
+            ),
+            (
+                BigInt::from(0),
+                BigInt::from(5),
+                BigInt::from(7),
+                BigInt::from(0),
+            ),
+            // modulus one
+            (
+                BigInt::from(7),
+                BigInt::from(0),
+                BigInt::from(1),
+                BigInt::from(0),
+            ),
+            (
+                BigInt::from(7),
+                BigInt::from(3),
+                BigInt::from(1),
+                BigInt::from(0),
+            ),
+            // inversion via Fermat's little theorem
+            (
+                BigInt::from(123_456_789),
+                &mersenne_127 - 2,
+                mersenne_127.clone(),
+                BigInt::from_str_radix("9093224369639006326868225003581871176", 10).unwrap(),
+            ),
+        ];
+        for (base, exponent, modulus, expected) in vectors.iter() {
+            assert_eq!(
+                &BigInt::mod_pow(base, exponent, modulus),
+                expected,
+                "{}^{} mod {}",
+                base,
+                exponent,
+                modulus
+            );
+        }
+    }
+
     #[test]
     #[should_panic]
     fn mod_pow_panics_if_exp_is_negative() {
@@ -142,7 +142,9 @@ pub trait BasicOps {
 pub trait Modulo: Sized {
     /// Calculates base^(exponent) (mod m)
     ///
-    /// Exponent must not be negative. Function will panic otherwise.
+    /// Backed by GMP `powm` (sliding window exponentiation) or by `num-bigint` `modpow`, depending on
+    /// enabled backend. Exponent must not be negative. Function will panic otherwise.
+    /// `base^0 = 1` unless `m = 1`, any value modulo one is zero.
     fn mod_pow(base: &Self, exponent: &Self, m: &Self) -> Self;
     /// Calculates a * b (mod m)
     fn mod_mul(a: &Self, b: &Self, modulus: &Self) -> Self;
@@ -151,6 +153,10 @@ pub trait Modulo: Sized {
     /// Calculates a + b (mod m)
     fn mod_add(a: &Self, b: &Self, modulus: &Self) -> Self;
     /// Calculates a^-1 (mod m). Returns None if `a` and `m` are not coprimes.
+    ///
+    /// For odd `m > 1` (eg. RSA or Paillier modulus) inversion is done via binary extended GCD
+    /// which running time depends only on bit length of `m`, so it's suitable for secret `a`.
+    /// Even moduli are handled by the backend, which inversion is not constant time.
     fn mod_inv(a: &Self, m: &Self) -> Option<Self>;
     /// Calculates a mod m
     fn modulus(&self, modulus: &Self) -> Self;
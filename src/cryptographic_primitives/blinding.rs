@@ -0,0 +1,52 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Blinding factors for commitments and encryption
+//!
+//! Pedersen commitments, verifiable encryption and similar primitives hide a value behind a
+//! fresh random scalar. Blinding factors must be sampled from a cryptographically secure RNG
+//! and never reused, so this module provides a single place where they're generated.
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::blinding;
+//! # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! let message = Scalar::<Secp256k1>::random();
+//! let r = blinding::random_blinding();
+//! let commitment = Point::generator() * message + Point::base_point2() * &r;
+//! ```
+
+use crate::elliptic::curves::{Curve, Scalar};
+
+/// Samples a fresh nonzero blinding factor using secure RNG
+pub fn random_blinding<E: Curve>() -> Scalar<E> {
+    Scalar::random()
+}
+
+/// Samples `n` fresh blinding factors, see [random_blinding]
+pub fn random_blinding_vec<E: Curve>(n: usize) -> Vec<Scalar<E>> {
+    std::iter::repeat_with(random_blinding).take(n).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(blinding_factors_are_fresh);
+    fn blinding_factors_are_fresh<E: Curve>() {
+        let r1 = random_blinding::<E>();
+        let r2 = random_blinding::<E>();
+        assert!(!r1.is_zero());
+        assert_ne!(r1, r2);
+
+        let rs = random_blinding_vec::<E>(10);
+        assert_eq!(rs.len(), 10);
+        for (i, r) in rs.iter().enumerate() {
+            assert!(rs[i + 1..].iter().all(|other| other != r));
+        }
+    }
+}
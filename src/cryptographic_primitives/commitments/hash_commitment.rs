@@ -34,7 +34,15 @@ impl<H: Digest + Clone> Commitment<BigInt> for HashCommitment<H> {
     }
 
     fn create_commitment(message: &BigInt) -> (BigInt, BigInt) {
-        let blinding_factor = BigInt::sample(SECURITY_BITS);
+        Self::create_commitment_with_security(message, SECURITY_BITS)
+    }
+}
+
+impl<H: Digest + Clone> HashCommitment<H> {
+    /// Same as [create_commitment](Commitment::create_commitment), but samples blinding factor of
+    /// given bit length instead of default 256 bits
+    pub fn create_commitment_with_security(message: &BigInt, sec_bits: usize) -> (BigInt, BigInt) {
+        let blinding_factor = BigInt::sample(sec_bits);
         let com = Self::create_commitment_with_user_defined_randomness(message, &blinding_factor);
         (com, blinding_factor)
     }
@@ -100,6 +108,73 @@ mod tests {
         assert_eq!(commitment, commitment2);
     }
 
+    test_for_all_hashes!(test_hash_commitment_opening);
+    fn test_hash_commitment_opening<H: Digest + Clone>() {
+        let message = BigInt::sample(SECURITY_BITS);
+        let (commitment, blind_factor) = HashCommitment::<H>::create_commitment(&message);
+        assert!(
+            HashCommitment::<H>::verify_commitment(&commitment, &message, &blind_factor).is_ok()
+        );
+
+        let wrong_message = &message + 1;
+        assert!(
+            HashCommitment::<H>::verify_commitment(&commitment, &wrong_message, &blind_factor)
+                .is_err()
+        );
+        let wrong_blind_factor = &blind_factor + 1;
+        assert!(
+            HashCommitment::<H>::verify_commitment(&commitment, &message, &wrong_blind_factor)
+                .is_err()
+        );
+    }
+
+    test_for_all_hashes!(test_create_commitment_with_security_bits);
+    fn test_create_commitment_with_security_bits<H: Digest + Clone>() {
+        let message = BigInt::sample(SECURITY_BITS);
+        for _ in 0..10 {
+            let (commitment, blind_factor) =
+                HashCommitment::<H>::create_commitment_with_security(&message, 80);
+            assert!(blind_factor.bit_length() <= 80);
+            assert!(
+                HashCommitment::<H>::verify_commitment(&commitment, &message, &blind_factor)
+                    .is_ok()
+            );
+        }
+    }
+
+    test_for_all_hashes!(test_commitments_with_different_security_levels);
+    fn test_commitments_with_different_security_levels<H: Digest + Clone>() {
+        let message = BigInt::sample(SECURITY_BITS);
+        let (commitment128, blind_factor128) =
+            HashCommitment::<H>::create_commitment_with_security(&message, 128);
+        let (commitment256, blind_factor256) =
+            HashCommitment::<H>::create_commitment_with_security(&message, 256);
+        assert!(blind_factor128.bit_length() <= 128);
+        assert!(blind_factor256.bit_length() <= 256);
+        assert_ne!(commitment128, commitment256);
+
+        // Default security level is 256 bits
+        assert_eq!(SECURITY_BITS, 256);
+        let default_blind_factors: Vec<_> = (0..10)
+            .map(|_| HashCommitment::<H>::create_commitment(&message).1)
+            .collect();
+        assert!(default_blind_factors.iter().all(|b| b.bit_length() <= 256));
+        assert!(default_blind_factors.iter().any(|b| b.bit_length() > 128));
+
+        assert!(
+            HashCommitment::<H>::verify_commitment(&commitment128, &message, &blind_factor128)
+                .is_ok()
+        );
+        assert!(
+            HashCommitment::<H>::verify_commitment(&commitment256, &message, &blind_factor256)
+                .is_ok()
+        );
+        assert!(
+            HashCommitment::<H>::verify_commitment(&commitment128, &message, &blind_factor256)
+                .is_err()
+        );
+    }
+
     test_for_all_hashes!(test_hashing_create_commitment_with_user_defined_randomness);
     fn test_hashing_create_commitment_with_user_defined_randomness<H: Digest + Clone>() {
         let mut digest = H::new();
@@ -111,7 +186,7 @@ mod tests {
         let message2 = message.to_bytes();
         digest.update(&message2);
         let bytes_blinding_factor = &BigInt::zero().to_bytes();
-        digest.update(&bytes_blinding_factor);
+        digest.update(bytes_blinding_factor);
         let hash_result = BigInt::from_bytes(digest.finalize().as_ref());
         assert_eq!(&commitment, &hash_result);
     }
@@ -7,6 +7,8 @@
 
 use std::marker::PhantomData;
 
+use subtle::ConstantTimeEq;
+
 use super::traits::Commitment;
 use super::SECURITY_BITS;
 use crate::arithmetic::traits::*;
@@ -43,3 +45,106 @@ impl<E: Curve> Commitment<Point<E>> for PedersenCommitment<E> {
         (com, blinding_factor)
     }
 }
+
+impl<E: Curve> PedersenCommitment<E> {
+    /// Commits to `value` with `blinding` factor, ie. computes `value·G + blinding·H`
+    ///
+    /// Same as [create_commitment_with_user_defined_randomness](Commitment::create_commitment_with_user_defined_randomness),
+    /// but takes scalars, so it doesn't convert them from BigInt.
+    pub fn commit(value: &Scalar<E>, blinding: &Scalar<E>) -> Point<E> {
+        Point::generator() * value + Point::base_point2() * blinding
+    }
+
+    /// Commits to `value` with random blinding factor, returns commitment along with the blinding
+    pub fn commit_random(value: &Scalar<E>) -> (Point<E>, Scalar<E>) {
+        let blinding = Scalar::random();
+        (Self::commit(value, &blinding), blinding)
+    }
+
+    /// Checks that `commitment` is opened to `value` with `blinding` factor, ie.
+    /// `commitment = value·G + blinding·H`
+    ///
+    /// Unlike [verify_commitment](Commitment::verify_commitment), takes scalars, so it doesn't
+    /// convert them from BigInt. Points are compared in constant time.
+    pub fn verify(commitment: &Point<E>, value: &Scalar<E>, blinding: &Scalar<E>) -> bool {
+        Self::commit(value, blinding).ct_eq(commitment).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_pedersen_commitment_is_additively_homomorphic);
+    fn test_pedersen_commitment_is_additively_homomorphic<E: Curve>() {
+        let q = Scalar::<E>::group_order();
+        let (a, b) = (BigInt::sample_below(q), BigInt::sample_below(q));
+        let (com_a, r_a) = PedersenCommitment::<E>::create_commitment(&a);
+        let (com_b, r_b) = PedersenCommitment::<E>::create_commitment(&b);
+
+        let com_sum = PedersenCommitment::<E>::create_commitment_with_user_defined_randomness(
+            &BigInt::mod_add(&a, &b, q),
+            &(r_a + r_b),
+        );
+        assert_eq!(com_a + com_b, com_sum);
+    }
+
+    test_for_all_curves!(test_commit_is_additively_homomorphic);
+    fn test_commit_is_additively_homomorphic<E: Curve>() {
+        let (a, b) = (Scalar::<E>::random(), Scalar::<E>::random());
+        let (com_a, r_a) = PedersenCommitment::commit_random(&a);
+        let (com_b, r_b) = PedersenCommitment::commit_random(&b);
+        assert_eq!(
+            &com_a + &com_b,
+            PedersenCommitment::commit(&(&a + &b), &(&r_a + &r_b))
+        );
+
+        // Scalar and BigInt APIs produce the same commitment
+        assert_eq!(
+            com_a,
+            PedersenCommitment::<E>::create_commitment_with_user_defined_randomness(
+                &a.to_bigint(),
+                &r_a.to_bigint()
+            )
+        );
+        assert!(PedersenCommitment::verify(&com_b, &b, &r_b));
+    }
+
+    test_for_all_curves!(test_pedersen_commitment_opening);
+    fn test_pedersen_commitment_opening<E: Curve>() {
+        let message = BigInt::sample_below(Scalar::<E>::group_order());
+        let (com, r) = PedersenCommitment::<E>::create_commitment(&message);
+        assert!(PedersenCommitment::<E>::verify_commitment(&com, &message, &r).is_ok());
+
+        let wrong_message = &message + 1;
+        assert!(PedersenCommitment::<E>::verify_commitment(&com, &wrong_message, &r).is_err());
+        let wrong_r = &r + 1;
+        assert!(PedersenCommitment::<E>::verify_commitment(&com, &message, &wrong_r).is_err());
+    }
+
+    test_for_all_curves!(test_verify_opening);
+    fn test_verify_opening<E: Curve>() {
+        let (value, blinding) = (Scalar::<E>::random(), Scalar::<E>::random());
+        let com = PedersenCommitment::commit(&value, &blinding);
+        assert!(PedersenCommitment::verify(&com, &value, &blinding));
+
+        let one = Scalar::<E>::from(1);
+        assert!(!PedersenCommitment::verify(
+            &com,
+            &(&value + &one),
+            &blinding
+        ));
+        assert!(!PedersenCommitment::verify(
+            &com,
+            &value,
+            &(&blinding + &one)
+        ));
+        assert!(!PedersenCommitment::verify(&com, &blinding, &value));
+        assert!(!PedersenCommitment::verify(
+            &(&com + Point::generator()),
+            &value,
+            &blinding
+        ));
+    }
+}
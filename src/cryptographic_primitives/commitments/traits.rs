@@ -5,6 +5,7 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 
+use crate::cryptographic_primitives::proofs::ProofError;
 use crate::BigInt;
 
 pub trait Commitment<T> {
@@ -14,4 +15,22 @@ pub trait Commitment<T> {
     ) -> T;
 
     fn create_commitment(message: &BigInt) -> (T, BigInt);
+
+    /// Checks that `commitment` is opened to `message` with `blinding_factor`
+    fn verify_commitment(
+        commitment: &T,
+        message: &BigInt,
+        blinding_factor: &BigInt,
+    ) -> Result<(), ProofError>
+    where
+        T: PartialEq,
+    {
+        if Self::create_commitment_with_user_defined_randomness(message, blinding_factor)
+            == *commitment
+        {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
 }
@@ -0,0 +1,273 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Additively homomorphic ElGamal encryption "in the exponent"
+//!
+//! Message `m` is encoded as a point `m·G`, ciphertext is `(c1, c2) = (r·G, m·G + r·Y)` where
+//! `Y` is a public key. Sum of ciphertexts encrypts sum of messages, which makes it suitable for
+//! tallying votes. Decryption outputs `m·G`: recovering `m` requires solving discrete log, which
+//! is feasible only for small messages and is left to the caller.
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::elgamal;
+//! # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! let sk = Scalar::<Secp256k1>::random();
+//! let pk = Point::generator() * &sk;
+//!
+//! let vote1 = elgamal::encrypt(&pk, &Scalar::from(1));
+//! let vote2 = elgamal::encrypt(&pk, &Scalar::from(0));
+//! let tally = elgamal::add(&vote1, &vote2);
+//! assert_eq!(elgamal::decrypt_to_point(&sk, &tally), Point::generator() * Scalar::from(1));
+//! ```
+//!
+//! ## Threshold decryption
+//!
+//! Secret key can be shared via [VerifiableSS]. Then each party publishes a
+//! [partial decryption](partial_decrypt_with_proof) `share_i·c1` together with a proof that it
+//! used the same share as in its public share `share_i·G`, and any `t+1` partial decryptions are
+//! [combined](combine_decryptions) into `sk·c1`:
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::elgamal;
+//! # use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+//! # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! # use sha2::Sha256;
+//! let sk = Scalar::<Secp256k1>::random();
+//! let (vss, shares) = VerifiableSS::<Secp256k1, Sha256>::share(1, 3, &sk);
+//! let ciphertext = elgamal::encrypt(vss.public_key(), &Scalar::from(5));
+//!
+//! let public_shares = vss.public_shares();
+//! let indices = [0, 2];
+//! let partials: Vec<_> = indices
+//!     .iter()
+//!     .map(|&i| {
+//!         let (partial, proof) =
+//!             elgamal::partial_decrypt_with_proof::<_, Sha256>(&ciphertext.0, &shares[usize::from(i)]);
+//!         elgamal::verify_partial_decryption(
+//!             &ciphertext.0,
+//!             &partial,
+//!             &proof,
+//!             &public_shares[usize::from(i)],
+//!         )
+//!         .expect("invalid partial decryption");
+//!         partial
+//!     })
+//!     .collect();
+//!
+//! let sk_c1 = elgamal::combine_decryptions(&partials, &indices, &vss).unwrap();
+//! assert_eq!(ciphertext.1 - sk_c1, Point::generator() * Scalar::from(5));
+//! ```
+
+use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::proofs::sigma_ec_ddh::{
+    ECDDHProof, ECDDHStatement, ECDDHWitness,
+};
+use crate::cryptographic_primitives::proofs::ProofError;
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::{ensure_distinct, VerifiableSS};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::ErrorSS;
+
+/// ElGamal ciphertext `(r·G, m·G + r·Y)`
+pub type Ciphertext<E> = (Point<E>, Point<E>);
+
+/// Encrypts `message` under public key `pk`
+pub fn encrypt<E: Curve>(pk: &Point<E>, message: &Scalar<E>) -> Ciphertext<E> {
+    encrypt_with_randomness(pk, message, &Scalar::random())
+}
+
+/// Encrypts `message` under public key `pk` using given randomness `r`
+///
+/// Randomness must be secret and never reused, otherwise use [encrypt].
+pub fn encrypt_with_randomness<E: Curve>(
+    pk: &Point<E>,
+    message: &Scalar<E>,
+    r: &Scalar<E>,
+) -> Ciphertext<E> {
+    let g = Point::<E>::generator();
+    (g * r, g * message + pk * r)
+}
+
+/// Homomorphically adds two ciphertexts, resulting ciphertext encrypts sum of messages
+pub fn add<E: Curve>(c1: &Ciphertext<E>, c2: &Ciphertext<E>) -> Ciphertext<E> {
+    (&c1.0 + &c2.0, &c1.1 + &c2.1)
+}
+
+/// Decrypts a ciphertext into `m·G`
+pub fn decrypt_to_point<E: Curve>(sk: &Scalar<E>, ciphertext: &Ciphertext<E>) -> Point<E> {
+    &ciphertext.1 - &ciphertext.0 * sk
+}
+
+/// Computes partial decryption `share·c1` of party holding secret key `share`
+pub fn partial_decrypt<E: Curve>(c1: &Point<E>, share: &Scalar<E>) -> Point<E> {
+    c1 * share
+}
+
+/// Computes [partial decryption](partial_decrypt) and Chaum-Pedersen proof that the same `share`
+/// was used in `share·G` and `share·c1`
+pub fn partial_decrypt_with_proof<E: Curve, H: Digest + Clone>(
+    c1: &Point<E>,
+    share: &Scalar<E>,
+) -> (Point<E>, ECDDHProof<E, H>) {
+    let partial = partial_decrypt(c1, share);
+    let statement = ECDDHStatement {
+        g1: Point::generator().to_point(),
+        h1: Point::generator() * share,
+        g2: c1.clone(),
+        h2: partial.clone(),
+    };
+    let proof = ECDDHProof::prove(&ECDDHWitness { x: share.clone() }, &statement);
+    (partial, proof)
+}
+
+/// Verifies proof produced by [partial_decrypt_with_proof]
+///
+/// `public_share` is `share·G` of the party, eg. taken from
+/// [VerifiableSS::public_shares].
+pub fn verify_partial_decryption<E: Curve, H: Digest + Clone>(
+    c1: &Point<E>,
+    partial: &Point<E>,
+    proof: &ECDDHProof<E, H>,
+    public_share: &Point<E>,
+) -> Result<(), ProofError> {
+    let statement = ECDDHStatement {
+        g1: Point::generator().to_point(),
+        h1: public_share.clone(),
+        g2: c1.clone(),
+        h2: partial.clone(),
+    };
+    proof.verify(&statement)
+}
+
+/// Combines partial decryptions of parties at (0-based) `indices` into `sk·c1`
+///
+/// Partial decryptions are interpolated in the exponent using
+/// [map_share_to_new_params](VerifiableSS::map_share_to_new_params). Message is then recovered as
+/// `m·G = c2 - sk·c1`. Partial decryptions are not checked here, verify them first via
+/// [verify_partial_decryption].
+///
+/// Returns [ErrorSS::MismatchedLengths] if `partials` and `indices` have different lengths,
+/// [ErrorSS::BelowThreshold] if there are not more than `t` of them, and
+/// [ErrorSS::DuplicateIndex] if an index appears twice.
+pub fn combine_decryptions<E: Curve, H: Digest + Clone>(
+    partials: &[Point<E>],
+    indices: &[u16],
+    vss: &VerifiableSS<E, H>,
+) -> Result<Point<E>, ErrorSS> {
+    if partials.len() != indices.len() {
+        return Err(ErrorSS::MismatchedLengths);
+    }
+    if partials.len() <= usize::from(vss.parameters.threshold) {
+        return Err(ErrorSS::BelowThreshold);
+    }
+    ensure_distinct(indices)?;
+    Ok(partials
+        .iter()
+        .zip(indices)
+        .map(|(partial, &index)| {
+            partial * VerifiableSS::<E, H>::map_share_to_new_params(&vss.parameters, index, indices)
+        })
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_encrypt_decrypt);
+    fn test_encrypt_decrypt<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let m = Scalar::random();
+
+        let ciphertext = encrypt(&pk, &m);
+        assert_eq!(decrypt_to_point(&sk, &ciphertext), Point::generator() * &m);
+        assert_ne!(
+            decrypt_to_point(&Scalar::random(), &ciphertext),
+            Point::generator() * &m
+        );
+        // Encryption is randomized
+        assert_ne!(encrypt(&pk, &m), ciphertext);
+    }
+
+    test_for_all_curves!(test_homomorphic_addition);
+    fn test_homomorphic_addition<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let (m1, m2) = (Scalar::random(), Scalar::random());
+        let (r1, r2) = (Scalar::random(), Scalar::random());
+
+        let c1 = encrypt_with_randomness(&pk, &m1, &r1);
+        let c2 = encrypt_with_randomness(&pk, &m2, &r2);
+        let sum = add(&c1, &c2);
+
+        assert_eq!(sum, encrypt_with_randomness(&pk, &(&m1 + &m2), &(r1 + r2)));
+        assert_eq!(decrypt_to_point(&sk, &sum), Point::generator() * (m1 + m2));
+    }
+
+    test_for_all_curves!(test_threshold_decryption);
+    fn test_threshold_decryption<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let (vss, shares) = VerifiableSS::<E, sha2::Sha256>::share(2, 5, &sk);
+        let m = Scalar::random();
+        let ciphertext = encrypt(vss.public_key(), &m);
+        let public_shares = vss.public_shares();
+
+        let (partials, proofs): (Vec<_>, Vec<_>) = shares
+            .iter()
+            .map(|share| partial_decrypt_with_proof::<E, sha2::Sha256>(&ciphertext.0, share))
+            .unzip();
+        for i in 0..5 {
+            assert!(verify_partial_decryption(
+                &ciphertext.0,
+                &partials[i],
+                &proofs[i],
+                &public_shares[i]
+            )
+            .is_ok());
+            assert_eq!(partials[i], partial_decrypt(&ciphertext.0, &shares[i]));
+        }
+        // Proof doesn't verify against public share of another party
+        assert!(verify_partial_decryption(
+            &ciphertext.0,
+            &partials[0],
+            &proofs[0],
+            &public_shares[1]
+        )
+        .is_err());
+        // Nor for a tampered partial decryption
+        let tampered = &partials[0] + Point::generator();
+        assert!(
+            verify_partial_decryption(&ciphertext.0, &tampered, &proofs[0], &public_shares[0])
+                .is_err()
+        );
+
+        let expected = Point::generator() * &m;
+        for indices in [[0u16, 1, 2], [1, 3, 4], [4, 0, 2]] {
+            let selected: Vec<_> = indices
+                .iter()
+                .map(|&i| partials[usize::from(i)].clone())
+                .collect();
+            let combined = combine_decryptions(&selected, &indices, &vss).unwrap();
+            assert_eq!(combined, &ciphertext.0 * &sk);
+            assert_eq!(&ciphertext.1 - combined, expected);
+        }
+
+        assert!(matches!(
+            combine_decryptions(&partials[..2], &[0, 1], &vss),
+            Err(ErrorSS::BelowThreshold)
+        ));
+        assert!(matches!(
+            combine_decryptions(&partials[..3], &[0, 1], &vss),
+            Err(ErrorSS::MismatchedLengths)
+        ));
+        assert!(matches!(
+            combine_decryptions(&partials[..3], &[0, 1, 1], &vss),
+            Err(ErrorSS::DuplicateIndex)
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+use digest::{BlockInput, Digest};
+use generic_array::GenericArray;
+use typenum::Unsigned;
+
+use crate::arithmetic::*;
+
+/// Expands a message into `len_in_bytes` uniformly distributed bytes
+///
+/// Implements `expand_message_xmd` as defined in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd).
+/// `dst` is a domain separation tag, it must be unique for every protocol (and for every use
+/// of the function within the protocol). DSTs longer than 255 bytes are hashed as specified
+/// by the RFC.
+///
+/// ## Panics
+/// Panics if `len_in_bytes` is zero, exceeds `u16::MAX`, or exceeds `255 * H::OutputSize`.
+pub fn expand_message_xmd<H: Digest + BlockInput>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Vec<u8> {
+    let b_in_bytes = H::OutputSize::USIZE;
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    assert!(
+        len_in_bytes > 0 && len_in_bytes <= usize::from(u16::MAX) && ell <= 255,
+        "len_in_bytes is out of range"
+    );
+
+    let hashed_dst;
+    let dst = if dst.len() > 255 {
+        hashed_dst = H::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize();
+        hashed_dst.as_slice()
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = H::new()
+        .chain(GenericArray::<u8, H::BlockSize>::default())
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    let mut b_i = H::new()
+        .chain(&b_0)
+        .chain([1u8])
+        .chain(dst)
+        .chain(dst_len)
+        .finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let b_0_xor_b_i: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = H::new()
+            .chain(b_0_xor_b_i)
+            .chain([i as u8])
+            .chain(dst)
+            .chain(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hashes a message to `count` elements of prime field `GF(modulus)`
+///
+/// Implements `hash_to_field` as defined in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio)
+/// for prime fields (extension degree `m = 1`), using [expand_message_xmd]. Every element is
+/// derived from `l` bytes, `l` must be at least `ceil((log2(modulus) + k) / 8)` where `k` is
+/// security level in bits.
+pub fn hash_to_field<H: Digest + BlockInput>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    modulus: &BigInt,
+    l: usize,
+) -> Vec<BigInt> {
+    expand_message_xmd::<H>(msg, dst, count * l)
+        .chunks(l)
+        .map(|tv| BigInt::from_bytes(tv).modulus(modulus))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Sha256;
+
+    use super::*;
+
+    // Test vectors from RFC 9380, Appendix K.1
+    #[test]
+    fn expand_message_xmd_sha256() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: &[(&[u8], usize, &str)] = &[
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0\
+                 dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec84946\
+                 9b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7\
+                 fa5b646c993f0ced",
+            ),
+            (
+                b"abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76\
+                 a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b\
+                 0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f\
+                 99b0509b4c895f40",
+            ),
+        ];
+        for (msg, len_in_bytes, expected) in vectors {
+            let uniform_bytes = expand_message_xmd::<Sha256>(msg, DST, *len_in_bytes);
+            assert_eq!(hex::encode(uniform_bytes), *expected);
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_long_dst() {
+        let long_dst = [b'1'; 300];
+        let hashed_dst = Sha256::new()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(&long_dst[..])
+            .finalize();
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", &long_dst, 32),
+            expand_message_xmd::<Sha256>(b"abc", &hashed_dst, 32),
+        );
+    }
+}
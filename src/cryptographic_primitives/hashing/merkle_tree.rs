@@ -92,7 +92,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::MT256;
-    use crate::elliptic::curves::{Curve, Point};
+    use crate::elliptic::curves::{Curve, Point, Scalar};
 
     use crate::test_for_all_curves;
 
@@ -123,4 +123,43 @@ mod tests {
         let root = mt256.get_root();
         proof1.verify(&root).expect("proof is invalid");
     }
+
+    test_for_all_curves!(test_mt_proof_for_every_leaf);
+
+    fn test_mt_proof_for_every_leaf<E: Curve>() {
+        let leaves: Vec<Point<E>> = (1..=7)
+            .map(|i| Point::generator() * Scalar::from(i))
+            .collect();
+        let mt256 = MT256::<_, sha3::Keccak256>::create_tree(leaves.clone());
+        let root = mt256.get_root();
+        for leaf in leaves {
+            let proof = mt256.build_proof(leaf).unwrap();
+            proof.verify(&root).expect("proof is invalid");
+        }
+
+        let unknown_point = Point::generator() * Scalar::from(8);
+        assert!(mt256.build_proof(unknown_point).is_none());
+    }
+
+    test_for_all_curves!(test_mt_rejects_tampered_leaf);
+
+    fn test_mt_rejects_tampered_leaf<E: Curve>() {
+        let leaves: Vec<Point<E>> = (1..=4)
+            .map(|i| Point::generator() * Scalar::from(i))
+            .collect();
+        let mt256 = MT256::<_, sha3::Keccak256>::create_tree(leaves.clone());
+        let root = mt256.get_root();
+
+        let mut proof = mt256.build_proof(leaves[1].clone()).unwrap();
+        proof.point = leaves[2].clone();
+        assert!(proof.verify(&root).is_err());
+
+        let mut proof = mt256.build_proof(leaves[1].clone()).unwrap();
+        proof.index = 3;
+        assert!(proof.verify(&root).is_err());
+
+        let other_tree = MT256::<_, sha3::Keccak256>::create_tree(leaves[..3].to_vec());
+        let proof = mt256.build_proof(leaves[1].clone()).unwrap();
+        assert!(proof.verify(&other_tree.get_root()).is_err());
+    }
 }
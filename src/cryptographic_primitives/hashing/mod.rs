@@ -4,8 +4,12 @@
     (https://github.com/KZen-networks/curv)
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
+mod domain_separated;
+mod expand_message;
 mod ext;
 pub mod merkle_tree;
 
 pub use digest::Digest;
+pub use domain_separated::*;
+pub use expand_message::*;
 pub use ext::*;
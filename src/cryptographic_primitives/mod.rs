@@ -5,8 +5,12 @@
     License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
 */
 
+pub mod blinding;
 pub mod commitments;
+pub mod elgamal;
 pub mod hashing;
 pub mod proofs;
 pub mod secret_sharing;
+pub mod signatures;
+pub mod transcript;
 pub mod twoparty;
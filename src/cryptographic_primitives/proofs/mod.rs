@@ -9,12 +9,14 @@ use std::error::Error;
 use std::fmt;
 
 pub mod low_degree_exponent_interpolation;
+pub mod range_proof;
 pub mod sigma_correct_homomorphic_elgamal_enc;
 pub mod sigma_correct_homomorphic_elgamal_encryption_of_dlog;
 pub mod sigma_dlog;
 pub mod sigma_ec_ddh;
 pub mod sigma_valid_pedersen;
 pub mod sigma_valid_pedersen_blind;
+pub mod verifiable_encryption;
 
 #[derive(Debug, Clone, Copy)]
 pub struct ProofError;
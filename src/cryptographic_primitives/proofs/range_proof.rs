@@ -0,0 +1,509 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::transcript::Transcript;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::ProofError;
+
+const TRANSCRIPT_LABEL: &[u8] = b"CURV-RANGE-PROOF";
+const GENERATORS_G_DST: &[u8] = b"CURV-RANGE-PROOF-GENERATORS-G";
+const GENERATORS_H_DST: &[u8] = b"CURV-RANGE-PROOF-GENERATORS-H";
+
+/// This is implementation of a (non-aggregated) range proof from Bulletproofs:
+/// B. Bünz, J. Bootle, D. Boneh, A. Poelstra, P. Wuille, and G. Maxwell. Bulletproofs: Short
+/// Proofs for Confidential Transactions and More. In IEEE S&P 2018.
+/// <https://eprint.iacr.org/2017/1066.pdf>.
+///
+/// Proves that Pedersen commitment `V = v·G + γ·H` (same as
+/// [PedersenCommitment](crate::cryptographic_primitives::commitments::pedersen_commitment::PedersenCommitment))
+/// commits to a value `v` in range `[0; 2^n)` without revealing `v`. Proof size is
+/// logarithmic in `n`.
+///
+/// The protocol is made non-interactive using [Transcript]. Vector generators are derived via
+/// [hash to curve](Point::hash_to_curve), so they have unknown discrete logarithms.
+///
+/// ```rust
+/// # use curv::cryptographic_primitives::proofs::range_proof::RangeProof;
+/// # use curv::elliptic::curves::{Scalar, Secp256k1};
+/// let value = Scalar::<Secp256k1>::from(1234u32);
+/// let blinding = Scalar::random();
+/// let proof = RangeProof::prove(&value, &blinding, 32);
+/// assert!(proof.verify(32).is_ok());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct RangeProof<E: Curve> {
+    /// Commitment `V = v·G + γ·H` to the value
+    pub commitment: Point<E>,
+    pub big_a: Point<E>,
+    pub big_s: Point<E>,
+    pub big_t1: Point<E>,
+    pub big_t2: Point<E>,
+    pub tau_x: Scalar<E>,
+    pub mu: Scalar<E>,
+    pub t_hat: Scalar<E>,
+    pub inner_product: InnerProductProof<E>,
+}
+
+/// Proof that `P = <a, g> + <b, h> + <a, b>·Q` for committed vectors `a` and `b`
+///
+/// Used as a part of [RangeProof].
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct InnerProductProof<E: Curve> {
+    pub big_l: Vec<Point<E>>,
+    pub big_r: Vec<Point<E>>,
+    pub a: Scalar<E>,
+    pub b: Scalar<E>,
+}
+
+impl<E: Curve> RangeProof<E> {
+    /// Proves that `value` is in range `[0; 2^n)`
+    ///
+    /// If `value` is out of range, resulting proof will not pass verification.
+    ///
+    /// ## Panics
+    /// Panics if `n` is not a power of two, or if `2^n` exceeds group order.
+    pub fn prove(value: &Scalar<E>, blinding: &Scalar<E>, n: usize) -> Self {
+        Self::prove_with_blinding_base(value, blinding, Point::base_point2(), n)
+    }
+
+    /// Proves that `value` is in range `[0; 2^n)` for commitment `V = v·G + γ·h` with custom
+    /// blinding base `h`
+    ///
+    /// Discrete logarithm of `h` must be unknown to the prover.
+    ///
+    /// ## Panics
+    /// Panics if `n` is not a power of two, or if `2^n` exceeds group order.
+    pub fn prove_with_blinding_base(
+        value: &Scalar<E>,
+        blinding: &Scalar<E>,
+        h: &Point<E>,
+        n: usize,
+    ) -> Self {
+        assert!(
+            is_valid_bit_length::<E>(n),
+            "n must be a power of two and 2^n must be less than group order"
+        );
+        let g = Point::<E>::generator();
+        let (gs, hs) = generators::<E>(n);
+
+        let commitment = g * value + h * blinding;
+        let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+        transcript.append_message(b"n", &(n as u64).to_be_bytes());
+        transcript.append_point(b"H", h);
+        transcript.append_point(b"V", &commitment);
+
+        let v = value.to_bigint();
+        let a_l: Vec<Scalar<E>> = (0..n)
+            .map(|i| {
+                if v.test_bit(i) {
+                    Scalar::one()
+                } else {
+                    Scalar::zero()
+                }
+            })
+            .collect();
+        let a_r: Vec<Scalar<E>> = a_l.iter().map(|a| a - Scalar::one()).collect();
+        let alpha = Scalar::random();
+        let big_a = h * &alpha + commit_vector(&gs, &a_l) + commit_vector(&hs, &a_r);
+
+        let s_l: Vec<Scalar<E>> = (0..n).map(|_| Scalar::random()).collect();
+        let s_r: Vec<Scalar<E>> = (0..n).map(|_| Scalar::random()).collect();
+        let rho = Scalar::random();
+        let big_s = h * &rho + commit_vector(&gs, &s_l) + commit_vector(&hs, &s_r);
+
+        transcript.append_point(b"A", &big_a);
+        transcript.append_point(b"S", &big_s);
+        let y = transcript.challenge_scalar::<E>(b"y");
+        let z = transcript.challenge_scalar::<E>(b"z");
+        let z2 = &z * &z;
+        let y_n = powers(&y, n);
+        let two_n = powers(&Scalar::from(2), n);
+
+        // l(X) = l0 + s_l·X, r(X) = r0 + r1·X, t(X) = <l(X), r(X)> = t0 + t1·X + t2·X^2
+        let l0: Vec<Scalar<E>> = a_l.iter().map(|a| a - &z).collect();
+        let r0: Vec<Scalar<E>> = a_r
+            .iter()
+            .zip(&y_n)
+            .zip(&two_n)
+            .map(|((a, y), two)| y * (a + &z) + &z2 * two)
+            .collect();
+        let r1: Vec<Scalar<E>> = s_r.iter().zip(&y_n).map(|(s, y)| y * s).collect();
+        let t1 = inner_product(&l0, &r1) + inner_product(&s_l, &r0);
+        let t2 = inner_product(&s_l, &r1);
+
+        let tau1 = Scalar::random();
+        let tau2 = Scalar::random();
+        let big_t1 = g * &t1 + h * &tau1;
+        let big_t2 = g * &t2 + h * &tau2;
+
+        transcript.append_point(b"T1", &big_t1);
+        transcript.append_point(b"T2", &big_t2);
+        let x = transcript.challenge_scalar::<E>(b"x");
+
+        let l: Vec<Scalar<E>> = l0.iter().zip(&s_l).map(|(l, s)| l + s * &x).collect();
+        let r: Vec<Scalar<E>> = r0.iter().zip(&r1).map(|(r0, r1)| r0 + r1 * &x).collect();
+        let t_hat = inner_product(&l, &r);
+        let tau_x = &tau2 * &x * &x + &tau1 * &x + &z2 * blinding;
+        let mu = alpha + rho * &x;
+
+        transcript.append_scalar(b"tau_x", &tau_x);
+        transcript.append_scalar(b"mu", &mu);
+        transcript.append_scalar(b"t_hat", &t_hat);
+        let w = transcript.challenge_scalar::<E>(b"w");
+        let q = g * &w;
+
+        let y_inv = y.invert().expect("challenge y is zero");
+        let hs_prime: Vec<Point<E>> = hs
+            .iter()
+            .zip(powers(&y_inv, n))
+            .map(|(h, y_inv)| h * y_inv)
+            .collect();
+        let inner_product = InnerProductProof::prove(&mut transcript, &q, gs, hs_prime, l, r);
+
+        RangeProof {
+            commitment,
+            big_a,
+            big_s,
+            big_t1,
+            big_t2,
+            tau_x,
+            mu,
+            t_hat,
+            inner_product,
+        }
+    }
+
+    /// Verifies that [commitment](Self::commitment) commits to a value in range `[0; 2^n)`
+    pub fn verify(&self, n: usize) -> Result<(), ProofError> {
+        self.verify_with_blinding_base(Point::base_point2(), n)
+    }
+
+    /// Verifies a proof produced by [prove_with_blinding_base](Self::prove_with_blinding_base)
+    pub fn verify_with_blinding_base(&self, h: &Point<E>, n: usize) -> Result<(), ProofError> {
+        if !is_valid_bit_length::<E>(n) {
+            return Err(ProofError);
+        }
+        let rounds = n.trailing_zeros() as usize;
+        let ipp = &self.inner_product;
+        if ipp.big_l.len() != rounds || ipp.big_r.len() != rounds {
+            return Err(ProofError);
+        }
+        let g = Point::<E>::generator();
+        let (gs, hs) = generators::<E>(n);
+
+        let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+        transcript.append_message(b"n", &(n as u64).to_be_bytes());
+        transcript.append_point(b"H", h);
+        transcript.append_point(b"V", &self.commitment);
+        transcript.append_point(b"A", &self.big_a);
+        transcript.append_point(b"S", &self.big_s);
+        let y = transcript.challenge_scalar::<E>(b"y");
+        let z = transcript.challenge_scalar::<E>(b"z");
+        transcript.append_point(b"T1", &self.big_t1);
+        transcript.append_point(b"T2", &self.big_t2);
+        let x = transcript.challenge_scalar::<E>(b"x");
+        transcript.append_scalar(b"tau_x", &self.tau_x);
+        transcript.append_scalar(b"mu", &self.mu);
+        transcript.append_scalar(b"t_hat", &self.t_hat);
+        let w = transcript.challenge_scalar::<E>(b"w");
+        let mut u = Vec::with_capacity(rounds);
+        for (big_l, big_r) in ipp.big_l.iter().zip(&ipp.big_r) {
+            transcript.append_point(b"L", big_l);
+            transcript.append_point(b"R", big_r);
+            u.push(transcript.challenge_scalar::<E>(b"u"));
+        }
+        let u_inv: Vec<Scalar<E>> = u
+            .iter()
+            .map(|u| u.invert())
+            .collect::<Option<_>>()
+            .ok_or(ProofError)?;
+        let y_inv = y.invert().ok_or(ProofError)?;
+
+        let z2 = &z * &z;
+        let z3 = &z2 * &z;
+        let y_n = powers(&y, n);
+        let y_inv_n = powers(&y_inv, n);
+        let two_n = powers(&Scalar::from(2), n);
+
+        // Check that t_hat = t(x): t_hat·G + tau_x·H = z^2·V + delta(y,z)·G + x·T1 + x^2·T2
+        let delta =
+            (&z - &z2) * y_n.iter().sum::<Scalar<E>>() - &z3 * two_n.iter().sum::<Scalar<E>>();
+        let lhs = g * &self.t_hat + h * &self.tau_x;
+        let rhs = Point::multi_scalar_mul(
+            &[
+                self.commitment.clone(),
+                g.to_point(),
+                self.big_t1.clone(),
+                self.big_t2.clone(),
+            ],
+            &[z2.clone(), delta, x.clone(), &x * &x],
+        );
+        if lhs != rhs {
+            return Err(ProofError);
+        }
+
+        // Check inner product proof for l(x), r(x), all folded into a single equation:
+        // A + x·S - z·<1, g> + <z·y^n + z^2·2^n, h'> - mu·H + t_hat·Q + Σ (u_j^2·L_j + u_j^-2·R_j)
+        //   = a·<s, g> + b·<s^-1, h'> + a·b·Q
+        // where h' = y^-n ∘ h, Q = w·G, and s_i is a product of u_j or u_j^-1 depending on bits of i
+        let s: Vec<Scalar<E>> = (0..n)
+            .map(|i| {
+                (0..rounds)
+                    .map(|j| {
+                        if (i >> (rounds - 1 - j)) & 1 == 1 {
+                            &u[j]
+                        } else {
+                            &u_inv[j]
+                        }
+                    })
+                    .product()
+            })
+            .collect();
+
+        let mut points = Vec::with_capacity(2 * n + 2 * rounds + 4);
+        let mut scalars = Vec::with_capacity(2 * n + 2 * rounds + 4);
+        points.extend([
+            self.big_a.clone(),
+            self.big_s.clone(),
+            h.clone(),
+            g.to_point(),
+        ]);
+        scalars.extend([
+            Scalar::one(),
+            x,
+            -&self.mu,
+            w * (&self.t_hat - &ipp.a * &ipp.b),
+        ]);
+        for (g_i, s_i) in gs.into_iter().zip(&s) {
+            points.push(g_i);
+            scalars.push(-&z - &ipp.a * s_i);
+        }
+        for (i, h_i) in hs.into_iter().enumerate() {
+            points.push(h_i);
+            let s_inv_i = &s[n - 1 - i];
+            scalars.push(&z + (&z2 * &two_n[i] - &ipp.b * s_inv_i) * &y_inv_n[i]);
+        }
+        for (j, (big_l, big_r)) in ipp.big_l.iter().zip(&ipp.big_r).enumerate() {
+            points.push(big_l.clone());
+            scalars.push(&u[j] * &u[j]);
+            points.push(big_r.clone());
+            scalars.push(&u_inv[j] * &u_inv[j]);
+        }
+
+        if Point::multi_scalar_mul(&points, &scalars).is_zero() {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+impl<E: Curve> InnerProductProof<E> {
+    fn prove(
+        transcript: &mut Transcript,
+        q: &Point<E>,
+        mut g: Vec<Point<E>>,
+        mut h: Vec<Point<E>>,
+        mut a: Vec<Scalar<E>>,
+        mut b: Vec<Scalar<E>>,
+    ) -> Self {
+        let mut big_l = vec![];
+        let mut big_r = vec![];
+        while a.len() > 1 {
+            let k = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(k);
+            let (b_lo, b_hi) = b.split_at(k);
+            let (g_lo, g_hi) = g.split_at(k);
+            let (h_lo, h_hi) = h.split_at(k);
+
+            let c_l = inner_product(a_lo, b_hi);
+            let c_r = inner_product(a_hi, b_lo);
+            let l = commit_vector(g_hi, a_lo) + commit_vector(h_lo, b_hi) + q * c_l;
+            let r = commit_vector(g_lo, a_hi) + commit_vector(h_hi, b_lo) + q * c_r;
+
+            transcript.append_point(b"L", &l);
+            transcript.append_point(b"R", &r);
+            let u = transcript.challenge_scalar::<E>(b"u");
+            let u_inv = u.invert().expect("challenge u is zero");
+
+            a = fold(a_lo, a_hi, &u, &u_inv);
+            b = fold(b_lo, b_hi, &u_inv, &u);
+            g = g_lo
+                .iter()
+                .zip(g_hi)
+                .map(|(lo, hi)| lo * &u_inv + hi * &u)
+                .collect();
+            h = h_lo
+                .iter()
+                .zip(h_hi)
+                .map(|(lo, hi)| lo * &u + hi * &u_inv)
+                .collect();
+            big_l.push(l);
+            big_r.push(r);
+        }
+        InnerProductProof {
+            big_l,
+            big_r,
+            a: a[0].clone(),
+            b: b[0].clone(),
+        }
+    }
+}
+
+fn is_valid_bit_length<E: Curve>(n: usize) -> bool {
+    n.is_power_of_two() && n < Scalar::<E>::group_order().bit_length()
+}
+
+/// Derives vector generators `g` and `h` of length `n`
+fn generators<E: Curve>(n: usize) -> (Vec<Point<E>>, Vec<Point<E>>) {
+    let derive = |dst: &[u8]| {
+        (0..n as u64)
+            .map(|i| Point::hash_to_curve(&i.to_be_bytes(), dst))
+            .collect()
+    };
+    (derive(GENERATORS_G_DST), derive(GENERATORS_H_DST))
+}
+
+fn powers<E: Curve>(x: &Scalar<E>, n: usize) -> Vec<Scalar<E>> {
+    iter::successors(Some(Scalar::one()), |p| Some(p * x))
+        .take(n)
+        .collect()
+}
+
+fn inner_product<E: Curve>(a: &[Scalar<E>], b: &[Scalar<E>]) -> Scalar<E> {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Computes `<scalars, points>` for secret scalars
+///
+/// Unlike [Point::multi_scalar_mul], it's a sum of regular scalar multiplications.
+fn commit_vector<E: Curve>(points: &[Point<E>], scalars: &[Scalar<E>]) -> Point<E> {
+    points.iter().zip(scalars).map(|(p, s)| p * s).sum()
+}
+
+fn fold<E: Curve>(
+    lo: &[Scalar<E>],
+    hi: &[Scalar<E>],
+    lo_coef: &Scalar<E>,
+    hi_coef: &Scalar<E>,
+) -> Vec<Scalar<E>> {
+    lo.iter()
+        .zip(hi)
+        .map(|(lo, hi)| lo * lo_coef + hi * hi_coef)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+    use crate::BigInt;
+
+    test_for_all_curves!(test_range_proof_in_range);
+    fn test_range_proof_in_range<E: Curve>() {
+        let n = 16;
+        for value in [0u64, (1 << n) - 1] {
+            let blinding = Scalar::random();
+            let proof = RangeProof::<E>::prove(&Scalar::from(value), &blinding, n);
+            assert_eq!(
+                proof.commitment,
+                Point::generator() * Scalar::from(value) + Point::base_point2() * blinding
+            );
+            assert!(proof.verify(n).is_ok());
+        }
+    }
+
+    test_for_all_curves!(test_range_proof_small_bit_lengths);
+    fn test_range_proof_small_bit_lengths<E: Curve>() {
+        for n in [1, 2, 4, 8] {
+            let proof = RangeProof::<E>::prove(&Scalar::from(1u16), &Scalar::random(), n);
+            assert!(proof.verify(n).is_ok());
+        }
+    }
+
+    test_for_all_curves!(test_range_proof_out_of_range);
+    fn test_range_proof_out_of_range<E: Curve>() {
+        let n = 8;
+        let out_of_range = [
+            Scalar::<E>::from(1u32 << n),
+            Scalar::from(12345u32),
+            -Scalar::<E>::from(1u16),
+        ];
+        for value in out_of_range {
+            let proof = RangeProof::<E>::prove(&value, &Scalar::random(), n);
+            assert!(proof.verify(n).is_err());
+        }
+    }
+
+    test_for_all_curves!(test_range_proof_tampered);
+    fn test_range_proof_tampered<E: Curve>() {
+        let n = 8;
+        let proof = RangeProof::<E>::prove(&Scalar::from(42u16), &Scalar::random(), n);
+        assert!(proof.verify(n).is_ok());
+
+        // Proof is bound to the bit length
+        assert!(proof.verify(4).is_err());
+        assert!(proof.verify(16).is_err());
+        assert!(proof.verify(3).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.commitment = &tampered.commitment + Point::generator();
+        assert!(tampered.verify(n).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.t_hat = &tampered.t_hat + Scalar::one();
+        assert!(tampered.verify(n).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.inner_product.a = &tampered.inner_product.a + Scalar::one();
+        assert!(tampered.verify(n).is_err());
+
+        let mut tampered = proof;
+        tampered.inner_product.big_l.swap(0, 1);
+        assert!(tampered.verify(n).is_err());
+    }
+
+    test_for_all_curves!(test_range_proof_with_blinding_base);
+    fn test_range_proof_with_blinding_base<E: Curve>() {
+        let n = 8;
+        let h = Point::<E>::generator() * Scalar::random();
+        let blinding = Scalar::random();
+        let proof = RangeProof::prove_with_blinding_base(&Scalar::from(42u16), &blinding, &h, n);
+        assert_eq!(
+            proof.commitment,
+            Point::generator() * Scalar::from(42u16) + &h * &blinding
+        );
+        assert!(proof.verify_with_blinding_base(&h, n).is_ok());
+        assert!(proof.verify(n).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_proof_panics_if_bit_length_is_not_power_of_two() {
+        use crate::elliptic::curves::Secp256k1;
+        RangeProof::<Secp256k1>::prove(&Scalar::from(1u16), &Scalar::random(), 24);
+    }
+
+    #[test]
+    fn test_range_proof_max_bit_length() {
+        use crate::elliptic::curves::Secp256k1;
+        let n = 128;
+        let value = Scalar::<Secp256k1>::from(BigInt::from(2).pow(128) - 1);
+        let proof = RangeProof::prove(&value, &Scalar::random(), n);
+        assert!(proof.verify(n).is_ok());
+        // 2^256 exceeds group order
+        assert!(proof.verify(256).is_err());
+    }
+}
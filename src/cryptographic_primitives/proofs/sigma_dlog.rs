@@ -89,4 +89,20 @@ mod tests {
         let dlog_proof = DLogProof::<E, H>::prove(&witness);
         assert!(DLogProof::verify(&dlog_proof).is_ok());
     }
+
+    crate::test_for_all_curves_and_hashes!(test_dlog_proof_tampered_response);
+    fn test_dlog_proof_tampered_response<E: Curve, H: Digest + Clone>() {
+        let witness = Scalar::random();
+        let mut dlog_proof = DLogProof::<E, H>::prove(&witness);
+        dlog_proof.challenge_response = &dlog_proof.challenge_response + Scalar::from(1);
+        assert!(DLogProof::verify(&dlog_proof).is_err());
+    }
+
+    crate::test_for_all_curves_and_hashes!(test_dlog_proof_tampered_statement);
+    fn test_dlog_proof_tampered_statement<E: Curve, H: Digest + Clone>() {
+        let witness = Scalar::random();
+        let mut dlog_proof = DLogProof::<E, H>::prove(&witness);
+        dlog_proof.pk = Point::generator() * Scalar::random();
+        assert!(DLogProof::verify(&dlog_proof).is_err());
+    }
 }
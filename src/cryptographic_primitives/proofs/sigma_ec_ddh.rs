@@ -131,6 +131,30 @@ mod tests {
         };
         let w = ECDDHWitness { x };
         let proof = ECDDHProof::<E, H>::prove(&w, &delta);
-        assert!(!proof.verify(&delta).is_ok());
+        assert!(proof.verify(&delta).is_err());
+    }
+
+    test_for_all_curves_and_hashes!(test_ecddh_proof_bound_to_statement);
+    fn test_ecddh_proof_bound_to_statement<E: Curve, H: Digest + Clone>() {
+        let x = Scalar::random();
+        let g1 = Point::generator() * Scalar::random();
+        let g2 = Point::generator() * Scalar::random();
+        let delta = ECDDHStatement {
+            h1: &g1 * &x,
+            h2: &g2 * &x,
+            g1,
+            g2,
+        };
+        let proof = ECDDHProof::<E, H>::prove(&ECDDHWitness { x: x.clone() }, &delta);
+        assert!(proof.verify(&delta).is_ok());
+
+        // the same logs with respect to other generators
+        let g1 = Point::generator() * Scalar::random();
+        let other_delta = ECDDHStatement {
+            h1: &g1 * &x,
+            g1,
+            ..delta
+        };
+        assert!(proof.verify(&other_delta).is_err());
     }
 }
@@ -0,0 +1,377 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Verifiable encryption of a secret share to a recipient public key
+//!
+//! Allows a dealer to publish encrypted shares instead of sending them over private channels:
+//! anyone can check that ciphertext encrypts a discrete logarithm of the share commitment
+//! `S = s·G` (e.g. obtained via [VerifiableSS::get_point_commitment]), and only recipient can
+//! decrypt it.
+//!
+//! Share is split into [CHUNK_BITS]-bits chunks `s = Σ 2^(16·j)·s_j`, every chunk is encrypted via
+//! ElGamal in the exponent: `(c1_j, c2_j) = (r_j·G, s_j·G + r_j·Y)`. Proof consists of:
+//! * Proof that every `(c1_j, c2_j)` is a valid ElGamal encryption (see [HomoELGamalProof])
+//! * Proof that every chunk is in range `[0; 2^16)` (see [RangeProof]), so recipient can
+//!   efficiently recover `s_j` from `s_j·G`
+//! * Proof that chunks add up to `s`, ie. `Σ 2^(16·j)·c2_j - S = R·Y` and `Σ 2^(16·j)·c1_j = R·G`
+//!   for the same `R` (see [ECDDHProof])
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::proofs::verifiable_encryption;
+//! # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! let recipient_sk = Scalar::<Secp256k1>::random();
+//! let recipient_pk = Point::generator() * &recipient_sk;
+//!
+//! let share = Scalar::random();
+//! let (ciphertext, proof) =
+//!     verifiable_encryption::encrypt_and_prove::<_, sha2::Sha256>(&share, &recipient_pk);
+//!
+//! // Anyone can verify the ciphertext against the share commitment
+//! let commitment = Point::generator() * &share;
+//! assert!(verifiable_encryption::verify(&ciphertext, &proof, &commitment, &recipient_pk).is_ok());
+//!
+//! // Recipient decrypts the share
+//! assert_eq!(verifiable_encryption::decrypt(&ciphertext, &recipient_sk), Some(share));
+//! ```
+//!
+//! [VerifiableSS::get_point_commitment]: crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS::get_point_commitment
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::proofs::range_proof::RangeProof;
+use crate::cryptographic_primitives::proofs::sigma_correct_homomorphic_elgamal_enc::{
+    HomoELGamalProof, HomoElGamalStatement, HomoElGamalWitness,
+};
+use crate::cryptographic_primitives::proofs::sigma_ec_ddh::{
+    ECDDHProof, ECDDHStatement, ECDDHWitness,
+};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::ProofError;
+
+/// Size of a chunk in bits
+pub const CHUNK_BITS: usize = 16;
+
+/// Encrypted share: ElGamal ciphertext `(r_j·G, s_j·G + r_j·Y)` of every chunk
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Ciphertext<E: Curve> {
+    pub chunks: Vec<(Point<E>, Point<E>)>,
+}
+
+/// Proof that [Ciphertext] encrypts a discrete log of share commitment
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifiableEncryptionProof<E: Curve, H: Digest + Clone> {
+    pub chunk_proofs: Vec<HomoELGamalProof<E, H>>,
+    pub range_proofs: Vec<RangeProof<E>>,
+    pub sum_proof: ECDDHProof<E, H>,
+}
+
+/// Encrypts a share to the recipient public key, and proves correctness of encryption
+pub fn encrypt_and_prove<E: Curve, H: Digest + Clone>(
+    share: &Scalar<E>,
+    recipient_pk: &Point<E>,
+) -> (Ciphertext<E>, VerifiableEncryptionProof<E, H>) {
+    let s = share.to_bigint();
+    let chunks = (0..chunks_count::<E>()).map(|j| {
+        Scalar::from(
+            (0..CHUNK_BITS)
+                .filter(|&i| s.test_bit(j * CHUNK_BITS + i))
+                .map(|i| 1u32 << i)
+                .sum::<u32>(),
+        )
+    });
+    encrypt_chunks_and_prove(chunks, share, recipient_pk)
+}
+
+/// Encrypts given chunks of a share, and proves correctness of encryption
+///
+/// Chunks are not checked to be in range, so range proofs of out-of-range chunks won't verify.
+fn encrypt_chunks_and_prove<E: Curve, H: Digest + Clone>(
+    chunks: impl Iterator<Item = Scalar<E>>,
+    share: &Scalar<E>,
+    recipient_pk: &Point<E>,
+) -> (Ciphertext<E>, VerifiableEncryptionProof<E, H>) {
+    let g = Point::<E>::generator();
+
+    let mut ciphertext_chunks = Vec::with_capacity(chunks_count::<E>());
+    let mut chunk_proofs = Vec::with_capacity(chunks_count::<E>());
+    let mut range_proofs = Vec::with_capacity(chunks_count::<E>());
+    let mut r_sum = Scalar::<E>::zero();
+    for (m, chunk_base) in chunks.zip(chunk_bases::<E>()) {
+        let r = Scalar::random();
+        let c1 = g * &r;
+        let c2 = g * &m + recipient_pk * &r;
+
+        chunk_proofs.push(HomoELGamalProof::prove(
+            &HomoElGamalWitness {
+                r: r.clone(),
+                x: m.clone(),
+            },
+            &chunk_statement(recipient_pk, &c1, &c2),
+        ));
+        range_proofs.push(RangeProof::prove_with_blinding_base(
+            &m,
+            &r,
+            recipient_pk,
+            CHUNK_BITS,
+        ));
+        r_sum = r_sum + chunk_base * r;
+        ciphertext_chunks.push((c1, c2));
+    }
+    let ciphertext = Ciphertext {
+        chunks: ciphertext_chunks,
+    };
+
+    let commitment = g * share;
+    let sum_proof = ECDDHProof::prove(
+        &ECDDHWitness { x: r_sum },
+        &sum_statement(&ciphertext, &commitment, recipient_pk),
+    );
+
+    let proof = VerifiableEncryptionProof {
+        chunk_proofs,
+        range_proofs,
+        sum_proof,
+    };
+    (ciphertext, proof)
+}
+
+/// Verifies that `ciphertext` encrypts discrete log of `share_commitment` under `recipient_pk`
+pub fn verify<E: Curve, H: Digest + Clone>(
+    ciphertext: &Ciphertext<E>,
+    proof: &VerifiableEncryptionProof<E, H>,
+    share_commitment: &Point<E>,
+    recipient_pk: &Point<E>,
+) -> Result<(), ProofError> {
+    let n = chunks_count::<E>();
+    if recipient_pk.is_zero()
+        || ciphertext.chunks.len() != n
+        || proof.chunk_proofs.len() != n
+        || proof.range_proofs.len() != n
+    {
+        return Err(ProofError);
+    }
+
+    // Cheaper checks go first
+    proof
+        .sum_proof
+        .verify(&sum_statement(ciphertext, share_commitment, recipient_pk))?;
+    for ((c1, c2), chunk_proof) in ciphertext.chunks.iter().zip(&proof.chunk_proofs) {
+        chunk_proof.verify(&chunk_statement(recipient_pk, c1, c2))?;
+    }
+    for ((_, c2), range_proof) in ciphertext.chunks.iter().zip(&proof.range_proofs) {
+        if range_proof.commitment != *c2 {
+            return Err(ProofError);
+        }
+        range_proof.verify_with_blinding_base(recipient_pk, CHUNK_BITS)?;
+    }
+    Ok(())
+}
+
+/// Decrypts a share
+///
+/// Returns `None` if any chunk doesn't decrypt to an integer in range `[0; 2^16)`, which
+/// never happens for ciphertexts that passed [verification](verify).
+pub fn decrypt<E: Curve>(
+    ciphertext: &Ciphertext<E>,
+    recipient_sk: &Scalar<E>,
+) -> Option<Scalar<E>> {
+    let g = Point::<E>::generator();
+
+    // Baby-step giant-step: m = i·2^8 + j, where i, j < 2^8
+    let steps = 1u32 << (CHUNK_BITS / 2);
+    let mut baby_steps = HashMap::with_capacity(steps as usize);
+    let mut p = Point::<E>::zero();
+    for j in 0..steps {
+        baby_steps.insert(p.to_bytes(true).to_vec(), j);
+        p = p + g;
+    }
+    let giant_step = -(g * Scalar::from(steps));
+
+    let mut share = Scalar::zero();
+    for ((c1, c2), chunk_base) in ciphertext.chunks.iter().zip(chunk_bases::<E>()) {
+        let mut p = c2 - c1 * recipient_sk;
+        let mut m = None;
+        for i in 0..steps {
+            if let Some(j) = baby_steps.get(&*p.to_bytes(true)) {
+                m = Some(i * steps + j);
+                break;
+            }
+            p = p + &giant_step;
+        }
+        share = share + chunk_base * Scalar::from(m?);
+    }
+    Some(share)
+}
+
+fn chunks_count<E: Curve>() -> usize {
+    (Scalar::<E>::group_order().bit_length() - 1) / CHUNK_BITS + 1
+}
+
+/// Iterator over `2^(16·j)` for every chunk `j`
+fn chunk_bases<E: Curve>() -> impl Iterator<Item = Scalar<E>> {
+    let step = Scalar::<E>::from(1u32 << CHUNK_BITS);
+    std::iter::successors(Some(Scalar::<E>::one()), move |b| Some(b * &step))
+        .take(chunks_count::<E>())
+}
+
+fn chunk_statement<E: Curve>(
+    recipient_pk: &Point<E>,
+    c1: &Point<E>,
+    c2: &Point<E>,
+) -> HomoElGamalStatement<E> {
+    HomoElGamalStatement {
+        G: Point::generator().to_point(),
+        H: Point::generator().to_point(),
+        Y: recipient_pk.clone(),
+        D: c2.clone(),
+        E: c1.clone(),
+    }
+}
+
+fn sum_statement<E: Curve>(
+    ciphertext: &Ciphertext<E>,
+    share_commitment: &Point<E>,
+    recipient_pk: &Point<E>,
+) -> ECDDHStatement<E> {
+    let (c1_sum, c2_sum) = ciphertext.chunks.iter().zip(chunk_bases::<E>()).fold(
+        (Point::zero(), Point::zero()),
+        |(c1_sum, c2_sum), ((c1, c2), chunk_base)| {
+            (c1_sum + c1 * &chunk_base, c2_sum + c2 * &chunk_base)
+        },
+    );
+    ECDDHStatement {
+        g1: Point::generator().to_point(),
+        h1: c1_sum,
+        g2: recipient_pk.clone(),
+        h2: c2_sum - share_commitment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Sha256;
+
+    use super::*;
+    use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+
+    // Proof consists of many range proofs, it's too slow to run for every curve in debug builds
+    crate::test_for_all! {[] test_encrypt_verify_decrypt =>
+        secp256k1 = crate::elliptic::curves::Secp256k1,
+        p256 = crate::elliptic::curves::Secp256r1,
+        ristretto = crate::elliptic::curves::Ristretto,
+    }
+    fn test_encrypt_verify_decrypt<E: Curve>() {
+        let (vss, shares) = VerifiableSS::<E, Sha256>::share(1, 2, &Scalar::random());
+        let recipient_sk = Scalar::<E>::random();
+        let recipient_pk = Point::generator() * &recipient_sk;
+
+        let (ciphertext, proof) = encrypt_and_prove::<E, Sha256>(&shares[1], &recipient_pk);
+        assert_eq!(ciphertext.chunks.len(), chunks_count::<E>());
+        let commitment = vss.get_point_commitment(2);
+        assert!(verify(&ciphertext, &proof, &commitment, &recipient_pk).is_ok());
+
+        let decrypted = decrypt(&ciphertext, &recipient_sk).unwrap();
+        assert_eq!(decrypted, shares[1]);
+        assert!(vss.validate_share(&decrypted, 2).is_ok());
+
+        // Ciphertext doesn't match another commitment or another recipient
+        let another_commitment = vss.get_point_commitment(1);
+        assert!(verify(&ciphertext, &proof, &another_commitment, &recipient_pk).is_err());
+        let another_pk = Point::generator() * Scalar::random();
+        assert!(verify(&ciphertext, &proof, &commitment, &another_pk).is_err());
+        assert_ne!(
+            decrypt(&ciphertext, &Scalar::random()),
+            Some(shares[1].clone())
+        );
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_is_rejected() {
+        use crate::elliptic::curves::Secp256k1;
+
+        let share = Scalar::<Secp256k1>::random();
+        let commitment = Point::generator() * &share;
+        let recipient_sk = Scalar::<Secp256k1>::random();
+        let recipient_pk = Point::generator() * &recipient_sk;
+        let (ciphertext, proof) = encrypt_and_prove::<_, Sha256>(&share, &recipient_pk);
+        assert!(verify(&ciphertext, &proof, &commitment, &recipient_pk).is_ok());
+
+        // Chunk encrypts another value
+        let mut tampered = ciphertext.clone();
+        tampered.chunks[3].1 = &tampered.chunks[3].1 + Point::generator();
+        assert!(verify(&tampered, &proof, &commitment, &recipient_pk).is_err());
+        assert_ne!(decrypt(&tampered, &recipient_sk), Some(share.clone()));
+
+        // Chunks are reordered
+        let mut tampered = ciphertext.clone();
+        tampered.chunks.swap(0, 1);
+        assert!(verify(&tampered, &proof, &commitment, &recipient_pk).is_err());
+
+        // Chunk is missing
+        let mut tampered = ciphertext.clone();
+        tampered.chunks.pop();
+        assert!(verify(&tampered, &proof, &commitment, &recipient_pk).is_err());
+
+        // Proofs are taken from another encryption of the same share
+        let (another_ciphertext, another_proof) =
+            encrypt_and_prove::<_, Sha256>(&share, &recipient_pk);
+        assert!(verify(
+            &another_ciphertext,
+            &another_proof,
+            &commitment,
+            &recipient_pk
+        )
+        .is_ok());
+        assert!(verify(&ciphertext, &another_proof, &commitment, &recipient_pk).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_chunk_is_rejected() {
+        use crate::elliptic::curves::Secp256k1;
+
+        // Dealer moves 1 from the second chunk to the first one, so chunks still add up to the
+        // share, but the first chunk is too large for the recipient to decrypt
+        let share = Scalar::<Secp256k1>::from(1u32 << CHUNK_BITS);
+        let commitment = Point::generator() * &share;
+        let recipient_sk = Scalar::<Secp256k1>::random();
+        let recipient_pk = Point::generator() * &recipient_sk;
+        let chunks = std::iter::once(Scalar::from(1u32 << CHUNK_BITS))
+            .chain(std::iter::repeat_with(Scalar::zero).take(chunks_count::<Secp256k1>() - 1));
+        let (ciphertext, proof) =
+            encrypt_chunks_and_prove::<_, Sha256>(chunks, &share, &recipient_pk);
+
+        // Everything but the range proof of the first chunk is honest
+        assert!(proof
+            .sum_proof
+            .verify(&sum_statement(&ciphertext, &commitment, &recipient_pk))
+            .is_ok());
+        for ((c1, c2), chunk_proof) in ciphertext.chunks.iter().zip(&proof.chunk_proofs) {
+            assert!(chunk_proof
+                .verify(&chunk_statement(&recipient_pk, c1, c2))
+                .is_ok());
+        }
+        for (j, range_proof) in proof.range_proofs.iter().enumerate() {
+            assert_eq!(range_proof.commitment, ciphertext.chunks[j].1);
+            assert_eq!(
+                range_proof
+                    .verify_with_blinding_base(&recipient_pk, CHUNK_BITS)
+                    .is_ok(),
+                j != 0
+            );
+        }
+
+        assert!(verify(&ciphertext, &proof, &commitment, &recipient_pk).is_err());
+        assert_eq!(decrypt(&ciphertext, &recipient_sk), None);
+    }
+}
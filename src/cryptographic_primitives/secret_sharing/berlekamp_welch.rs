@@ -0,0 +1,179 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use std::iter;
+
+use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Scalar};
+use crate::ErrorSS::{self, VerifyShareError};
+
+impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
+    /// Reconstructs a secret from shares `values` evaluated at `points`, correcting up to
+    /// `max_errors` corrupted shares
+    ///
+    /// Implements Berlekamp-Welch decoder: finds error locator polynomial $E$ (monic, of degree
+    /// `max_errors`) and polynomial $Q$ of degree at most $t + $ `max_errors` such that
+    /// $Q(x_i) = y_i E(x_i)$ for every share, then the dealer's polynomial is $Q / E$. Unlike
+    /// [reconstruct_robust](Self::reconstruct_robust), runs in polynomial time: it solves a
+    /// single linear system. Requires at least $t + 1 + 2 \cdot$ `max_errors` shares. `points`
+    /// are the actual evaluation points (ie. `index + 1`), they must be pairwise distinct.
+    ///
+    /// Returns [ErrorSS::MismatchedLengths] if `points` and `values` have different lengths,
+    /// [ErrorSS::BelowThreshold] if there are not enough shares to correct `max_errors` errors,
+    /// and [ErrorSS::VerifyShareError] if there are more than `max_errors` corrupted shares.
+    pub fn reconstruct_bw(
+        &self,
+        points: &[Scalar<E>],
+        values: &[Scalar<E>],
+        max_errors: usize,
+    ) -> Result<Scalar<E>, ErrorSS> {
+        if points.len() != values.len() {
+            return Err(ErrorSS::MismatchedLengths);
+        }
+        let t = usize::from(self.parameters.threshold);
+        let e = max_errors;
+        if points.len() < t + 1 + 2 * e {
+            return Err(ErrorSS::BelowThreshold);
+        }
+
+        // Unknowns are coefficients q_0, ..., q_{t+e} of Q and e_0, ..., e_{e-1} of E (e_e = 1):
+        // sum_j q_j x^j - y sum_{j<e} e_j x^j = y x^e
+        let rows = points
+            .iter()
+            .zip(values)
+            .map(|(x, y)| {
+                let powers: Vec<Scalar<E>> =
+                    iter::successors(Some(Scalar::from(1)), |p| Some(p * x))
+                        .take(t + e + 1)
+                        .collect();
+                let mut row = powers.clone();
+                row.extend(powers[..e].iter().map(|p| -(y * p)));
+                row.push(y * &powers[e]);
+                row
+            })
+            .collect();
+        let solution = solve_linear_system(rows, t + 2 * e + 1).ok_or(VerifyShareError)?;
+
+        let mut q = solution[..=t + e].to_vec();
+        let mut error_locator = solution[t + e + 1..].to_vec();
+        error_locator.push(Scalar::from(1));
+
+        // P = Q / E, remainder must be zero
+        let mut p = vec![Scalar::<E>::zero(); t + 1];
+        for i in (0..=t).rev() {
+            let lead = q[i + e].clone();
+            for (j, e_j) in error_locator.iter().enumerate() {
+                q[i + j] = &q[i + j] - &lead * e_j;
+            }
+            p[i] = lead;
+        }
+        if q.iter().any(|c| !c.is_zero()) {
+            return Err(VerifyShareError);
+        }
+
+        let p = Polynomial::from_coefficients(p);
+        let agreed = points
+            .iter()
+            .zip(values)
+            .filter(|(x, y)| &p.evaluate(x) == *y)
+            .count();
+        if agreed + e < points.len() {
+            return Err(VerifyShareError);
+        }
+        Ok(p.coefficients()[0].clone())
+    }
+}
+
+/// Solves linear system given as augmented matrix rows via Gaussian elimination
+///
+/// Returns any solution (free variables are set to zero), or None if system is inconsistent.
+fn solve_linear_system<E: Curve>(
+    mut rows: Vec<Vec<Scalar<E>>>,
+    unknowns: usize,
+) -> Option<Vec<Scalar<E>>> {
+    let mut pivots = vec![];
+    for col in 0..unknowns {
+        let r = pivots.len();
+        let pivot = match (r..rows.len()).find(|&i| !rows[i][col].is_zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(r, pivot);
+        let inv = rows[r][col].invert().expect("pivot is not zero");
+        for c in rows[r].iter_mut() {
+            *c = &*c * &inv;
+        }
+        let pivot_row = rows[r].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i == r || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (c, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *c = &*c - &factor * p;
+            }
+        }
+        pivots.push(col);
+    }
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[unknowns].is_zero())
+    {
+        return None;
+    }
+
+    let mut solution = vec![Scalar::zero(); unknowns];
+    for (r, &col) in pivots.iter().enumerate() {
+        solution[col] = rows[r][unknowns].clone();
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_reconstruct_bw);
+
+    fn test_reconstruct_bw<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (vss, shares) = VerifiableSS::<E, sha2::Sha256>::share(2, 9, &secret);
+        let points: Vec<Scalar<E>> = (1..=9u16).map(Scalar::from).collect();
+
+        // t + 1 + 2e = 9 shares tolerate e = 3 corrupted ones
+        let mut values = shares.to_vec();
+        for i in [1, 4, 8] {
+            values[i] = Scalar::random();
+        }
+        assert_eq!(vss.reconstruct_bw(&points, &values, 3), Ok(secret.clone()));
+        // Fewer actual errors than max_errors
+        assert_eq!(vss.reconstruct_bw(&points, &shares, 3), Ok(secret.clone()));
+        assert_eq!(
+            vss.reconstruct_bw(&points[..7], &values[..7], 2),
+            Ok(secret.clone())
+        );
+        assert_eq!(vss.reconstruct_bw(&points, &shares, 0), Ok(secret));
+
+        // Too many errors
+        values[6] = Scalar::random();
+        assert_eq!(
+            vss.reconstruct_bw(&points, &values, 3).err(),
+            Some(ErrorSS::VerifyShareError)
+        );
+        assert_eq!(
+            vss.reconstruct_bw(&points, &values, 4).err(),
+            Some(ErrorSS::BelowThreshold)
+        );
+        assert_eq!(
+            vss.reconstruct_bw(&points, &values[..8], 1).err(),
+            Some(ErrorSS::MismatchedLengths)
+        );
+    }
+}
@@ -8,22 +8,98 @@
 
 use std::convert::{TryFrom, TryInto};
 use std::num::NonZeroU16;
-use std::{fmt, ops};
+use std::{fmt, iter, ops};
 
+use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use typenum::Unsigned;
 
-use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
 use crate::cryptographic_primitives::proofs::sigma_dlog::DLogProof;
-use crate::cryptographic_primitives::secret_sharing::Polynomial;
-use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::cryptographic_primitives::secret_sharing::pedersen_vss::PedersenVSS;
+use crate::cryptographic_primitives::secret_sharing::{Polynomial, PolynomialDegree};
+use crate::elliptic::curves::{Curve, DeserializationError, ECPoint, ECScalar, Point, Scalar};
+use crate::marker::HashChoice;
+use crate::BigInt;
 use crate::ErrorSS::{self, VerifyShareError};
 
+/// Domain separation tag of [VerifiableSS::commitment_digest]
+const COMMITMENT_DIGEST_DST: &[u8] = b"CURV-FELDMAN-VSS-COMMITMENT-DIGEST";
+
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ShamirSecretSharing {
     pub threshold: u16,   //t
     pub share_count: u16, //n
 }
 
+impl ShamirSecretSharing {
+    /// Shares a secret among `n` parties, so that any `t+1` of them can reconstruct it
+    ///
+    /// Plain Shamir secret sharing: unlike [VerifiableSS::share], no commitments are computed,
+    /// which saves a point multiplication per polynomial coefficient. The tradeoff is that
+    /// parties cannot verify their shares, so a malicious dealer may distribute inconsistent
+    /// shares, and a malicious party may provide a wrong share during reconstruction without
+    /// being detected. Suitable only when the dealer and shareholders are trusted.
+    ///
+    /// `secret_shares[i]` is a share of the party at (0-based) index `i`.
+    pub fn share<E: Curve>(t: u16, n: u16, secret: &Scalar<E>) -> Vec<Scalar<E>> {
+        assert!(t < n);
+        Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone())
+            .evaluate_many_bigint(1..=n)
+            .collect()
+    }
+
+    /// Shares a secret via [plain Shamir](Self::share), sampling polynomial using given source of
+    /// randomness
+    ///
+    /// Seeded `rng` yields the same shares as [VerifiableSS::share_with_rng] does.
+    pub fn share_with_rng<E: Curve, R: RngCore + CryptoRng>(
+        t: u16,
+        n: u16,
+        secret: &Scalar<E>,
+        rng: &mut R,
+    ) -> Vec<Scalar<E>> {
+        assert!(t < n);
+        Polynomial::<E>::sample_exact_with_fixed_const_term_and_rng(t, secret.clone(), rng)
+            .evaluate_many_bigint(1..=n)
+            .collect()
+    }
+
+    /// Reconstructs a secret from shares of parties at (0-based) `indices`
+    ///
+    /// Returns [ErrorSS::MismatchedLengths] if `indices` and `shares` have different lengths,
+    /// and [ErrorSS::BelowThreshold] if there are fewer than `t+1` shares.
+    pub fn reconstruct<E: Curve>(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+    ) -> Result<Scalar<E>, ErrorSS> {
+        self.ensure_can_reconstruct(indices.len(), shares.len())?;
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        Ok((0..)
+            .zip(shares)
+            .map(|(j, share)| share * Polynomial::lagrange_basis(&Scalar::zero(), j, &points))
+            .sum())
+    }
+
+    pub(super) fn ensure_can_reconstruct(
+        &self,
+        indices: usize,
+        shares: usize,
+    ) -> Result<(), ErrorSS> {
+        if indices != shares {
+            Err(ErrorSS::MismatchedLengths)
+        } else if shares <= usize::from(self.threshold) {
+            Err(ErrorSS::BelowThreshold)
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// Feldman VSS, based on  Paul Feldman. 1987. A practical scheme for non-interactive verifiable secret sharing.
 /// In Foundations of Computer Science, 1987., 28th Annual Symposium on.IEEE, 427–43
 ///
@@ -50,8 +126,8 @@ pub struct VerifiableSS<E: Curve, H: Digest + Clone> {
 /// [low degree exponent interpolation]: crate::cryptographic_primitives::proofs::low_degree_exponent_interpolation
 #[derive(Clone)]
 pub struct SecretShares<E: Curve> {
-    shares: Vec<Scalar<E>>,
-    polynomial: Polynomial<E>,
+    pub(super) shares: Vec<Scalar<E>>,
+    pub(super) polynomial: Polynomial<E>,
 }
 
 impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
@@ -59,10 +135,43 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
         self.parameters.threshold + 1
     }
 
+    /// Public key `g^secret` of the shared secret, ie. commitment to the constant term
+    ///
+    /// In DKG, group public key is a sum of public keys of all dealers, see
+    /// [aggregate_public_keys].
+    pub fn public_key(&self) -> &Point<E> {
+        &self.commitments[0]
+    }
+
     // generate VerifiableSS from a secret
     pub fn share(t: u16, n: u16, secret: &Scalar<E>) -> (VerifiableSS<E, H>, SecretShares<E>) {
         assert!(t < n);
         let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
+        Self::share_polynomial(t, n, polynomial, secret)
+    }
+
+    /// Generates VerifiableSS from a secret, sampling polynomial using given source of randomness
+    ///
+    /// Same as [share](Self::share), but seeded `rng` yields the same shares and commitments,
+    /// which is useful for reproducible test vectors. Note that DLog proof is still randomized.
+    pub fn share_with_rng<R: RngCore + CryptoRng>(
+        t: u16,
+        n: u16,
+        secret: &Scalar<E>,
+        rng: &mut R,
+    ) -> (VerifiableSS<E, H>, SecretShares<E>) {
+        assert!(t < n);
+        let polynomial =
+            Polynomial::<E>::sample_exact_with_fixed_const_term_and_rng(t, secret.clone(), rng);
+        Self::share_polynomial(t, n, polynomial, secret)
+    }
+
+    pub(super) fn share_polynomial(
+        t: u16,
+        n: u16,
+        polynomial: Polynomial<E>,
+        secret: &Scalar<E>,
+    ) -> (VerifiableSS<E, H>, SecretShares<E>) {
         let shares = polynomial.evaluate_many_bigint(1..=n).collect();
 
         let g = Point::<E>::generator();
@@ -91,7 +200,7 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
         let t = self.parameters.threshold;
         let n = self.parameters.share_count;
 
-        let one = Scalar::<E>::from(1);
+        let one = Scalar::<E>::one();
         let poly = Polynomial::<E>::sample_exact_with_fixed_const_term(t, one.clone());
         let secret_shares_biased: Vec<_> = poly.evaluate_many_bigint(1..=n).collect();
         let secret_shares: Vec<_> = (0..secret_shares_biased.len())
@@ -112,24 +221,231 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
         )
     }
 
+    /// Shares zero among `n` parties, so that any `t+1` of them can reconstruct it
+    ///
+    /// Resulting scheme has the identity point as constant commitment, which lets everyone
+    /// check that the dealt secret is zero. Used to refresh existing scheme with the same
+    /// parameters, see [apply_refresh](Self::apply_refresh).
+    pub fn share_zero(t: u16, n: u16) -> (VerifiableSS<E, H>, SecretShares<E>) {
+        Self::share(t, n, &Scalar::zero())
+    }
+
+    /// Refreshes a secret share of party at (1-based) `index` using updates dealt via
+    /// [share_zero](Self::share_zero)
+    ///
+    /// Every update is a share of zero, so refreshed shares reconstruct the same secret as
+    /// before, while shares from before the refresh can't be combined with refreshed ones.
+    /// Updates may come from several parties, `update_shares[i]` is a share of the party received
+    /// from the dealer of `update_schemes[i]`. Returns refreshed scheme along with the refreshed
+    /// share.
+    ///
+    /// Returns [ErrorSS::VerifyShareError] if `old_share` doesn't match this scheme,
+    /// [ErrorSS::MismatchedLengths] if `update_schemes` and `update_shares` have different
+    /// lengths, [ErrorSS::MismatchedParameters] if any update has different parameters,
+    /// [ErrorSS::InvalidParameters] if there are more than `u16::MAX` updates, and
+    /// [ErrorSS::InvalidShare] with (0-based) position of an update that doesn't share zero
+    /// or whose share doesn't pass validation.
+    pub fn apply_refresh(
+        &self,
+        old_share: &Scalar<E>,
+        index: u16,
+        update_schemes: &[VerifiableSS<E, H>],
+        update_shares: &[Scalar<E>],
+    ) -> Result<(VerifiableSS<E, H>, Scalar<E>), ErrorSS> {
+        if update_schemes.len() != update_shares.len() {
+            return Err(ErrorSS::MismatchedLengths);
+        }
+        if update_schemes.len() > usize::from(u16::MAX) {
+            return Err(ErrorSS::InvalidParameters);
+        }
+        self.validate_share(old_share, index)?;
+
+        let g = Point::<E>::generator();
+        let mut refreshed = self.clone();
+        for (i, (scheme, share)) in (0u16..).zip(update_schemes.iter().zip(update_shares)) {
+            if scheme.parameters != self.parameters {
+                return Err(ErrorSS::MismatchedParameters);
+            }
+            let shares_zero =
+                scheme.validate_parameters().is_ok() && scheme.commitments[0].is_zero();
+            if !shares_zero || scheme.validate_share_public(&(g * share), index).is_err() {
+                return Err(ErrorSS::InvalidShare { index: i });
+            }
+            refreshed = refreshed.add(scheme)?;
+        }
+        let share = update_shares
+            .iter()
+            .fold(old_share.clone(), |acc, update| acc + update);
+        Ok((refreshed, share))
+    }
+
+    /// Reshares `my_share` of party at (1-based) `my_index` to new parameters `(t, n)`
+    ///
+    /// Unlike [apply_refresh](Self::apply_refresh), resharing allows changing both the threshold
+    /// and the set of parties. Every party in a set of at least `t_old+1` parties deals its share
+    /// via this method, and every new party combines received shares via
+    /// [combine_reshared](Self::combine_reshared). The secret stays the same, while old shares
+    /// are useless in combination with new ones.
+    ///
+    /// Returns [ErrorSS::VerifyShareError] if `my_share` doesn't match this scheme, and
+    /// [ErrorSS::InvalidParameters] unless `t < n`.
+    pub fn reshare_to(
+        &self,
+        my_share: &Scalar<E>,
+        my_index: u16,
+        t: u16,
+        n: u16,
+    ) -> Result<(VerifiableSS<E, H>, SecretShares<E>), ErrorSS> {
+        self.validate_share(my_share, my_index)?;
+        if t >= n {
+            return Err(ErrorSS::InvalidParameters);
+        }
+        Ok(Self::share(t, n, my_share))
+    }
+
+    /// Combines shares dealt via [reshare_to](Self::reshare_to) into a share of the same secret
+    /// under new parameters
+    ///
+    /// `shares[i]` is a share of the new party at (1-based) `my_index` received from the
+    /// dealer that held share of this scheme at (1-based) index `dealers[i]` and published
+    /// `schemes[i]`. Constant commitment of every scheme is checked to match public share of its
+    /// dealer, so dealers can't change the secret. Returns new scheme along with the new share.
+    ///
+    /// Returns [ErrorSS::MismatchedLengths] if `dealers`, `schemes` and `shares` have different
+    /// lengths, [ErrorSS::BelowThreshold] if there are fewer than `t_old+1` dealers,
+    /// [ErrorSS::DuplicateIndex] if dealers repeat, [ErrorSS::MismatchedParameters] if schemes
+    /// have different parameters, and [ErrorSS::InvalidShare] with (0-based) position of a dealer
+    /// whose scheme or share doesn't pass validation.
+    pub fn combine_reshared(
+        &self,
+        my_index: u16,
+        dealers: &[u16],
+        schemes: &[VerifiableSS<E, H>],
+        shares: &[Scalar<E>],
+    ) -> Result<(VerifiableSS<E, H>, Scalar<E>), ErrorSS> {
+        if dealers.len() != schemes.len() {
+            return Err(ErrorSS::MismatchedLengths);
+        }
+        self.parameters
+            .ensure_can_reconstruct(dealers.len(), shares.len())?;
+        // Dealer indices are nonzero `u16`, so some of them repeat if there are more
+        if dealers.len() > usize::from(u16::MAX) {
+            return Err(ErrorSS::DuplicateIndex);
+        }
+        let dealer_indices = (0u16..)
+            .zip(dealers)
+            .map(|(i, &dealer)| NonZeroU16::new(dealer).ok_or(ErrorSS::InvalidShare { index: i }))
+            .collect::<Result<Vec<_>, _>>()?;
+        ensure_distinct(&dealer_indices)?;
+
+        let g = Point::<E>::generator();
+        for (i, ((&dealer, scheme), share)) in (0u16..).zip(dealers.iter().zip(schemes).zip(shares))
+        {
+            if scheme.parameters != schemes[0].parameters {
+                return Err(ErrorSS::MismatchedParameters);
+            }
+            if scheme.validate_parameters().is_err()
+                || scheme.commitments[0] != self.get_point_commitment(dealer)
+                || scheme
+                    .validate_share_public(&(g * share), my_index)
+                    .is_err()
+            {
+                return Err(ErrorSS::InvalidShare { index: i });
+            }
+        }
+
+        let xs = dealers.iter().map(|&d| Scalar::from(d)).collect::<Vec<_>>();
+        let lambdas = (0..)
+            .take(dealers.len())
+            .map(|j| Polynomial::lagrange_basis(&Scalar::zero(), j, &xs))
+            .collect::<Vec<_>>();
+        let commitments = (0..schemes[0].commitments.len())
+            .map(|k| {
+                schemes
+                    .iter()
+                    .zip(&lambdas)
+                    .map(|(scheme, lambda)| &scheme.commitments[k] * lambda)
+                    .sum()
+            })
+            .collect();
+        let share = shares
+            .iter()
+            .zip(&lambdas)
+            .map(|(s, lambda)| s * lambda)
+            .sum();
+        // Constant commitment of new scheme equals to the one of this scheme, so the proof applies
+        Ok((
+            VerifiableSS {
+                parameters: schemes[0].parameters.clone(),
+                commitments,
+                proof: self.proof.clone(),
+            },
+            share,
+        ))
+    }
+
+    /// Adds two schemes, resulting scheme shares the sum of both secrets
+    ///
+    /// In DKG every party deals its own sharing, and the group secret is the sum of all dealt
+    /// secrets. Commitments of the resulting scheme are elementwise sums of commitments, and
+    /// a party's share in it is the sum of shares it received (see [combine_shares](Self::combine_shares)).
+    ///
+    /// Resulting scheme carries the proof of `self`, which doesn't prove knowledge of the
+    /// combined secret: proofs need to be checked for every dealer before combining, and
+    /// combined shares validated via [validate_share_public](Self::validate_share_public).
+    ///
+    /// Returns [ErrorSS::MismatchedParameters] if schemes have different parameters.
+    pub fn add(&self, other: &VerifiableSS<E, H>) -> Result<VerifiableSS<E, H>, ErrorSS> {
+        if self.parameters != other.parameters || self.commitments.len() != other.commitments.len()
+        {
+            return Err(ErrorSS::MismatchedParameters);
+        }
+        let commitments = self
+            .commitments
+            .iter()
+            .zip(&other.commitments)
+            .map(|(a, b)| a + b)
+            .collect();
+        Ok(VerifiableSS {
+            parameters: self.parameters.clone(),
+            commitments,
+            proof: self.proof.clone(),
+        })
+    }
+
+    /// Combines shares a party received from several dealers into its share of the sum of
+    /// their secrets, see [add](Self::add)
+    pub fn combine_shares(my_shares: &[Scalar<E>]) -> Scalar<E> {
+        my_shares.iter().sum()
+    }
+
     /// generate VerifiableSS from a secret and user defined x values (in case user wants to distribute point f(1), f(4), f(6) and not f(1),f(2),f(3))
+    ///
+    /// Shares can be validated via [validate_share](Self::validate_share) by passing the same index the
+    /// share was evaluated at, and reconstructed via [reconstruct_at_indices](Self::reconstruct_at_indices).
+    /// Returns [ErrorSS::MismatchedLengths] if number of `indicies` isn't equal to `n`, and
+    /// [ErrorSS::DuplicateIndex] if `indicies` contain duplicates.
+    ///
     /// NOTE: The caller should make sure that `t`, `n` and the contents of `index_vec` can't be controlled by a malicious party.
     pub fn share_at_indices<I>(
         t: u16,
         n: u16,
         secret: &Scalar<E>,
         indicies: I,
-    ) -> (VerifiableSS<E, H>, SecretShares<E>)
+    ) -> Result<(VerifiableSS<E, H>, SecretShares<E>), ErrorSS>
     where
         I: IntoIterator<Item = NonZeroU16>,
         I::IntoIter: ExactSizeIterator,
     {
-        let indicies = indicies.into_iter();
-        assert_eq!(usize::from(n), indicies.len());
+        let indicies: Vec<NonZeroU16> = indicies.into_iter().collect();
+        if usize::from(n) != indicies.len() {
+            return Err(ErrorSS::MismatchedLengths);
+        }
+        ensure_distinct(&indicies)?;
 
         let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
         let shares = polynomial
-            .evaluate_many_bigint(indicies.map(NonZeroU16::get))
+            .evaluate_many_bigint(indicies.into_iter().map(NonZeroU16::get))
             .collect();
 
         let g = Point::<E>::generator();
@@ -140,7 +456,7 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
             .collect::<Vec<Point<E>>>();
 
         let proof = DLogProof::<E, H>::prove(secret);
-        (
+        Ok((
             VerifiableSS {
                 parameters: ShamirSecretSharing {
                     threshold: t,
@@ -150,7 +466,42 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
                 proof,
             },
             SecretShares { shares, polynomial },
-        )
+        ))
+    }
+
+    /// Generates VerifiableSS from a secret for parties with different weights
+    ///
+    /// Party `i` receives `weights[i]` shares evaluated at a contiguous block of indices: the first
+    /// party gets indices `1..=weights[0]`, the second one gets the following `weights[1]` indices,
+    /// and so on. Secret can be reconstructed by any set of parties whose total weight exceeds `t`.
+    ///
+    /// Returns, for every party, a list of its shares paired with the index the share was evaluated
+    /// at. Shares can be validated via [validate_share](Self::validate_share), and the secret can be
+    /// reconstructed from flattened `(index, share)` pairs via
+    /// [reconstruct_at_indices](Self::reconstruct_at_indices).
+    ///
+    /// ## Panics
+    /// Panics if total weight doesn't fit into `u16`, or if `t` is not less than total weight.
+    #[allow(clippy::type_complexity)]
+    pub fn share_weighted(
+        t: u16,
+        weights: &[u16],
+        secret: &Scalar<E>,
+    ) -> (VerifiableSS<E, H>, Vec<Vec<(NonZeroU16, Scalar<E>)>>) {
+        let n = weights
+            .iter()
+            .try_fold(0u16, |acc, w| acc.checked_add(*w))
+            .expect("total weight exceeds u16::MAX");
+        let (vss_scheme, secret_shares) = Self::share(t, n, secret);
+
+        let mut indexed_shares = (1..=n)
+            .map(|i| NonZeroU16::new(i).expect("index is nonzero"))
+            .zip(secret_shares.iter().cloned());
+        let party_shares = weights
+            .iter()
+            .map(|&w| indexed_shares.by_ref().take(usize::from(w)).collect())
+            .collect();
+        (vss_scheme, party_shares)
     }
 
     // returns vector of coefficients
@@ -176,15 +527,239 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
         Polynomial::<E>::from_coefficients(coefficients.to_vec()).evaluate(&point)
     }
 
-    pub fn reconstruct(&self, indices: &[u16], shares: &[Scalar<E>]) -> Scalar<E> {
-        assert_eq!(shares.len(), indices.len());
-        assert!(shares.len() >= usize::from(self.reconstruct_limit()));
+    /// Evaluates polynomial with given `coefficients` (starting from the constant term) at
+    /// every point in `points`
+    ///
+    /// Uses a subproduct tree: points are paired up into products $\prod (x - x_i)$, and the
+    /// polynomial is reduced modulo these products on the way down the tree, so that at the
+    /// leaves $f \bmod (x - x_i) = f(x_i)$. Polynomials are multiplied using Karatsuba algorithm
+    /// and divided via Newton iteration, so evaluating degree $t$ polynomial at $n$ points takes
+    /// $O(n^{1.59} \log n)$ multiplications instead of $O(tn)$ required by
+    /// [Polynomial::evaluate_many]. Constant factor is larger though, so it pays off only for
+    /// large committees (several thousands parties) when polynomial degree is comparable to
+    /// number of points. Run `cargo bench --bench vss -- evaluate` to compare both on your
+    /// platform.
+    ///
+    /// Outputs are in the same order as `points`.
+    pub fn evaluate_multi(coefficients: &[Scalar<E>], points: &[Scalar<E>]) -> Vec<Scalar<E>> {
+        if points.is_empty() {
+            return vec![];
+        }
+
+        // tree[0] holds (x - x_i) for every point, tree[k+1] holds products of pairs of tree[k]
+        let mut tree = vec![points
+            .iter()
+            .map(|x| Polynomial::from_coefficients(vec![-x, Scalar::one()]))
+            .collect::<Vec<_>>()];
+        while tree[tree.len() - 1].len() > 1 {
+            let level = tree[tree.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a * b,
+                    [a] => a.clone(),
+                    _ => unreachable!("chunks are non-empty and have at most 2 elements"),
+                })
+                .collect();
+            tree.push(level);
+        }
+
+        // Node i at level k is a child of node i/2 at level k+1
+        let polynomial = Polynomial::from_coefficients(coefficients.to_vec());
+        let mut remainders = vec![polynomial.rem_monic(&tree[tree.len() - 1][0])];
+        for level in tree.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, modulus)| remainders[i / 2].rem_monic(modulus))
+                .collect();
+        }
+        remainders
+            .iter()
+            .map(|r| {
+                r.coefficients()
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(Scalar::zero)
+            })
+            .collect()
+    }
+
+    /// Reconstructs a secret from shares of parties at (0-based) `indices`
+    ///
+    /// Returns [ErrorSS::MismatchedLengths] if `indices` and `shares` have different lengths,
+    /// and [ErrorSS::BelowThreshold] if there are fewer than `t+1` shares. Shares are not
+    /// validated, use [reconstruct_checked](Self::reconstruct_checked) if they come from
+    /// untrusted parties.
+    pub fn reconstruct(&self, indices: &[u16], shares: &[Scalar<E>]) -> Result<Scalar<E>, ErrorSS> {
+        self.validate_parameters()?;
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
         // add one to indices to get points
         let points = indices
             .iter()
             .map(|i| Scalar::from(*i + 1))
             .collect::<Vec<_>>();
-        VerifiableSS::<E, H>::lagrange_interpolation_at_zero(&points, shares)
+        Ok(VerifiableSS::<E, H>::lagrange_interpolation_at_zero(
+            &points, shares,
+        ))
+    }
+
+    /// Reconstructs a secret from shares of parties at (0-based) `indices` together with shares
+    /// that are publicly `known`
+    ///
+    /// `known` lists pairs of (0-based) index and share, eg. shares of parties that were
+    /// disqualified and had their shares revealed. Known shares count towards the threshold, so
+    /// only `t+1 - known.len()` private shares are required. Returns
+    /// [ErrorSS::DuplicateIndex] if an index appears twice among `indices` and `known`, and
+    /// other errors the same as [reconstruct](Self::reconstruct).
+    ///
+    /// If the same shares are known across many reconstructions, precompute them once via
+    /// [KnownShares::new] and use
+    /// [reconstruct_with_precomputed_known](Self::reconstruct_with_precomputed_known).
+    pub fn reconstruct_with_known(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+        known: &[(u16, Scalar<E>)],
+    ) -> Result<Scalar<E>, ErrorSS> {
+        self.reconstruct_with_precomputed_known(indices, shares, &KnownShares::new(known)?)
+    }
+
+    /// Same as [reconstruct_with_known](Self::reconstruct_with_known), but reuses interpolation
+    /// of `known` shares
+    ///
+    /// Denoting `P_K` the polynomial interpolating known shares and `V_K(x) = ∏(x - x_k)` the
+    /// polynomial vanishing at their points, the shared polynomial is `f = P_K + V_K·Q`, where
+    /// `Q` interpolates `(y_a - P_K(x_a)) / V_K(x_a)` over the remaining points `x_a` only, and
+    /// secret is `P_K(0) + V_K(0)·Q(0)`. Reconstruction thus costs `O(|A|·|K| + |A|²)` instead
+    /// of `O((|A| + |K|)²)` of [reconstruct](Self::reconstruct), where `|A|` and `|K|` are
+    /// numbers of given and known shares.
+    pub fn reconstruct_with_precomputed_known(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+        known: &KnownShares<E>,
+    ) -> Result<Scalar<E>, ErrorSS> {
+        self.validate_parameters()?;
+        let known_len = known.indices.len();
+        self.parameters
+            .ensure_can_reconstruct(indices.len() + known_len, shares.len() + known_len)?;
+        let all_points = indices
+            .iter()
+            .chain(&known.indices)
+            .map(|i| {
+                i.checked_add(1)
+                    .and_then(NonZeroU16::new)
+                    .ok_or(ErrorSS::InvalidShare { index: *i })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        ensure_distinct(&all_points)?;
+
+        let known_at_zero = known.interpolated.evaluate(&Scalar::zero());
+        if indices.is_empty() {
+            return Ok(known_at_zero);
+        }
+        let points: Vec<Scalar<E>> = all_points[..indices.len()]
+            .iter()
+            .map(|x| Scalar::from(x.get()))
+            .collect();
+        let mut vanishing_at_points: Vec<Scalar<E>> =
+            points.iter().map(|x| known.vanishing.evaluate(x)).collect();
+        let zeros = Scalar::batch_invert(&mut vanishing_at_points);
+        debug_assert!(
+            !zeros.contains(&true),
+            "points are distinct from known ones"
+        );
+        let residuals: Vec<Scalar<E>> = points
+            .iter()
+            .zip(shares)
+            .zip(&vanishing_at_points)
+            .map(|((x, y), v_inv)| (y - known.interpolated.evaluate(x)) * v_inv)
+            .collect();
+        let q_at_zero = Self::lagrange_interpolation_at_zero(&points, &residuals);
+        Ok(known_at_zero + known.vanishing.evaluate(&Scalar::zero()) * q_at_zero)
+    }
+
+    /// Reconstructs public key `g^secret` from public shares `g^{share_i}` of parties at (0-based)
+    /// `indices`
+    ///
+    /// Performs Lagrange interpolation at zero in the exponent, so secret shares are never
+    /// needed. Returns the same errors as [reconstruct](Self::reconstruct).
+    pub fn reconstruct_public(
+        &self,
+        indices: &[u16],
+        public_shares: &[Point<E>],
+    ) -> Result<Point<E>, ErrorSS> {
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), public_shares.len())?;
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        let lambdas = (0..)
+            .take(points.len())
+            .map(|j| Polynomial::lagrange_basis(&Scalar::zero(), j, &points))
+            .collect::<Vec<_>>();
+        Ok(Point::multi_scalar_mul(public_shares, &lambdas))
+    }
+
+    /// Reconstructs a secret, validating every share against this scheme commitments first
+    ///
+    /// Takes the same arguments as [reconstruct](Self::reconstruct). Returns
+    /// [ErrorSS::InvalidShare] with index of the first share that doesn't pass
+    /// [validate_share](Self::validate_share), so a corrupted share never silently results into
+    /// a wrong secret.
+    pub fn reconstruct_checked(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+    ) -> Result<Scalar<E>, ErrorSS> {
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
+        for (share, &index) in shares.iter().zip(indices) {
+            self.validate_share(share, index + 1)
+                .map_err(|_| ErrorSS::InvalidShare { index })?;
+        }
+        self.reconstruct(indices, shares)
+    }
+
+    /// Reconstructs the whole polynomial used to derive secret shares, not only its constant term
+    ///
+    /// Takes the same arguments and returns the same errors as [reconstruct](Self::reconstruct).
+    /// Secret is the constant term of resulting polynomial.
+    pub fn reconstruct_polynomial(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+    ) -> Result<Polynomial<E>, ErrorSS> {
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        Ok(Polynomial::interpolate(&points, shares))
+    }
+
+    /// Reconstructs a secret from shares evaluated at given indices (see [share_at_indices](Self::share_at_indices))
+    ///
+    /// Unlike [reconstruct](Self::reconstruct), `indices` are the actual points at which shares
+    /// were evaluated. Returns [ErrorSS::DuplicateIndex] if `indices` contain duplicates.
+    pub fn reconstruct_at_indices(
+        &self,
+        indices: &[NonZeroU16],
+        shares: &[Scalar<E>],
+    ) -> Result<Scalar<E>, ErrorSS> {
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
+        ensure_distinct(indices)?;
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(i.get()))
+            .collect::<Vec<_>>();
+        Ok(VerifiableSS::<E, H>::lagrange_interpolation_at_zero(
+            &points, shares,
+        ))
     }
 
     // Performs a Lagrange interpolation in field Zp at the origin
@@ -208,8 +783,8 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
                 .map(|i| {
                     let xi = &points[i];
                     let yi = &values[i];
-                    let num = Scalar::from(1);
-                    let denum = Scalar::from(1);
+                    let num = Scalar::one();
+                    let denum = Scalar::one();
                     let num = points.iter().zip(0..vec_len).fold(num, |acc, x| {
                         if i != x.1 {
                             acc * x.0
@@ -235,7 +810,71 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
         tail.fold(head.clone(), |acc, x| acc + x)
     }
 
+    /// Performs a Lagrange interpolation at the origin, same as [lagrange_interpolation_at_zero](Self::lagrange_interpolation_at_zero)
+    ///
+    /// Sequence of operations doesn't depend on `values`, so it's suitable for interpolating secret
+    /// shares. All denominators are inverted at once via Montgomery's trick, thus it takes a single
+    /// inversion instead of one per point.
+    ///
+    /// ## Panics
+    /// Panics if `points` and `values` have different lengths, or if `points` contain duplicates.
+    pub fn lagrange_interpolation_at_zero_ct(
+        points: &[Scalar<E>],
+        values: &[Scalar<E>],
+    ) -> Scalar<E> {
+        let n = values.len();
+        assert_eq!(points.len(), n);
+
+        // num_i = Π_{j≠i} x_j is computed from prefix and suffix products
+        let mut suffix = vec![Scalar::<E>::one(); n + 1];
+        for i in (0..n).rev() {
+            suffix[i] = &suffix[i + 1] * &points[i];
+        }
+        let mut prefix = Scalar::<E>::one();
+        let mut numerators = Vec::with_capacity(n);
+        for i in 0..n {
+            numerators.push(&prefix * &suffix[i + 1]);
+            prefix = prefix * &points[i];
+        }
+
+        // denum_i = Π_{j≠i} (x_j - x_i)
+        let denominators: Vec<Scalar<E>> = (0..n)
+            .map(|i| {
+                (0..i)
+                    .chain(i + 1..n)
+                    .fold(Scalar::one(), |acc, j| acc * (&points[j] - &points[i]))
+            })
+            .collect();
+        let mut inverted = denominators;
+        let zeros = Scalar::batch_invert(&mut inverted);
+        assert!(!zeros.contains(&true), "points must be distinct");
+
+        numerators
+            .iter()
+            .zip(&inverted)
+            .zip(values)
+            .fold(Scalar::zero(), |acc, ((num, denum_inv), y)| {
+                acc + num * denum_inv * y
+            })
+    }
+
+    /// Checks that scheme is well-formed: it has exactly `t+1` commitments and `n ≥ t+1`
+    ///
+    /// Scheme received from untrusted dealer might be malformed, which would make share
+    /// validation and reconstruction misbehave. The check is performed by
+    /// [validate_share](Self::validate_share) and [reconstruct](Self::reconstruct), other methods
+    /// assume that scheme is well-formed. Returns [ErrorSS::InvalidParameters] if check fails.
+    pub fn validate_parameters(&self) -> Result<(), ErrorSS> {
+        let limit = usize::from(self.parameters.threshold) + 1;
+        if self.commitments.len() != limit || usize::from(self.parameters.share_count) < limit {
+            Err(ErrorSS::InvalidParameters)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn validate_share(&self, secret_share: &Scalar<E>, index: u16) -> Result<(), ErrorSS> {
+        self.validate_parameters()?;
         if self.commitments[0] != self.proof.pk || DLogProof::verify(&self.proof).is_err() {
             return Err(VerifyShareError);
         }
@@ -253,12 +892,339 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
         }
     }
 
+    /// Validates many shares at once, ie. the same as calling `schemes[i].validate_share(&shares[i].0, shares[i].1)`
+    /// for every `i`, but much faster
+    ///
+    /// All the share checks are combined into a single equation `Σᵢ rᵢ·(sᵢ·G - Cᵢ(indexᵢ)) = O`, where
+    /// `rᵢ` are random coefficients sampled from secure RNG, so a malicious dealer cannot craft shares
+    /// that cancel each other out. If the equation doesn't hold, every share is checked separately.
+    ///
+    /// Returns a list of positions in `shares` that didn't pass the validation. Schemes that are
+    /// [malformed](Self::validate_parameters) or carry invalid proof are reported without checking
+    /// any shares.
+    ///
+    /// ## Panics
+    /// Panics if `shares` and `schemes` have different lengths.
+    pub fn batch_validate(
+        shares: &[(Scalar<E>, u16)],
+        schemes: &[&VerifiableSS<E, H>],
+    ) -> Result<(), Vec<usize>> {
+        assert_eq!(shares.len(), schemes.len());
+
+        let bad_proofs: Vec<usize> = schemes
+            .iter()
+            .enumerate()
+            .filter(|(_, vss)| {
+                vss.validate_parameters().is_err()
+                    || vss.commitments[0] != vss.proof.pk
+                    || DLogProof::verify(&vss.proof).is_err()
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if !bad_proofs.is_empty() {
+            return Err(bad_proofs);
+        }
+
+        let mut points = vec![Point::generator().to_point()];
+        let mut scalars = vec![Scalar::zero()];
+        for ((share, index), vss) in shares.iter().zip(schemes) {
+            let r = Scalar::<E>::random();
+            scalars[0] = &scalars[0] - &r * share;
+
+            let index = Scalar::from(*index);
+            let coefs = iter::successors(Some(r), |x| Some(x * &index));
+            points.extend(vss.commitments.iter().cloned());
+            scalars.extend(coefs.take(vss.commitments.len()));
+        }
+        if Point::multi_scalar_mul(&points, &scalars).is_zero() {
+            return Ok(());
+        }
+
+        // Equation always holds if all the shares are valid, so at least one of them is not
+        let g = Point::generator();
+        Err(shares
+            .iter()
+            .zip(schemes)
+            .enumerate()
+            .filter(|(_, ((share, index), vss))| {
+                vss.validate_share_public(&(g * share), *index).is_err()
+            })
+            .map(|(i, _)| i)
+            .collect())
+    }
+
+    /// Validates all shares produced by [share](Self::share) at once (`shares[i]` is expected to be
+    /// evaluated at index `i + 1`)
+    ///
+    /// Helps dealer to make sure that shares match the commitments before distributing them. All
+    /// checks are combined into a single equation `Σᵢ rᵢ·(sᵢ·G - C(i + 1)) = O`, where `rᵢ` are
+    /// random coefficients, so it takes a single multiscalar multiplication of size `t + 1` no
+    /// matter how many shares are validated. If the equation doesn't hold, every share is checked
+    /// separately.
+    ///
+    /// Returns a list of positions in `shares` that didn't pass the validation. Indices are `u16`,
+    /// so if there are more than `u16::MAX` shares, every position is reported. The same applies
+    /// if the scheme is [malformed](Self::validate_parameters).
+    pub fn validate_all(&self, shares: &[Scalar<E>]) -> Result<(), Vec<usize>> {
+        if shares.len() > usize::from(u16::MAX)
+            || self.validate_parameters().is_err()
+            || self.commitments[0] != self.proof.pk
+            || DLogProof::verify(&self.proof).is_err()
+        {
+            return Err((0..shares.len()).collect());
+        }
+
+        let mut share_coef = Scalar::<E>::zero();
+        let mut commitment_coefs = vec![Scalar::<E>::zero(); self.commitments.len()];
+        for (index, share) in (1u16..).zip(shares) {
+            let r = Scalar::<E>::random();
+            share_coef = share_coef + &r * share;
+
+            let index = Scalar::from(index);
+            let powers = iter::successors(Some(r), |x| Some(x * &index));
+            for (coef, power) in commitment_coefs.iter_mut().zip(powers) {
+                *coef = &*coef + power;
+            }
+        }
+        if Point::generator() * share_coef
+            == Point::multi_scalar_mul(&self.commitments, &commitment_coefs)
+        {
+            return Ok(());
+        }
+
+        // Equation always holds if all the shares are valid, so at least one of them is not
+        let g = Point::generator();
+        Err((1u16..)
+            .zip(shares)
+            .enumerate()
+            .filter(|(_, (index, share))| {
+                self.validate_share_public(&(g * *share), *index).is_err()
+            })
+            .map(|(i, _)| i)
+            .collect())
+    }
+
+    /// Reveals a share of the party at (0-based) `index` in response to its complaint
+    ///
+    /// In DKG, a party that received a share not matching the dealer's commitments broadcasts a
+    /// complaint. The dealer must then publish the disputed share, so that anyone can adjudicate
+    /// the complaint via [verify_complaint](Self::verify_complaint).
+    ///
+    /// Share is validated against this scheme before being revealed. Returns
+    /// [ErrorSS::VerifyShareError] if there's no share at `index` or it doesn't match the
+    /// commitments, in which case the complaint is justified.
+    pub fn reveal_share(
+        &self,
+        secret_shares: &[Scalar<E>],
+        index: usize,
+    ) -> Result<Scalar<E>, ErrorSS> {
+        let share = secret_shares.get(index).ok_or(VerifyShareError)?;
+        let index = u16::try_from(index + 1).or(Err(VerifyShareError))?;
+        self.validate_share(share, index)?;
+        Ok(share.clone())
+    }
+
+    /// Adjudicates a complaint of the party at (0-based) `index` against the dealer
+    ///
+    /// Checks whether `revealed_share` (published via [reveal_share](Self::reveal_share)) matches
+    /// the dealer's commitments. The outcome depends only on public data, so every honest party
+    /// reaches the same verdict.
+    pub fn verify_complaint(
+        dealer_scheme: &Self,
+        revealed_share: &Scalar<E>,
+        index: usize,
+    ) -> ComplaintVerdict {
+        let index = match u16::try_from(index) {
+            Ok(index) if index < dealer_scheme.parameters.share_count => index,
+            // There's no such party, so complaint cannot be justified
+            _ => return ComplaintVerdict::RejectComplaint,
+        };
+        match dealer_scheme.validate_share(revealed_share, index + 1) {
+            Ok(()) => ComplaintVerdict::RejectComplaint,
+            Err(_) => ComplaintVerdict::DisqualifyDealer,
+        }
+    }
+
+    /// Hash of the commitments vector
+    ///
+    /// Computed using the scheme's hash function `H` over a domain separation tag, `threshold`
+    /// and `share_count` (each as big-endian `u16`), number of commitments (big-endian `u64`),
+    /// and concatenated compressed commitments, so schemes with different parameters never share
+    /// a digest. Dealer may broadcast the digest before sending commitments to every party, so that
+    /// parties can detect a dealer sending different commitments to different recipients
+    /// (see [verify_against_digest](Self::verify_against_digest)).
+    pub fn commitment_digest(&self) -> BigInt {
+        let mut hasher = H::new();
+        hasher.update(COMMITMENT_DIGEST_DST);
+        hasher.update(self.parameters.threshold.to_be_bytes());
+        hasher.update(self.parameters.share_count.to_be_bytes());
+        hasher.update((self.commitments.len() as u64).to_be_bytes());
+        for commitment in &self.commitments {
+            hasher.update(&commitment.to_bytes(true)[..]);
+        }
+        hasher.result_bigint()
+    }
+
+    /// Checks that commitments match a previously broadcasted [digest](Self::commitment_digest)
+    pub fn verify_against_digest(&self, digest: &BigInt) -> Result<(), ErrorSS> {
+        if self.commitment_digest() == *digest {
+            Ok(())
+        } else {
+            Err(VerifyShareError)
+        }
+    }
+
     pub fn get_point_commitment(&self, index: u16) -> Point<E> {
         let index_fe = Scalar::from(index);
-        let mut comm_iterator = self.commitments.iter().rev();
-        let head = comm_iterator.next().unwrap();
-        let tail = comm_iterator;
-        tail.fold(head.clone(), |acc, x| x + acc * &index_fe)
+        let powers: Vec<Scalar<E>> = iter::successors(Some(Scalar::one()), |x| Some(x * &index_fe))
+            .take(self.commitments.len())
+            .collect();
+        Point::multi_scalar_mul(&self.commitments, &powers)
+    }
+
+    /// Converts the scheme into [Pedersen VSS](PedersenVSS) by blinding its commitments
+    ///
+    /// Every commitment $C_i = a_i G$ is lifted to $a_i G + b_i H$, where $b_i$ are coefficients
+    /// of `blinding_polynomial`. Secret and secret shares stay the same, every party additionally
+    /// needs its blinding share `blinding_polynomial.evaluate_bigint(index)`.
+    ///
+    /// ## Panics
+    /// Panics if `blinding_polynomial` degree exceeds threshold `t`.
+    pub fn to_pedersen(&self, blinding_polynomial: &Polynomial<E>) -> PedersenVSS<E> {
+        assert!(
+            blinding_polynomial.degree() <= PolynomialDegree::Finite(self.parameters.threshold),
+            "blinding polynomial degree exceeds threshold"
+        );
+        let h = Point::<E>::base_point2();
+        let zero = Scalar::zero();
+        let blinding_coefficients = blinding_polynomial
+            .coefficients()
+            .iter()
+            .chain(iter::repeat(&zero));
+        let commitments = self
+            .commitments
+            .iter()
+            .zip(blinding_coefficients)
+            .map(|(commitment, b)| commitment + h * b)
+            .collect();
+        PedersenVSS {
+            parameters: self.parameters.clone(),
+            commitments,
+        }
+    }
+
+    /// Encodes the scheme in compact binary format
+    ///
+    /// Format is independent of serde and is meant to minimize bandwidth when commitments are
+    /// sent over the network:
+    /// * `threshold` and `share_count`, each as big-endian `u32`
+    /// * number of commitments as LEB128 varint, followed by compressed commitments
+    /// * `pk_t_rand_commitment` (compressed) and `challenge_response` of the DLog proof
+    ///
+    /// Public key of the proof always equals `commitments[0]`, so it's omitted. Scheme can be
+    /// decoded via [from_bytes](Self::from_bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&u32::from(self.parameters.threshold).to_be_bytes());
+        bytes.extend_from_slice(&u32::from(self.parameters.share_count).to_be_bytes());
+        let mut len = self.commitments.len();
+        while len >= 0x80 {
+            bytes.push((len & 0x7f) as u8 | 0x80);
+            len >>= 7;
+        }
+        bytes.push(len as u8);
+        for commitment in &self.commitments {
+            bytes.extend_from_slice(&commitment.to_bytes(true));
+        }
+        bytes.extend_from_slice(&self.proof.pk_t_rand_commitment.to_bytes(true));
+        bytes.extend_from_slice(&self.proof.challenge_response.to_bytes());
+        bytes
+    }
+
+    /// Decodes a scheme encoded via [to_bytes](Self::to_bytes)
+    ///
+    /// Returns error if bytes are malformed (including non-canonical varint), contain trailing
+    /// data, if any point is invalid, or if there are no commitments. Decoded scheme is not
+    /// validated otherwise, shares still need to be checked via
+    /// [validate_share](Self::validate_share).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        fn take<'b>(bytes: &mut &'b [u8], n: usize) -> Result<&'b [u8], DeserializationError> {
+            if bytes.len() < n {
+                return Err(DeserializationError);
+            }
+            let (head, tail) = bytes.split_at(n);
+            *bytes = tail;
+            Ok(head)
+        }
+        fn take_u16(bytes: &mut &[u8]) -> Result<u16, DeserializationError> {
+            let n = u32::from_be_bytes(take(bytes, 4)?.try_into().unwrap());
+            u16::try_from(n).or(Err(DeserializationError))
+        }
+        fn take_point<E: Curve>(bytes: &mut &[u8]) -> Result<Point<E>, DeserializationError> {
+            let point_len = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+            Point::from_bytes(take(bytes, point_len)?).or(Err(DeserializationError))
+        }
+
+        let mut bytes = bytes;
+        let threshold = take_u16(&mut bytes)?;
+        let share_count = take_u16(&mut bytes)?;
+
+        let mut len = 0usize;
+        for shift in (0..).step_by(7) {
+            let byte = take(&mut bytes, 1)?[0];
+            let bits = usize::from(byte & 0x7f);
+            if shift >= usize::BITS || bits << shift >> shift != bits {
+                return Err(DeserializationError);
+            }
+            len |= bits << shift;
+            if byte & 0x80 == 0 {
+                // Encoding must be canonical: the last byte can't be zero unless it's the only one
+                if byte == 0 && shift > 0 {
+                    return Err(DeserializationError);
+                }
+                break;
+            }
+        }
+        if len == 0 {
+            return Err(DeserializationError);
+        }
+        let commitments = (0..len)
+            .map(|_| take_point(&mut bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let pk_t_rand_commitment = take_point(&mut bytes)?;
+        let scalar_len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+        let challenge_response = Scalar::from_bytes(take(&mut bytes, scalar_len)?)?;
+        if !bytes.is_empty() {
+            return Err(DeserializationError);
+        }
+
+        Ok(VerifiableSS {
+            parameters: ShamirSecretSharing {
+                threshold,
+                share_count,
+            },
+            proof: DLogProof {
+                pk: commitments[0].clone(),
+                pk_t_rand_commitment,
+                challenge_response,
+                hash_choice: HashChoice::new(),
+            },
+            commitments,
+        })
+    }
+
+    /// Public shares `g^{share_i}` of all `n` parties, computed from commitments only
+    ///
+    /// `public_shares()[i]` corresponds to the share of the party at (0-based) index `i`, ie.
+    /// to `secret_shares[i]` produced by [share](Self::share). Public shares let everyone
+    /// verify contributions of individual parties, e.g. partial signatures or decryptions.
+    /// Schemes dealt via [share_at_indices](Self::share_at_indices) should use
+    /// [get_point_commitment](Self::get_point_commitment) with the actual indices instead.
+    pub fn public_shares(&self) -> Vec<Point<E>> {
+        (1..=self.parameters.share_count)
+            .map(|index| self.get_point_commitment(index))
+            .collect()
     }
 
     //compute \lambda_{index,S}, a lagrangian coefficient that change the (t,n) scheme to (|S|,|S|)
@@ -277,6 +1243,76 @@ impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
     }
 }
 
+/// Computes group public key in DKG, ie. sum of [public keys](VerifiableSS::public_key) of all
+/// dealers
+///
+/// Group public key corresponds to the secret shared via schemes [combined](VerifiableSS::add)
+/// together.
+pub fn aggregate_public_keys<E: Curve, H: Digest + Clone>(
+    schemes: &[&VerifiableSS<E, H>],
+) -> Point<E> {
+    schemes.iter().map(|vss| vss.public_key()).sum()
+}
+
+/// Returns [ErrorSS::DuplicateIndex] if any index appears twice
+pub(crate) fn ensure_distinct<T: Ord + Clone>(indices: &[T]) -> Result<(), ErrorSS> {
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable();
+    if sorted.windows(2).any(|w| w[0] == w[1]) {
+        Err(ErrorSS::DuplicateIndex)
+    } else {
+        Ok(())
+    }
+}
+
+/// Publicly known shares, precomputed for
+/// [reconstruct_with_precomputed_known](VerifiableSS::reconstruct_with_precomputed_known)
+#[derive(Clone, Debug)]
+pub struct KnownShares<E: Curve> {
+    indices: Vec<u16>,
+    /// Polynomial of degree `< |K|` passing through known shares
+    interpolated: Polynomial<E>,
+    /// `∏(x - x_k)` over points of known shares
+    vanishing: Polynomial<E>,
+}
+
+impl<E: Curve> KnownShares<E> {
+    /// Interpolates `known` pairs of (0-based) index and share
+    ///
+    /// Returns [ErrorSS::DuplicateIndex] if an index appears twice.
+    pub fn new(known: &[(u16, Scalar<E>)]) -> Result<Self, ErrorSS> {
+        let points = known
+            .iter()
+            .map(|(i, _)| {
+                i.checked_add(1)
+                    .and_then(NonZeroU16::new)
+                    .ok_or(ErrorSS::InvalidShare { index: *i })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        ensure_distinct(&points)?;
+        let xs: Vec<Scalar<E>> = points.iter().map(|x| Scalar::from(x.get())).collect();
+        let ys: Vec<Scalar<E>> = known.iter().map(|(_, share)| share.clone()).collect();
+        let vanishing = xs.iter().fold(
+            Polynomial::from_coefficients(vec![Scalar::one()]),
+            |acc, x| &acc * &Polynomial::from_coefficients(vec![-x, Scalar::one()]),
+        );
+        Ok(Self {
+            indices: known.iter().map(|(i, _)| *i).collect(),
+            interpolated: Polynomial::interpolate(&xs, &ys),
+            vanishing,
+        })
+    }
+}
+
+/// Outcome of [VerifiableSS::verify_complaint]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComplaintVerdict {
+    /// Revealed share doesn't match dealer's commitments, dealer must be disqualified
+    DisqualifyDealer,
+    /// Revealed share is valid, complaint is not justified
+    RejectComplaint,
+}
+
 impl<E: Curve> SecretShares<E> {
     /// Polynomial that was used to derive secret shares
     pub fn polynomial(&self) -> &Polynomial<E> {
@@ -300,8 +1336,11 @@ impl<E: Curve> ops::Deref for SecretShares<E> {
 
 #[cfg(test)]
 mod tests {
+    use typenum::Unsigned;
+
     use super::*;
-    use crate::test_for_all_curves_and_hashes;
+    use crate::elliptic::curves::ECPoint;
+    use crate::{test_for_all_curves, test_for_all_curves_and_hashes};
 
     test_for_all_curves_and_hashes!(test_secret_sharing_3_out_of_5_at_indices);
 
@@ -313,7 +1352,8 @@ mod tests {
             5,
             &secret,
             parties.iter().map(|&v| NonZeroU16::new(v).unwrap()),
-        );
+        )
+        .unwrap();
 
         let shares_vec = vec![
             secret_shares[0].clone(),
@@ -324,8 +1364,23 @@ mod tests {
 
         //test reconstruction
 
-        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 4, 5], &shares_vec);
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 4, 5], &shares_vec).unwrap();
+        assert_eq!(secret, secret_reconstructed);
+
+        let indices: Vec<_> = [1, 2, 5, 6]
+            .iter()
+            .map(|&v| NonZeroU16::new(v).unwrap())
+            .collect();
+        let secret_reconstructed = vss_scheme
+            .reconstruct_at_indices(&indices, &shares_vec)
+            .unwrap();
         assert_eq!(secret, secret_reconstructed);
+
+        // test secret shares are verifiable at the points they were evaluated at
+        for (&party, share) in parties.iter().zip(secret_shares.iter()) {
+            assert!(vss_scheme.validate_share(share, party).is_ok());
+        }
+        assert!(vss_scheme.validate_share(&secret_shares[2], 3).is_err());
     }
 
     test_for_all_curves_and_hashes!(test_secret_sharing_3_out_of_5);
@@ -344,7 +1399,7 @@ mod tests {
 
         //test reconstruction
 
-        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 2, 4], &shares_vec);
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 2, 4], &shares_vec).unwrap();
 
         assert_eq!(secret, secret_reconstructed);
         // test secret shares are verifiable
@@ -359,7 +1414,7 @@ mod tests {
         assert!(valid1_public.is_ok());
 
         // test map (t,n) - (t',t')
-        let s = &vec![0, 1, 2, 3, 4];
+        let s = &[0, 1, 2, 3, 4];
         let l0 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 0, s);
         let l1 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 1, s);
         let l2 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 2, s);
@@ -388,7 +1443,7 @@ mod tests {
         ];
 
         //test reconstruction
-        let secret_reconstructed = vss_scheme.reconstruct(&[0, 6, 2, 4], &shares_vec);
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 6, 2, 4], &shares_vec).unwrap();
         assert_eq!(secret, secret_reconstructed);
 
         // test secret shares are verifiable
@@ -398,7 +1453,7 @@ mod tests {
         assert!(valid1.is_ok());
 
         // test map (t,n) - (t',t')
-        let s = &vec![0, 1, 3, 4, 6];
+        let s = &[0, 1, 3, 4, 6];
         let l0 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 0, s);
         let l1 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 1, s);
         let l3 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 3, s);
@@ -423,7 +1478,7 @@ mod tests {
         let shares_vec = vec![secret_shares[0].clone(), secret_shares[1].clone()];
 
         //test reconstruction
-        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1], &shares_vec);
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1], &shares_vec).unwrap();
         assert_eq!(secret, secret_reconstructed);
 
         // test secret shares are verifiable
@@ -433,7 +1488,7 @@ mod tests {
         assert!(valid1.is_ok());
 
         // test map (t,n) - (t',t')
-        let s = &vec![0, 1];
+        let s = &[0, 1];
         let l0 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 0, s);
         let l1 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 1, s);
         let w = l0 * &secret_shares[0] + l1 * &secret_shares[1];
@@ -463,7 +1518,7 @@ mod tests {
         assert_eq!(point1_sum_com, g_sum);
 
         //test reconstruction
-        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1], &shares_vec);
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1], &shares_vec).unwrap();
         assert_eq!(secret, secret_reconstructed);
 
         // test secret shares are verifiable
@@ -473,7 +1528,7 @@ mod tests {
         assert!(valid1.is_ok());
 
         // test map (t,n) - (t',t')
-        let s = &vec![0, 2];
+        let s = &[0, 2];
         let l0 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 0, s);
         let l2 = VerifiableSS::<E, H>::map_share_to_new_params(&vss_scheme.parameters, 2, s);
 
@@ -496,7 +1551,7 @@ mod tests {
         let shares_vec = vec![new_share_party_1.clone(), new_share_party_3.clone()];
 
         // reconstruction
-        let secret_reconstructed = vss_scheme.reconstruct(&[0, 2], &shares_vec);
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 2], &shares_vec).unwrap();
         assert_eq!(secret, secret_reconstructed);
 
         // test secret shares are verifiable
@@ -508,4 +1563,1066 @@ mod tests {
         assert!(valid2.is_ok());
         assert!(valid3.is_ok());
     }
+
+    test_for_all_curves_and_hashes!(test_weighted_secret_sharing);
+
+    fn test_weighted_secret_sharing<E: Curve, H: Digest + Clone>() {
+        let secret = Scalar::random();
+        let (vss_scheme, party_shares) =
+            VerifiableSS::<E, H>::share_weighted(4, &[1, 2, 3], &secret);
+
+        assert_eq!(vss_scheme.parameters.share_count, 6);
+        let indices: Vec<Vec<u16>> = party_shares
+            .iter()
+            .map(|shares| shares.iter().map(|(i, _)| i.get()).collect())
+            .collect();
+        assert_eq!(indices, vec![vec![1], vec![2, 3], vec![4, 5, 6]]);
+
+        for (index, share) in party_shares.iter().flatten() {
+            assert!(vss_scheme.validate_share(share, index.get()).is_ok());
+        }
+
+        // two heaviest parties hold 5 shares, which is enough to reconstruct the secret
+        let (indices, shares): (Vec<_>, Vec<_>) =
+            party_shares[1..].iter().flatten().cloned().unzip();
+        let secret_reconstructed = vss_scheme
+            .reconstruct_at_indices(&indices, &shares)
+            .unwrap();
+        assert_eq!(secret, secret_reconstructed);
+
+        // the lightest and the heaviest parties hold only 4 shares
+        let (indices, shares): (Vec<Scalar<E>>, Vec<_>) = party_shares[0]
+            .iter()
+            .chain(&party_shares[2])
+            .map(|(i, share)| (Scalar::from(i.get()), share.clone()))
+            .unzip();
+        let interpolated = VerifiableSS::<E, H>::lagrange_interpolation_at_zero(&indices, &shares);
+        assert_ne!(secret, interpolated);
+    }
+
+    test_for_all_curves_and_hashes!(test_reconstruct_polynomial);
+
+    fn test_reconstruct_polynomial<E: Curve, H: Digest + Clone>() {
+        let secret = Scalar::random();
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(3, 7, &secret);
+
+        let indices = [0, 2, 3, 6];
+        let shares: Vec<_> = indices
+            .iter()
+            .map(|&i| secret_shares[usize::from(i)].clone())
+            .collect();
+        let polynomial = vss_scheme
+            .reconstruct_polynomial(&indices, &shares)
+            .unwrap();
+
+        assert_eq!(
+            polynomial.coefficients(),
+            secret_shares.polynomial().coefficients()
+        );
+        assert_eq!(polynomial.coefficients()[0], secret);
+        let evaluated: Vec<_> = polynomial.evaluate_many_bigint(1..=7).collect();
+        assert_eq!(evaluated, secret_shares.to_vec());
+
+        // Reconstructed polynomial can be evaluated at any point, e.g. at zero to get the secret,
+        // or at a new party index to issue a share that matches the commitments
+        assert_eq!(polynomial.evaluate(&Scalar::zero()), secret);
+        let new_share = polynomial.evaluate(&Scalar::from(8));
+        assert!(vss_scheme.validate_share(&new_share, 8).is_ok());
+
+        // Interpolating more points than needed results into the same polynomial with zero
+        // higher coefficients
+        let all_indices: Vec<u16> = (0..7).collect();
+        let polynomial = vss_scheme
+            .reconstruct_polynomial(&all_indices, &secret_shares)
+            .unwrap();
+        assert_eq!(polynomial.degree(), 3.into());
+        assert_eq!(
+            polynomial.coefficients()[..4],
+            secret_shares.polynomial().coefficients()[..]
+        );
+
+        assert!(matches!(
+            vss_scheme.reconstruct_polynomial(&indices[..3], &shares[..3]),
+            Err(ErrorSS::BelowThreshold)
+        ));
+        assert!(matches!(
+            vss_scheme.reconstruct_polynomial(&indices[..3], &shares),
+            Err(ErrorSS::MismatchedLengths)
+        ));
+    }
+
+    test_for_all_curves_and_hashes!(test_share_at_indices_rejects_wrong_number_of_indices);
+
+    fn test_share_at_indices_rejects_wrong_number_of_indices<E: Curve, H: Digest + Clone>() {
+        let secret = Scalar::random();
+        for count in [4, 6] {
+            let indices = (1..=count).map(|v| NonZeroU16::new(v).unwrap());
+            let result = VerifiableSS::<E, H>::share_at_indices(3, 5, &secret, indices);
+            assert_eq!(result.err(), Some(ErrorSS::MismatchedLengths));
+        }
+    }
+
+    test_for_all_curves_and_hashes!(test_share_at_indices_rejects_duplicates);
+
+    fn test_share_at_indices_rejects_duplicates<E: Curve, H: Digest + Clone>() {
+        let secret = Scalar::random();
+        let indices = [1, 4, 2, 4, 6].iter().map(|&v| NonZeroU16::new(v).unwrap());
+        let result = VerifiableSS::<E, H>::share_at_indices(3, 5, &secret, indices);
+        assert_eq!(result.err(), Some(ErrorSS::DuplicateIndex));
+
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(3, 5, &secret);
+        let indices: Vec<_> = [1, 2, 3, 3]
+            .iter()
+            .map(|&v| NonZeroU16::new(v).unwrap())
+            .collect();
+        let result = vss_scheme.reconstruct_at_indices(&indices, &secret_shares[..4]);
+        assert_eq!(result, Err(ErrorSS::DuplicateIndex));
+    }
+
+    test_for_all_curves_and_hashes!(test_batch_validate);
+
+    fn test_batch_validate<E: Curve, H: Digest + Clone>() {
+        let my_index = 3;
+        let dealings: Vec<_> = (0..10)
+            .map(|_| VerifiableSS::<E, H>::share(2, 5, &Scalar::random()))
+            .collect();
+        let schemes: Vec<_> = dealings.iter().map(|(vss, _)| vss).collect();
+        let mut shares: Vec<_> = dealings
+            .iter()
+            .map(|(_, shares)| (shares[usize::from(my_index) - 1].clone(), my_index))
+            .collect();
+
+        assert_eq!(VerifiableSS::batch_validate(&shares, &schemes), Ok(()));
+
+        shares[6].0 = &shares[6].0 + Scalar::from(1);
+        assert_eq!(
+            VerifiableSS::batch_validate(&shares, &schemes),
+            Err(vec![6])
+        );
+
+        assert_eq!(VerifiableSS::<E, H>::batch_validate(&[], &[]), Ok(()));
+
+        // Malformed schemes are reported instead of panicking or being checked partially
+        let mut empty = schemes[2].clone();
+        empty.commitments.clear();
+        let mut too_long = schemes[5].clone();
+        too_long.commitments.push(Point::generator().to_point());
+        let mut schemes = schemes;
+        schemes[2] = &empty;
+        schemes[5] = &too_long;
+        assert_eq!(
+            VerifiableSS::batch_validate(&shares, &schemes),
+            Err(vec![2, 5])
+        );
+    }
+
+    test_for_all_curves_and_hashes!(test_validate_all);
+
+    fn test_validate_all<E: Curve, H: Digest + Clone>() {
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(2, 5, &Scalar::random());
+        let mut shares = secret_shares.to_vec();
+        assert_eq!(vss_scheme.validate_all(&shares), Ok(()));
+
+        shares[3] = &shares[3] + Scalar::from(1);
+        assert_eq!(vss_scheme.validate_all(&shares), Err(vec![3]));
+
+        // shares are swapped, so neither of them is evaluated at the expected index
+        shares.swap(0, 3);
+        assert_eq!(vss_scheme.validate_all(&shares), Err(vec![0, 3]));
+
+        assert_eq!(vss_scheme.validate_all(&[]), Ok(()));
+
+        let too_many = vec![Scalar::zero(); usize::from(u16::MAX) + 1];
+        let rejected = vss_scheme.validate_all(&too_many).unwrap_err();
+        assert_eq!(rejected.len(), too_many.len());
+
+        let shares = secret_shares.to_vec();
+        let mut empty = vss_scheme.clone();
+        empty.commitments.clear();
+        assert_eq!(empty.validate_all(&shares), Err(vec![0, 1, 2, 3, 4]));
+        let mut too_long = vss_scheme;
+        too_long.commitments.push(Point::generator().to_point());
+        assert_eq!(too_long.validate_all(&shares), Err(vec![0, 1, 2, 3, 4]));
+    }
+
+    test_for_all_curves_and_hashes!(test_complaint_against_corrupt_dealer);
+
+    fn test_complaint_against_corrupt_dealer<E: Curve, H: Digest + Clone>() {
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(2, 5, &Scalar::random());
+        let mut shares = secret_shares.to_vec();
+        // Dealer sends a corrupt share to party 3, who complains
+        shares[3] = &shares[3] + Scalar::from(1);
+        assert!(vss_scheme.validate_share(&shares[3], 4).is_err());
+
+        // Corrupt share doesn't pass validation, so dealer can only publish it as is
+        assert_eq!(
+            vss_scheme.reveal_share(&shares, 3).err(),
+            Some(ErrorSS::VerifyShareError)
+        );
+        assert_eq!(
+            VerifiableSS::verify_complaint(&vss_scheme, &shares[3], 3),
+            ComplaintVerdict::DisqualifyDealer
+        );
+
+        // Complaint of a party that received a valid share is rejected
+        let revealed = vss_scheme.reveal_share(&shares, 1).unwrap();
+        assert_eq!(
+            VerifiableSS::verify_complaint(&vss_scheme, &revealed, 1),
+            ComplaintVerdict::RejectComplaint
+        );
+        // Complaints of non-existent parties are rejected
+        assert_eq!(
+            VerifiableSS::verify_complaint(&vss_scheme, &revealed, 5),
+            ComplaintVerdict::RejectComplaint
+        );
+        assert_eq!(
+            vss_scheme.reveal_share(&shares, 5).err(),
+            Some(ErrorSS::VerifyShareError)
+        );
+    }
+
+    test_for_all_curves!(test_reconstruct_checked);
+
+    fn test_reconstruct_checked<E: Curve>() {
+        let secret = Scalar::random();
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, sha2::Sha256>::share(2, 5, &secret);
+        let indices = [4, 0, 2];
+        let mut shares: Vec<_> = indices
+            .iter()
+            .map(|&i| secret_shares[usize::from(i)].clone())
+            .collect();
+        assert_eq!(
+            vss_scheme.reconstruct_checked(&indices, &shares),
+            Ok(secret.clone())
+        );
+
+        // Corrupted share would make plain reconstruct output a wrong secret
+        shares[1] = &shares[1] + Scalar::from(1);
+        assert_ne!(vss_scheme.reconstruct(&indices, &shares).unwrap(), secret);
+        assert_eq!(
+            vss_scheme.reconstruct_checked(&indices, &shares),
+            Err(ErrorSS::InvalidShare { index: 0 })
+        );
+    }
+
+    test_for_all_curves!(test_reconstruct_rejects_invalid_input);
+
+    fn test_reconstruct_rejects_invalid_input<E: Curve>() {
+        let (vss_scheme, secret_shares) =
+            VerifiableSS::<E, sha2::Sha256>::share(2, 5, &Scalar::random());
+
+        assert_eq!(
+            vss_scheme.reconstruct(&[0, 1], &secret_shares[..2]),
+            Err(ErrorSS::BelowThreshold)
+        );
+        assert_eq!(
+            vss_scheme.reconstruct(&[0, 1, 2], &secret_shares[..4]),
+            Err(ErrorSS::MismatchedLengths)
+        );
+        assert_eq!(
+            vss_scheme.reconstruct_checked(&[0, 1], &secret_shares[..2]),
+            Err(ErrorSS::BelowThreshold)
+        );
+    }
+
+    test_for_all_curves!(test_share_with_seeded_rng);
+
+    fn test_share_with_seeded_rng<E: Curve>() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let secret = Scalar::random();
+        let share = |seed: u64| {
+            VerifiableSS::<E, sha2::Sha256>::share_with_rng(
+                2,
+                5,
+                &secret,
+                &mut ChaCha20Rng::seed_from_u64(seed),
+            )
+        };
+
+        let (vss1, shares1) = share(42);
+        let (vss2, shares2) = share(42);
+        assert_eq!(vss1.commitments, vss2.commitments);
+        assert_eq!(shares1.to_vec(), shares2.to_vec());
+        assert_eq!(
+            vss1.reconstruct(&[0, 1, 2], &shares1[..3]),
+            Ok(secret.clone())
+        );
+        for (i, share) in (1..).zip(shares1.iter()) {
+            assert_eq!(vss1.validate_share(share, i), Ok(()));
+        }
+
+        let (vss3, shares3) = share(43);
+        assert_ne!(vss1.commitments, vss3.commitments);
+        assert_ne!(shares1.to_vec(), shares3.to_vec());
+    }
+
+    test_for_all_curves!(test_plain_shamir_matches_vss);
+
+    fn test_plain_shamir_matches_vss<E: Curve>() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let secret = Scalar::<E>::random();
+        let shares =
+            ShamirSecretSharing::share_with_rng(2, 5, &secret, &mut ChaCha20Rng::seed_from_u64(1));
+        let (vss_scheme, vss_shares) = VerifiableSS::<E, sha2::Sha256>::share_with_rng(
+            2,
+            5,
+            &secret,
+            &mut ChaCha20Rng::seed_from_u64(1),
+        );
+        assert_eq!(shares, vss_shares.to_vec());
+
+        let indices = [4, 0, 2];
+        let subset: Vec<_> = indices
+            .iter()
+            .map(|&i| shares[usize::from(i)].clone())
+            .collect();
+        let reconstructed = vss_scheme.parameters.reconstruct(&indices, &subset);
+        assert_eq!(reconstructed, vss_scheme.reconstruct(&indices, &subset));
+        assert_eq!(reconstructed, Ok(secret.clone()));
+
+        let shares = ShamirSecretSharing::share(2, 5, &secret);
+        assert_eq!(
+            vss_scheme.parameters.reconstruct(&[2, 3, 4], &shares[2..]),
+            Ok(secret)
+        );
+        assert_eq!(
+            vss_scheme.parameters.reconstruct(&[0, 1], &shares[..2]),
+            Err(ErrorSS::BelowThreshold)
+        );
+    }
+
+    test_for_all_curves!(test_to_pedersen);
+
+    fn test_to_pedersen<E: Curve>() {
+        let secret = Scalar::random();
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, sha2::Sha256>::share(2, 5, &secret);
+        let blinding_polynomial = Polynomial::sample_exact(2);
+        let pedersen_scheme = vss_scheme.to_pedersen(&blinding_polynomial);
+        assert_eq!(pedersen_scheme.parameters, vss_scheme.parameters);
+
+        for (i, share) in (1..).zip(secret_shares.iter()) {
+            let blinding_share = blinding_polynomial.evaluate_bigint(i);
+            assert_eq!(vss_scheme.validate_share(share, i), Ok(()));
+            assert_eq!(
+                pedersen_scheme.validate_share(share, &blinding_share, i),
+                Ok(())
+            );
+            assert!(pedersen_scheme
+                .validate_share(share, &(blinding_share + Scalar::one()), i)
+                .is_err());
+        }
+        assert_eq!(
+            pedersen_scheme.reconstruct(
+                &[0, 1, 4],
+                &[
+                    secret_shares[0].clone(),
+                    secret_shares[1].clone(),
+                    secret_shares[4].clone()
+                ]
+            ),
+            Ok(secret)
+        );
+
+        // Blinding polynomial of lower degree is padded with zero coefficients
+        let constant = Polynomial::from_coefficients(vec![Scalar::random()]);
+        let pedersen_scheme = vss_scheme.to_pedersen(&constant);
+        assert_eq!(
+            pedersen_scheme.validate_share(&secret_shares[3], &constant.evaluate_bigint(4), 4),
+            Ok(())
+        );
+    }
+
+    test_for_all_curves!(test_compact_encoding_roundtrip);
+
+    fn test_compact_encoding_roundtrip<E: Curve>() {
+        let (vss_scheme, secret_shares) =
+            VerifiableSS::<E, sha2::Sha256>::share(2, 5, &Scalar::random());
+        let bytes = vss_scheme.to_bytes();
+        let decoded = VerifiableSS::<E, sha2::Sha256>::from_bytes(&bytes).unwrap();
+        assert_same_scheme(&decoded, &vss_scheme);
+        assert_eq!(decoded.validate_share(&secret_shares[0], 1), Ok(()));
+
+        let point_len = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+        let scalar_len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+        assert_eq!(bytes.len(), 4 + 4 + 1 + 4 * point_len + scalar_len);
+        let json = serde_json::to_vec(&vss_scheme).unwrap();
+        assert!(
+            bytes.len() * 2 < json.len(),
+            "{} vs {}",
+            bytes.len(),
+            json.len()
+        );
+
+        // Truncated, extended or empty encodings are rejected, as well as non-canonical varint
+        for malformed in [
+            &bytes[..bytes.len() - 1],
+            &[&bytes[..], &[0]].concat(),
+            &bytes[..8],
+            &[&bytes[..8], &[0]].concat(),
+            &[&bytes[..8], &[bytes[8] | 0x80, 0x00], &bytes[9..]].concat(),
+        ] {
+            assert!(VerifiableSS::<E, sha2::Sha256>::from_bytes(malformed).is_err());
+        }
+    }
+
+    #[test]
+    fn test_compact_encoding_of_many_commitments() {
+        use crate::elliptic::curves::Secp256k1;
+
+        // 200 commitments need 2-byte varint
+        let (vss_scheme, _) =
+            VerifiableSS::<Secp256k1, sha2::Sha256>::share(199, 200, &Scalar::random());
+        let bytes = vss_scheme.to_bytes();
+        assert_eq!(bytes[8..10], [0xc8, 0x01]);
+        assert_same_scheme(
+            &VerifiableSS::<Secp256k1, sha2::Sha256>::from_bytes(&bytes).unwrap(),
+            &vss_scheme,
+        );
+
+        // Same length padded with a zero byte
+        let padded = [&bytes[..8], &[0xc8, 0x81, 0x00], &bytes[10..]].concat();
+        assert!(VerifiableSS::<Secp256k1, sha2::Sha256>::from_bytes(&padded).is_err());
+    }
+
+    // VerifiableSS<E, H> is PartialEq only if H is
+    fn assert_same_scheme<E: Curve, H: Digest + Clone>(
+        a: &VerifiableSS<E, H>,
+        b: &VerifiableSS<E, H>,
+    ) {
+        assert_eq!(a.parameters, b.parameters);
+        assert_eq!(a.commitments, b.commitments);
+        assert_eq!(a.proof.pk, b.proof.pk);
+        assert_eq!(a.proof.pk_t_rand_commitment, b.proof.pk_t_rand_commitment);
+        assert_eq!(a.proof.challenge_response, b.proof.challenge_response);
+    }
+
+    test_for_all_curves!(test_reconstruct_public);
+
+    fn test_reconstruct_public<E: Curve>() {
+        let (vss_scheme, secret_shares) =
+            VerifiableSS::<E, sha2::Sha256>::share(2, 5, &Scalar::random());
+        let public_shares: Vec<_> = secret_shares
+            .iter()
+            .map(|share| Point::generator() * share)
+            .collect();
+
+        let indices = [3, 0, 4];
+        let subset: Vec<_> = indices
+            .iter()
+            .map(|&i| public_shares[usize::from(i)].clone())
+            .collect();
+        assert_eq!(
+            vss_scheme.reconstruct_public(&indices, &subset),
+            Ok(vss_scheme.commitments[0].clone())
+        );
+        assert_eq!(
+            vss_scheme.reconstruct_public(&[0, 1, 2, 3, 4], &public_shares),
+            Ok(vss_scheme.commitments[0].clone())
+        );
+        assert_eq!(
+            vss_scheme.reconstruct_public(&[0, 1], &public_shares[..2]),
+            Err(ErrorSS::BelowThreshold)
+        );
+    }
+
+    test_for_all_curves!(test_reconstruct_with_known);
+
+    fn test_reconstruct_with_known<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (vss, shares) = VerifiableSS::<E, sha2::Sha256>::share(3, 7, &secret);
+        let full = vss
+            .reconstruct(&[0, 2, 5, 6], &[0, 2, 5, 6].map(|i| shares[i].clone()))
+            .unwrap();
+        assert_eq!(full, secret);
+
+        let known = [(5, shares[5].clone()), (2, shares[2].clone())];
+        assert_eq!(
+            vss.reconstruct_with_known(&[0, 6], &[shares[0].clone(), shares[6].clone()], &known),
+            Ok(full.clone())
+        );
+        // Everything is known
+        let known: Vec<_> = (0u16..4)
+            .map(|i| (i, shares[usize::from(i)].clone()))
+            .collect();
+        assert_eq!(vss.reconstruct_with_known(&[], &[], &known), Ok(full));
+
+        let known = [(5, shares[5].clone())];
+        assert_eq!(
+            vss.reconstruct_with_known(&[0, 6], &[shares[0].clone(), shares[6].clone()], &known)
+                .err(),
+            Some(ErrorSS::BelowThreshold)
+        );
+        assert_eq!(
+            vss.reconstruct_with_known(
+                &[0, 5, 6],
+                &[shares[0].clone(), shares[5].clone(), shares[6].clone()],
+                &known
+            )
+            .err(),
+            Some(ErrorSS::DuplicateIndex)
+        );
+        assert_eq!(
+            vss.reconstruct_with_known(&[0, 6], &[shares[0].clone()], &known)
+                .err(),
+            Some(ErrorSS::MismatchedLengths)
+        );
+        assert_eq!(
+            KnownShares::new(&[(1, shares[1].clone()), (1, shares[1].clone())]).err(),
+            Some(ErrorSS::DuplicateIndex)
+        );
+    }
+
+    test_for_all_curves!(test_reconstruct_with_precomputed_known);
+
+    fn test_reconstruct_with_precomputed_known<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (vss, shares) = VerifiableSS::<E, sha2::Sha256>::share(5, 12, &secret);
+        let known_indices = [3u16, 7, 10];
+        let known =
+            KnownShares::new(&known_indices.map(|i| (i, shares[usize::from(i)].clone()))).unwrap();
+
+        // The same precomputation is reused with different sets of remaining shares
+        for indices in [
+            vec![0u16, 1, 2],
+            vec![11, 5, 4, 0],
+            vec![1, 2, 4, 5, 6, 8, 9, 11],
+        ] {
+            let subset: Vec<_> = indices
+                .iter()
+                .map(|&i| shares[usize::from(i)].clone())
+                .collect();
+            let all_indices: Vec<u16> = indices.iter().chain(&known_indices).copied().collect();
+            let all_shares: Vec<_> = all_indices
+                .iter()
+                .map(|&i| shares[usize::from(i)].clone())
+                .collect();
+            let full = vss.reconstruct(&all_indices, &all_shares).unwrap();
+            assert_eq!(full, secret);
+            assert_eq!(
+                vss.reconstruct_with_precomputed_known(&indices, &subset, &known),
+                Ok(full)
+            );
+        }
+
+        // Wrong share yields wrong secret, just like in full reconstruction
+        let mut subset = vec![shares[0].clone(), shares[1].clone(), shares[2].clone()];
+        subset[1] = &subset[1] + Scalar::one();
+        assert_ne!(
+            vss.reconstruct_with_precomputed_known(&[0, 1, 2], &subset, &known),
+            Ok(secret)
+        );
+        assert_eq!(
+            vss.reconstruct_with_precomputed_known(&[0, 1], &subset[..2], &known),
+            Err(ErrorSS::BelowThreshold)
+        );
+        assert_eq!(
+            vss.reconstruct_with_precomputed_known(&[0, 1, 7], &subset, &known),
+            Err(ErrorSS::DuplicateIndex)
+        );
+    }
+
+    test_for_all_curves!(test_evaluate_multi);
+
+    fn test_evaluate_multi<E: Curve>() {
+        for (degree, n) in [
+            (0, 1),
+            (1, 1),
+            (3, 2),
+            (4, 5),
+            (5, 4),
+            (16, 17),
+            (10, 33),
+            (100, 150),
+            (200, 70),
+        ] {
+            let polynomial = Polynomial::<E>::sample_exact(degree);
+            let points: Vec<Scalar<E>> = (0..n).map(|_| Scalar::random()).collect();
+            let expected: Vec<_> = polynomial.evaluate_many(&points).collect();
+            assert_eq!(
+                VerifiableSS::<E, sha2::Sha256>::evaluate_multi(polynomial.coefficients(), &points),
+                expected,
+                "degree = {}, n = {}",
+                degree,
+                n
+            );
+        }
+
+        // Coinciding points and zero polynomial
+        let polynomial = Polynomial::<E>::sample_exact(3);
+        let x = Scalar::random();
+        let points = vec![x.clone(), Scalar::zero(), x];
+        let expected: Vec<_> = polynomial.evaluate_many(&points).collect();
+        assert_eq!(
+            VerifiableSS::<E, sha2::Sha256>::evaluate_multi(polynomial.coefficients(), &points),
+            expected
+        );
+        assert_eq!(
+            VerifiableSS::<E, sha2::Sha256>::evaluate_multi(&[], &points),
+            vec![Scalar::zero(); 3]
+        );
+        assert!(
+            VerifiableSS::<E, sha2::Sha256>::evaluate_multi(polynomial.coefficients(), &[])
+                .is_empty()
+        );
+    }
+
+    test_for_all_curves!(test_aggregate_public_keys);
+
+    fn test_aggregate_public_keys<E: Curve>() {
+        let secrets: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let schemes: Vec<_> = secrets
+            .iter()
+            .map(|secret| VerifiableSS::<E, sha2::Sha256>::share(1, 3, secret).0)
+            .collect();
+        for (vss, secret) in schemes.iter().zip(&secrets) {
+            assert_eq!(*vss.public_key(), Point::generator() * secret);
+        }
+
+        let group_public_key = aggregate_public_keys(&schemes.iter().collect::<Vec<_>>());
+        let group_secret: Scalar<E> = secrets.iter().sum();
+        assert_eq!(group_public_key, Point::generator() * group_secret);
+
+        let combined = schemes[0]
+            .add(&schemes[1])
+            .and_then(|vss| vss.add(&schemes[2]))
+            .unwrap();
+        assert_eq!(*combined.public_key(), group_public_key);
+    }
+
+    test_for_all_curves!(test_validate_parameters);
+    fn test_validate_parameters<E: Curve>() {
+        let secret = Scalar::random();
+        let (vss, shares) = VerifiableSS::<E, sha2::Sha256>::share(2, 5, &secret);
+        assert_eq!(vss.validate_parameters(), Ok(()));
+
+        // Dealer sends too short commitment vector
+        let mut short = vss.clone();
+        short.commitments.pop();
+        assert_eq!(short.validate_parameters(), Err(ErrorSS::InvalidParameters));
+        assert_eq!(
+            short.validate_share(&shares[0], 1),
+            Err(ErrorSS::InvalidParameters)
+        );
+        assert_eq!(
+            short.reconstruct(&[0, 1, 2], &shares[..3]),
+            Err(ErrorSS::InvalidParameters)
+        );
+
+        // Empty commitment vector must not cause a panic
+        let mut empty = vss.clone();
+        empty.commitments.clear();
+        assert_eq!(
+            empty.validate_share(&shares[0], 1),
+            Err(ErrorSS::InvalidParameters)
+        );
+
+        let mut long = vss.clone();
+        long.commitments.push(Point::generator().to_point());
+        assert_eq!(long.validate_parameters(), Err(ErrorSS::InvalidParameters));
+
+        let mut too_few_parties = vss;
+        too_few_parties.parameters.share_count = 2;
+        assert_eq!(
+            too_few_parties.validate_parameters(),
+            Err(ErrorSS::InvalidParameters)
+        );
+    }
+
+    test_for_all_curves!(test_public_shares);
+
+    fn test_public_shares<E: Curve>() {
+        let (vss_scheme, secret_shares) =
+            VerifiableSS::<E, sha2::Sha256>::share(2, 5, &Scalar::random());
+        let public_shares = vss_scheme.public_shares();
+        assert_eq!(public_shares.len(), 5);
+        for (public_share, secret_share) in public_shares.iter().zip(secret_shares.iter()) {
+            assert_eq!(*public_share, Point::generator() * secret_share);
+        }
+    }
+
+    test_for_all_curves!(test_add_schemes);
+
+    fn test_add_schemes<E: Curve>() {
+        // Three dealers share their secrets, the group secret is a sum of them
+        let secrets: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let dealt: Vec<_> = secrets
+            .iter()
+            .map(|secret| VerifiableSS::<E, sha2::Sha256>::share(2, 5, secret))
+            .collect();
+
+        let combined = dealt[1..]
+            .iter()
+            .try_fold(dealt[0].0.clone(), |acc, (vss, _)| acc.add(vss))
+            .unwrap();
+        let group_secret: Scalar<E> = secrets.iter().sum();
+        assert_eq!(combined.commitments[0], Point::generator() * &group_secret);
+
+        let combined_shares: Vec<_> = (0..5)
+            .map(|i| {
+                let my_shares: Vec<_> = dealt.iter().map(|(_, shares)| shares[i].clone()).collect();
+                VerifiableSS::<E, sha2::Sha256>::combine_shares(&my_shares)
+            })
+            .collect();
+        for (i, share) in (1..).zip(&combined_shares) {
+            assert_eq!(
+                combined.validate_share_public(&(Point::generator() * share), i),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            combined.reconstruct(
+                &[0, 2, 4],
+                &[
+                    combined_shares[0].clone(),
+                    combined_shares[2].clone(),
+                    combined_shares[4].clone()
+                ]
+            ),
+            Ok(group_secret)
+        );
+
+        let (other, _) = VerifiableSS::<E, sha2::Sha256>::share(3, 5, &Scalar::random());
+        assert_eq!(
+            combined.add(&other).err(),
+            Some(ErrorSS::MismatchedParameters)
+        );
+    }
+
+    test_for_all_curves_and_hashes!(test_commitment_digest);
+
+    fn test_commitment_digest<E: Curve, H: Digest + Clone>() {
+        let (vss_scheme, _) = VerifiableSS::<E, H>::share(2, 5, &Scalar::random());
+        let digest = vss_scheme.commitment_digest();
+        assert_eq!(vss_scheme.verify_against_digest(&digest), Ok(()));
+        assert_eq!(vss_scheme.clone().commitment_digest(), digest);
+
+        // Dealer equivocates by sending a different scheme to another party
+        let (another_scheme, _) = VerifiableSS::<E, H>::share(2, 5, &Scalar::random());
+        assert_ne!(another_scheme.commitment_digest(), digest);
+        assert_eq!(
+            another_scheme.verify_against_digest(&digest),
+            Err(VerifyShareError)
+        );
+
+        // Even a single modified commitment is detected
+        let mut tampered = vss_scheme.clone();
+        tampered.commitments[2] = &tampered.commitments[2] + Point::generator();
+        assert_eq!(
+            tampered.verify_against_digest(&digest),
+            Err(VerifyShareError)
+        );
+
+        // Digest binds parameters of the scheme
+        let mut other_parameters = vss_scheme.clone();
+        other_parameters.parameters.share_count = 6;
+        assert_ne!(other_parameters.commitment_digest(), digest);
+        let mut other_parameters = vss_scheme.clone();
+        other_parameters.parameters.threshold = 3;
+        assert_ne!(other_parameters.commitment_digest(), digest);
+
+        // Digest of commitments' prefix differs from the digest of the commitments
+        let mut prefix = vss_scheme;
+        prefix.commitments.pop();
+        prefix.parameters.threshold = 1;
+        assert_ne!(prefix.commitment_digest(), digest);
+    }
+
+    test_for_all_curves_and_hashes!(test_proactive_refresh);
+
+    fn test_proactive_refresh<E: Curve, H: Digest + Clone>() {
+        let secret = Scalar::random();
+        let (mut vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(3, 5, &secret);
+        let original_shares = secret_shares.to_vec();
+        let mut shares = original_shares.clone();
+
+        for _ in 0..2 {
+            // two parties deal updates
+            let (updates, update_shares): (Vec<_>, Vec<_>) = (0..2)
+                .map(|_| VerifiableSS::<E, H>::share_zero(3, 5))
+                .unzip();
+            let refreshed: Vec<_> = (1..)
+                .zip(&shares)
+                .map(|(i, share)| {
+                    let my_updates: Vec<_> = update_shares
+                        .iter()
+                        .map(|s| s[usize::from(i) - 1].clone())
+                        .collect();
+                    vss_scheme
+                        .apply_refresh(share, i, &updates, &my_updates)
+                        .unwrap()
+                })
+                .collect();
+            vss_scheme = refreshed[0].0.clone();
+            assert!(refreshed
+                .iter()
+                .all(|(scheme, _)| scheme.commitments == vss_scheme.commitments));
+            assert_ne!(vss_scheme.commitments[1..], updates[0].commitments[1..]);
+            shares = refreshed.into_iter().map(|(_, share)| share).collect();
+
+            for (i, share) in (1..).zip(&shares) {
+                assert!(vss_scheme.validate_share(share, i).is_ok());
+            }
+            let indices = [0, 1, 3, 4];
+            let subset: Vec<_> = indices
+                .iter()
+                .map(|&i| shares[usize::from(i)].clone())
+                .collect();
+            assert_eq!(vss_scheme.reconstruct(&indices, &subset).unwrap(), secret);
+        }
+
+        // old shares are useless in combination with refreshed ones
+        assert!(vss_scheme.validate_share(&original_shares[0], 1).is_err());
+        let mixed = vec![
+            original_shares[0].clone(),
+            shares[1].clone(),
+            shares[2].clone(),
+            shares[3].clone(),
+        ];
+        assert_ne!(
+            vss_scheme.reconstruct(&[0, 1, 2, 3], &mixed).unwrap(),
+            secret
+        );
+
+        // update sharing nonzero secret is rejected
+        let (update, update_shares) = VerifiableSS::<E, H>::share(3, 5, &Scalar::random());
+        assert_eq!(
+            vss_scheme
+                .apply_refresh(&shares[0], 1, &[update], &update_shares[..1])
+                .err(),
+            Some(ErrorSS::InvalidShare { index: 0 })
+        );
+        // update with different parameters is rejected
+        let (update, update_shares) = VerifiableSS::<E, H>::share_zero(2, 5);
+        assert_eq!(
+            vss_scheme
+                .apply_refresh(&shares[0], 1, &[update], &update_shares[..1])
+                .err(),
+            Some(ErrorSS::MismatchedParameters)
+        );
+        // update share not matching its scheme is rejected
+        let (update, update_shares) = VerifiableSS::<E, H>::share_zero(3, 5);
+        assert_eq!(
+            vss_scheme
+                .apply_refresh(&shares[0], 1, &[update], &update_shares[1..2])
+                .err(),
+            Some(ErrorSS::InvalidShare { index: 0 })
+        );
+        // old share is rejected
+        let (update, update_shares) = VerifiableSS::<E, H>::share_zero(3, 5);
+        assert_eq!(
+            vss_scheme
+                .apply_refresh(&original_shares[0], 1, &[update], &update_shares[..1])
+                .err(),
+            Some(ErrorSS::VerifyShareError)
+        );
+    }
+
+    test_for_all_curves_and_hashes!(test_reshare_to_new_parameters);
+
+    fn test_reshare_to_new_parameters<E: Curve, H: Digest + Clone>() {
+        let secret = Scalar::random();
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(2, 5, &secret);
+
+        // parties 1, 3 and 4 reshare 2-of-5 sharing to 3-of-7
+        let dealers = [1u16, 3, 4];
+        let (schemes, sub_shares): (Vec<_>, Vec<_>) = dealers
+            .iter()
+            .map(|&d| {
+                vss_scheme
+                    .reshare_to(&secret_shares[usize::from(d) - 1], d, 3, 7)
+                    .unwrap()
+            })
+            .unzip();
+
+        let mut new_scheme = None;
+        let mut new_shares = vec![];
+        for j in 1..=7u16 {
+            let received: Vec<_> = sub_shares
+                .iter()
+                .map(|s| s[usize::from(j) - 1].clone())
+                .collect();
+            let (scheme, share) = vss_scheme
+                .combine_reshared(j, &dealers, &schemes, &received)
+                .unwrap();
+            assert_eq!(scheme.reconstruct_limit(), 4);
+            assert!(scheme.validate_share(&share, j).is_ok());
+            assert_eq!(
+                new_scheme.get_or_insert(scheme.clone()).commitments,
+                scheme.commitments
+            );
+            new_shares.push(share);
+        }
+        let new_scheme = new_scheme.unwrap();
+        assert_eq!(new_scheme.public_key(), vss_scheme.public_key());
+
+        let indices = [0, 2, 4, 6];
+        let subset: Vec<_> = indices
+            .iter()
+            .map(|&i| new_shares[usize::from(i)].clone())
+            .collect();
+        assert_eq!(new_scheme.reconstruct(&indices, &subset).unwrap(), secret);
+        assert_eq!(
+            new_scheme.reconstruct(&indices[..3], &subset[..3]),
+            Err(ErrorSS::BelowThreshold)
+        );
+
+        let received: Vec<_> = sub_shares.iter().map(|s| s[0].clone()).collect();
+        // not enough dealers
+        assert_eq!(
+            vss_scheme
+                .combine_reshared(1, &dealers[..2], &schemes[..2], &received[..2])
+                .err(),
+            Some(ErrorSS::BelowThreshold)
+        );
+        // dealer resharing a share of another party is rejected
+        assert_eq!(
+            vss_scheme
+                .combine_reshared(1, &[1, 3, 5], &schemes, &received)
+                .err(),
+            Some(ErrorSS::InvalidShare { index: 2 })
+        );
+        assert_eq!(
+            vss_scheme
+                .combine_reshared(1, &[1, 3, 3], &schemes, &received)
+                .err(),
+            Some(ErrorSS::DuplicateIndex)
+        );
+        // a wrong share is rejected
+        let mut wrong = received.clone();
+        wrong[1] = sub_shares[1][1].clone();
+        assert_eq!(
+            vss_scheme
+                .combine_reshared(1, &dealers, &schemes, &wrong)
+                .err(),
+            Some(ErrorSS::InvalidShare { index: 1 })
+        );
+        // a dealer can't reshare a share that doesn't match the scheme
+        assert_eq!(
+            vss_scheme.reshare_to(&secret_shares[0], 2, 3, 7).err(),
+            Some(ErrorSS::VerifyShareError)
+        );
+        assert_eq!(
+            vss_scheme.reshare_to(&secret_shares[0], 1, 7, 7).err(),
+            Some(ErrorSS::InvalidParameters)
+        );
+    }
+
+    test_for_all_curves_and_hashes!(test_lagrange_interpolation_ct_matches_non_ct);
+
+    fn test_lagrange_interpolation_ct_matches_non_ct<E: Curve, H: Digest + Clone>() {
+        for n in 1..8 {
+            let points: Vec<Scalar<E>> = (0..n).map(|_| Scalar::random()).collect();
+            let values: Vec<Scalar<E>> = (0..n).map(|_| Scalar::random()).collect();
+            assert_eq!(
+                VerifiableSS::<E, H>::lagrange_interpolation_at_zero_ct(&points, &values),
+                VerifiableSS::<E, H>::lagrange_interpolation_at_zero(&points, &values),
+            );
+        }
+
+        let secret = Scalar::random();
+        let (_vss_scheme, secret_shares) = VerifiableSS::<E, H>::share(3, 5, &secret);
+        let points: Vec<Scalar<E>> = (1..=5).map(|i: u16| Scalar::from(i)).collect();
+        assert_eq!(
+            VerifiableSS::<E, H>::lagrange_interpolation_at_zero_ct(&points, &secret_shares),
+            secret
+        );
+    }
+
+    test_for_all_curves!(run_vss_roundtrip);
+
+    /// Shares and reconstructs a secret for every `t < n <= 6`
+    fn run_vss_roundtrip<E: Curve>() {
+        for n in 2..=6u16 {
+            for t in 1..n {
+                let secret = Scalar::random();
+                let (vss_scheme, secret_shares) =
+                    VerifiableSS::<E, sha2::Sha256>::share(t, n, &secret);
+                assert_eq!(vss_scheme.validate_all(&secret_shares), Ok(()));
+
+                // reconstruct from the first and the last t+1 shares
+                let k = usize::from(t) + 1;
+                let first: Vec<u16> = (0..t + 1).collect();
+                let last: Vec<u16> = (n - t - 1..n).collect();
+                for indices in [first, last] {
+                    let shares: Vec<_> = indices
+                        .iter()
+                        .map(|&i| secret_shares[usize::from(i)].clone())
+                        .collect();
+                    assert_eq!(vss_scheme.reconstruct(&indices, &shares).unwrap(), secret);
+
+                    // t shares are not enough
+                    let reconstructed =
+                        VerifiableSS::<E, sha2::Sha256>::lagrange_interpolation_at_zero(
+                            &indices[..k - 1]
+                                .iter()
+                                .map(|&i| Scalar::from(i + 1))
+                                .collect::<Vec<_>>(),
+                            &shares[..k - 1],
+                        );
+                    assert_ne!(reconstructed, secret);
+                }
+            }
+        }
+    }
+
+    test_for_all_curves_and_hashes!(test_serde_roundtrip);
+
+    fn test_serde_roundtrip<E: Curve, H: Digest + Clone>() {
+        let (vss_scheme, _) = VerifiableSS::<E, H>::share(2, 5, &Scalar::random());
+
+        let json = serde_json::to_string(&vss_scheme).unwrap();
+        let deserialized: VerifiableSS<E, H> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.parameters, vss_scheme.parameters);
+        assert_eq!(deserialized.commitments, vss_scheme.commitments);
+        assert_eq!(deserialized.proof.pk, vss_scheme.proof.pk);
+        assert_eq!(
+            deserialized.proof.pk_t_rand_commitment,
+            vss_scheme.proof.pk_t_rand_commitment
+        );
+        assert_eq!(
+            deserialized.proof.challenge_response,
+            vss_scheme.proof.challenge_response
+        );
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+        // Commitments are serialized in compressed form
+        let compressed_len = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let commitments = value["commitments"].as_array().unwrap();
+        assert_eq!(commitments.len(), vss_scheme.commitments.len());
+        for commitment in commitments {
+            let point = commitment["point"].as_str().unwrap();
+            assert_eq!(point.len(), 2 * compressed_len);
+        }
+        assert_eq!(value["parameters"]["threshold"], 2);
+        assert_eq!(value["parameters"]["share_count"], 5);
+
+        let bytes = bincode::serialize(&vss_scheme).unwrap();
+        let deserialized: VerifiableSS<E, H> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized.parameters, vss_scheme.parameters);
+        assert_eq!(deserialized.commitments, vss_scheme.commitments);
+        assert_eq!(deserialized.proof.pk, vss_scheme.proof.pk);
+        assert_eq!(
+            deserialized.proof.pk_t_rand_commitment,
+            vss_scheme.proof.pk_t_rand_commitment
+        );
+        assert_eq!(
+            deserialized.proof.challenge_response,
+            vss_scheme.proof.challenge_response
+        );
+        assert_eq!(bincode::serialize(&deserialized).unwrap(), bytes);
+
+        // bincode encodes the point as length-prefixed compressed bytes following the curve name
+        for commitment in &vss_scheme.commitments {
+            let encoded = bincode::serialize(commitment).unwrap();
+            let mut expected = (compressed_len as u64).to_le_bytes().to_vec();
+            expected.extend_from_slice(&commitment.to_bytes(true));
+            assert!(encoded.ends_with(&expected));
+            assert_eq!(encoded.len(), 8 + E::CURVE_NAME.len() + expected.len());
+        }
+    }
 }
@@ -5,7 +5,11 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 
+mod berlekamp_welch;
 pub mod feldman_vss;
+mod packed;
+pub mod pedersen_vss;
 mod polynomial;
+mod robust;
 
-pub use polynomial::{Polynomial, PolynomialDegree};
+pub use polynomial::{NttNotSupported, Polynomial, PolynomialDegree};
@@ -0,0 +1,129 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use std::convert::TryFrom;
+use std::iter;
+
+use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::{SecretShares, VerifiableSS};
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Scalar};
+use crate::ErrorSS;
+
+impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
+    /// Shares `k = secrets.len()` secrets via a single polynomial (packed secret sharing)
+    ///
+    /// Implements packing by Franklin and Yung: polynomial $f$ of degree $t+k-1$ is sampled such
+    /// that $f(-j) = secrets_j$ for $j = 0, \dots, k-1$, and shares are $f(1), \dots, f(n)$ as
+    /// usual. Any $t$ shares reveal nothing about the secrets, any $t+k$ shares reveal all of them
+    /// (use [reconstruct_packed](Self::reconstruct_packed)). Resulting scheme has threshold
+    /// $t+k-1$, so shares are validated as usual, and DLog proof is issued for `secrets[0]`
+    /// (the constant term).
+    ///
+    /// ## Panics
+    /// Panics if `secrets` is empty, if $t+k-1$ doesn't fit into `u16`, or if $t+k-1 \ge n$.
+    pub fn share_packed(
+        t: u16,
+        n: u16,
+        secrets: &[Scalar<E>],
+    ) -> (VerifiableSS<E, H>, SecretShares<E>) {
+        assert!(!secrets.is_empty());
+        let k = u16::try_from(secrets.len()).expect("too many secrets");
+        let threshold = t.checked_add(k - 1).expect("t + k - 1 overflows u16");
+        assert!(threshold < n);
+
+        // Secrets are placed at 0, -1, ..., -(k-1), randomness at -k, ..., -(t+k-1)
+        let points: Vec<Scalar<E>> = (0..=threshold).map(|j| -Scalar::from(j)).collect();
+        let values: Vec<Scalar<E>> = secrets
+            .iter()
+            .cloned()
+            .chain(iter::repeat_with(Scalar::random).take(usize::from(t)))
+            .collect();
+        let polynomial = Polynomial::interpolate(&points, &values);
+        Self::share_polynomial(threshold, n, polynomial, &secrets[0])
+    }
+
+    /// Reconstructs `k` secrets shared via [share_packed](Self::share_packed) from shares of
+    /// parties at (0-based) `indices`
+    ///
+    /// Returns the same errors as [reconstruct](Self::reconstruct): at least `t+k` shares are
+    /// required, which is the reconstruction limit of packed scheme.
+    ///
+    /// ## Panics
+    /// Panics if `k` exceeds [reconstruct_limit](Self::reconstruct_limit).
+    pub fn reconstruct_packed(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+        k: u16,
+    ) -> Result<Vec<Scalar<E>>, ErrorSS> {
+        assert!(k <= self.reconstruct_limit());
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        Ok((0..k)
+            .map(|j| {
+                let x = -Scalar::from(j);
+                (0..)
+                    .zip(shares)
+                    .map(|(i, share)| share * Polynomial::lagrange_basis(&x, i, &points))
+                    .sum()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic::curves::Point;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_packed_sharing);
+
+    fn test_packed_sharing<E: Curve>() {
+        let secrets: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let (vss, shares) = VerifiableSS::<E, sha2::Sha256>::share_packed(2, 8, &secrets);
+
+        // Degree t+k-1 = 4, so commitments have the usual shape
+        assert_eq!(vss.parameters.threshold, 4);
+        assert_eq!(vss.commitments.len(), 5);
+        assert_eq!(*vss.public_key(), Point::generator() * &secrets[0]);
+        assert!(vss.validate_all(&shares).is_ok());
+
+        let indices = [7, 0, 3, 5, 1];
+        let subset: Vec<_> = indices
+            .iter()
+            .map(|&i| shares[usize::from(i)].clone())
+            .collect();
+        assert_eq!(
+            vss.reconstruct_packed(&indices, &subset, 3),
+            Ok(secrets.clone())
+        );
+        assert_eq!(vss.reconstruct(&indices, &subset), Ok(secrets[0].clone()));
+
+        // Using all the shares gives the same result
+        let all: Vec<u16> = (0..8).collect();
+        assert_eq!(vss.reconstruct_packed(&all, &shares, 3), Ok(secrets));
+
+        assert_eq!(
+            vss.reconstruct_packed(&indices[..4], &subset[..4], 3).err(),
+            Some(ErrorSS::BelowThreshold)
+        );
+    }
+
+    test_for_all_curves!([#[should_panic(expected = "t + k - 1 overflows u16")]] test_share_packed_rejects_threshold_overflow);
+
+    fn test_share_packed_rejects_threshold_overflow<E: Curve>() {
+        let secrets: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        // t + k - 1 would wrap around to 0, which is less than n
+        VerifiableSS::<E, sha2::Sha256>::share_packed(u16::MAX - 1, 5, &secrets);
+    }
+}
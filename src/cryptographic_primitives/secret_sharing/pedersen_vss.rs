@@ -0,0 +1,231 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::{
+    SecretShares, ShamirSecretSharing,
+};
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::ErrorSS::{self, VerifyShareError};
+
+/// Pedersen VSS, based on Torben Pryds Pedersen. 1991. Non-Interactive and Information-Theoretic
+/// Secure Verifiable Secret Sharing. In Advances in Cryptology — CRYPTO ’91, LNCS 576, 129–140
+///
+/// The dealer samples two polynomials: $f(x)$ with $f(0) = \text{secret}$, and a blinding polynomial
+/// $r(x)$. Commitments are of form $C_i = a_i G + b_i H$, where $a_i$, $b_i$ are coefficients of
+/// $f$ and $r$ respectively, and $H$ is [base_point2](Point::base_point2). Unlike [Feldman VSS],
+/// commitments reveal no information about the secret, even to an unbounded adversary.
+///
+/// Each party is given an index from 1,..,n, a secret share $f(i)$ and a blinding share $r(i)$.
+///
+/// [Feldman VSS]: super::feldman_vss::VerifiableSS
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct PedersenVSS<E: Curve> {
+    pub parameters: ShamirSecretSharing,
+    pub commitments: Vec<Point<E>>,
+}
+
+impl<E: Curve> PedersenVSS<E> {
+    pub fn reconstruct_limit(&self) -> u16 {
+        self.parameters.threshold + 1
+    }
+
+    /// Shares a secret, returns the scheme, secret shares and blinding shares
+    ///
+    /// Both secret and blinding shares need to be sent to the parties.
+    pub fn share(
+        t: u16,
+        n: u16,
+        secret: &Scalar<E>,
+    ) -> (PedersenVSS<E>, SecretShares<E>, SecretShares<E>) {
+        assert!(t < n);
+        let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
+        let blinding_polynomial = Polynomial::<E>::sample_exact(t);
+        let shares = polynomial.evaluate_many_bigint(1..=n).collect();
+        let blinding_shares = blinding_polynomial.evaluate_many_bigint(1..=n).collect();
+
+        let g = Point::<E>::generator();
+        let h = Point::<E>::base_point2();
+        let commitments = polynomial
+            .coefficients()
+            .iter()
+            .zip(blinding_polynomial.coefficients())
+            .map(|(a, b)| g * a + h * b)
+            .collect::<Vec<_>>();
+
+        (
+            PedersenVSS {
+                parameters: ShamirSecretSharing {
+                    threshold: t,
+                    share_count: n,
+                },
+                commitments,
+            },
+            SecretShares { shares, polynomial },
+            SecretShares {
+                shares: blinding_shares,
+                polynomial: blinding_polynomial,
+            },
+        )
+    }
+
+    /// Reconstructs a secret from shares of parties at (0-based) `indices`
+    ///
+    /// Returns [ErrorSS::MismatchedLengths] if `indices` and `shares` have different lengths,
+    /// and [ErrorSS::BelowThreshold] if there are fewer than `t+1` shares.
+    pub fn reconstruct(&self, indices: &[u16], shares: &[Scalar<E>]) -> Result<Scalar<E>, ErrorSS> {
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
+        // add one to indices to get points
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        Ok((0u16..)
+            .zip(shares)
+            .map(|(j, share)| share * Polynomial::lagrange_basis(&Scalar::zero(), j, &points))
+            .sum())
+    }
+
+    /// Checks that scheme is well-formed: it has exactly `t+1` commitments and `n ≥ t+1`
+    ///
+    /// Same as [VerifiableSS::validate_parameters], the check is performed by
+    /// [validate_share](Self::validate_share). Returns [ErrorSS::InvalidParameters] if check fails.
+    ///
+    /// [VerifiableSS::validate_parameters]: super::feldman_vss::VerifiableSS::validate_parameters
+    pub fn validate_parameters(&self) -> Result<(), ErrorSS> {
+        let limit = usize::from(self.parameters.threshold) + 1;
+        if self.commitments.len() != limit || usize::from(self.parameters.share_count) < limit {
+            Err(ErrorSS::InvalidParameters)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that `secret_share` and `blinding_share` open commitment to the share of party `index`
+    ///
+    /// Returns [ErrorSS::InvalidParameters] if the scheme is malformed (see
+    /// [validate_parameters](Self::validate_parameters)), and [ErrorSS::VerifyShareError] if
+    /// shares don't open the commitment.
+    pub fn validate_share(
+        &self,
+        secret_share: &Scalar<E>,
+        blinding_share: &Scalar<E>,
+        index: u16,
+    ) -> Result<(), ErrorSS> {
+        self.validate_parameters()?;
+        let g = Point::<E>::generator();
+        let h = Point::<E>::base_point2();
+        let share_commitment = g * secret_share + h * blinding_share;
+        if share_commitment == self.get_point_commitment(index) {
+            Ok(())
+        } else {
+            Err(VerifyShareError)
+        }
+    }
+
+    /// Evaluates $\sum_i C_i \cdot \text{index}^i$, ie. commitment to the share of party `index`
+    pub fn get_point_commitment(&self, index: u16) -> Point<E> {
+        let index_fe = Scalar::from(index);
+        let powers: Vec<Scalar<E>> = iter::successors(Some(Scalar::one()), |x| Some(x * &index_fe))
+            .take(self.commitments.len())
+            .collect();
+        Point::multi_scalar_mul(&self.commitments, &powers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_pedersen_secret_sharing_3_out_of_5);
+
+    fn test_pedersen_secret_sharing_3_out_of_5<E: Curve>() {
+        let secret = Scalar::random();
+
+        let (vss_scheme, secret_shares, blinding_shares) = PedersenVSS::<E>::share(3, 5, &secret);
+
+        let shares_vec = vec![
+            secret_shares[0].clone(),
+            secret_shares[1].clone(),
+            secret_shares[2].clone(),
+            secret_shares[4].clone(),
+        ];
+
+        //test reconstruction
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 2, 4], &shares_vec);
+        assert_eq!(Ok(secret), secret_reconstructed);
+        assert_eq!(
+            vss_scheme.reconstruct(&[0, 1, 2], &shares_vec[..3]),
+            Err(ErrorSS::BelowThreshold)
+        );
+        assert_eq!(
+            vss_scheme.reconstruct(&[0, 1, 2], &shares_vec),
+            Err(ErrorSS::MismatchedLengths)
+        );
+
+        // test secret shares are verifiable
+        for i in 0..5 {
+            let valid =
+                vss_scheme.validate_share(&secret_shares[i], &blinding_shares[i], i as u16 + 1);
+            assert!(valid.is_ok());
+        }
+
+        // test share doesn't verify against wrong index or wrong blinding
+        assert!(vss_scheme
+            .validate_share(&secret_shares[0], &blinding_shares[0], 2)
+            .is_err());
+        assert!(vss_scheme
+            .validate_share(&secret_shares[0], &blinding_shares[1], 1)
+            .is_err());
+    }
+
+    test_for_all_curves!(test_pedersen_validate_parameters);
+
+    fn test_pedersen_validate_parameters<E: Curve>() {
+        let (vss_scheme, secret_shares, blinding_shares) =
+            PedersenVSS::<E>::share(2, 5, &Scalar::random());
+        assert_eq!(vss_scheme.validate_parameters(), Ok(()));
+
+        // Missing or extra commitments
+        let mut truncated = vss_scheme.clone();
+        truncated.commitments.pop();
+        let mut extended = vss_scheme.clone();
+        extended.commitments.push(Point::generator().to_point());
+        // Fewer parties than reconstruction limit
+        let mut too_few_parties = vss_scheme.clone();
+        too_few_parties.parameters.share_count = 2;
+
+        for malformed in [truncated, extended, too_few_parties] {
+            assert_eq!(
+                malformed.validate_parameters(),
+                Err(ErrorSS::InvalidParameters)
+            );
+            assert_eq!(
+                malformed.validate_share(&secret_shares[0], &blinding_shares[0], 1),
+                Err(ErrorSS::InvalidParameters)
+            );
+        }
+    }
+
+    test_for_all_curves!(test_pedersen_commitments_hide_secret);
+
+    fn test_pedersen_commitments_hide_secret<E: Curve>() {
+        let secret = Scalar::random();
+
+        let (vss_scheme, _secret_shares, _blinding_shares) = PedersenVSS::<E>::share(1, 3, &secret);
+
+        let g = Point::generator();
+        assert_ne!(vss_scheme.commitments[0], g * &secret);
+    }
+}
@@ -2,9 +2,14 @@ use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::{iter, ops};
 
+use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
+use crate::arithmetic::traits::*;
+use crate::elliptic::curves::field::PrimeField;
 use crate::elliptic::curves::{Curve, Scalar};
+use crate::BigInt;
 
 /// Degree of a [polynomial](Polynomial).
 ///
@@ -126,6 +131,21 @@ impl<E: Curve> Polynomial<E> {
         }
     }
 
+    /// Samples random polynomial of degree $n$ with fixed constant term using given source of
+    /// randomness
+    ///
+    /// Same as [sample_exact_with_fixed_const_term](Self::sample_exact_with_fixed_const_term),
+    /// but seeded `rng` yields the same polynomial every time.
+    pub fn sample_exact_with_fixed_const_term_and_rng<R: RngCore + CryptoRng>(
+        n: u16,
+        const_term: Scalar<E>,
+        rng: &mut R,
+    ) -> Self {
+        let random_coefficients =
+            iter::repeat_with(|| Scalar::random_with_rng(rng)).take(usize::from(n));
+        Self::from_coefficients(iter::once(const_term).chain(random_coefficients).collect())
+    }
+
     /// Returns degree $d$ of polynomial $f(x)$: $d = \deg f$
     ///
     /// ```rust
@@ -330,6 +350,271 @@ impl<E: Curve> Polynomial<E> {
             .expect("elements in xs are not pairwise distinct");
         num * denum
     }
+
+    /// Recovers polynomial of degree at most $t$ from $t+1$ points $(x_i, y_i)$ using Lagrange
+    /// interpolation
+    ///
+    /// Unlike [lagrange_basis](Self::lagrange_basis), which evaluates interpolated polynomial at
+    /// a single point, this function recovers all its coefficients: $L_{X,Y}(x) = \sum_j Y_j
+    /// \cdot l_{X,j}(x)$ is expanded as polynomial in $x$. Takes $O(t^2)$ multiplications and
+    /// a single inversion.
+    ///
+    /// ## Panics
+    /// This function will panic if elements in `xs` are not pairwise distinct, or if `xs` and `ys`
+    /// have different lengths
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use curv::cryptographic_primitives::secret_sharing::Polynomial;
+    /// # use curv::elliptic::curves::*;
+    /// let f = Polynomial::<Secp256k1>::sample_exact(2);
+    /// let xs = &[Scalar::from(1), Scalar::from(2), Scalar::from(3)];
+    /// let ys = &[f.evaluate(&xs[0]), f.evaluate(&xs[1]), f.evaluate(&xs[2])];
+    ///
+    /// let g = Polynomial::interpolate(xs, ys);
+    /// assert_eq!(f.coefficients(), g.coefficients());
+    /// ```
+    pub fn interpolate(xs: &[Scalar<E>], ys: &[Scalar<E>]) -> Self {
+        assert_eq!(xs.len(), ys.len());
+        let n = xs.len();
+
+        // N(x) = (x - x_0) * ... * (x - x_{n-1})
+        let mut master = vec![Scalar::<E>::one()];
+        for x_m in xs {
+            let mut next = vec![Scalar::<E>::zero(); master.len() + 1];
+            for (i, c) in master.iter().enumerate() {
+                next[i + 1] = &next[i + 1] + c;
+                next[i] = &next[i] - c * x_m;
+            }
+            master = next;
+        }
+
+        // Denominators of lagrange basis polynomials: l_j(x) = N(x) / (x - x_j) / denum_j
+        let mut denums: Vec<Scalar<E>> = (0..n)
+            .map(|j| {
+                (0..n)
+                    .filter(|m| *m != j)
+                    .map(|m| &xs[j] - &xs[m])
+                    .product()
+            })
+            .collect();
+        let zeros = Scalar::batch_invert(&mut denums);
+        assert!(
+            !zeros.contains(&true),
+            "elements in xs are not pairwise distinct"
+        );
+
+        let mut coefficients = vec![Scalar::<E>::zero(); n];
+        for ((x_j, y_j), denum_j) in xs.iter().zip(ys).zip(&denums) {
+            let scale = y_j * denum_j;
+            // Divide N(x) by (x - x_j) using synthetic division
+            let mut carry = Scalar::<E>::zero();
+            for k in (1..=n).rev() {
+                carry = &master[k] + &carry * x_j;
+                coefficients[k - 1] = &coefficients[k - 1] + &carry * &scale;
+            }
+        }
+        Self::from_coefficients(coefficients)
+    }
+
+    /// Multiplies two polynomials using number-theoretic transform (NTT)
+    ///
+    /// Takes $O(n \log n)$ multiplications instead of $O(n^2)$ required by [`&f * &g`](ops::Mul),
+    /// where $n$ is the degree of resulting polynomial. Multiplication modulo $x^m - 1$ requires
+    /// a root of unity of order $m$ (a power of two that's at least $n+1$), which exists only if
+    /// $m$ divides $q - 1$. Largest supported $m$ depends on the curve:
+    ///
+    /// | Curve                  | max $m$    |
+    /// |------------------------|------------|
+    /// | bls12_381              | $2^{32}$   |
+    /// | secp256k1              | $2^6$      |
+    /// | secp256r1              | $2^4$      |
+    /// | ed25519, ristretto     | $2^2$      |
+    ///
+    /// So in practice NTT is only useful for BLS12-381. Returns [NttNotSupported] error if
+    /// the field doesn't have a large enough root of unity.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use curv::cryptographic_primitives::secret_sharing::Polynomial;
+    /// use curv::elliptic::curves::{Bls12_381_1, Ed25519};
+    ///
+    /// let f = Polynomial::<Bls12_381_1>::sample_exact(100);
+    /// let g = Polynomial::<Bls12_381_1>::sample_exact(200);
+    /// assert_eq!(f.mul_ntt(&g).unwrap().coefficients(), (&f * &g).coefficients());
+    ///
+    /// let f = Polynomial::<Ed25519>::sample_exact(100);
+    /// assert!(f.mul_ntt(&f).is_err());
+    /// ```
+    pub fn mul_ntt(&self, g: &Self) -> Result<Self, NttNotSupported> {
+        if self.coefficients.is_empty() || g.coefficients.is_empty() {
+            return Ok(Polynomial::from_coefficients(vec![]));
+        }
+        let len = self.coefficients.len() + g.coefficients.len() - 1;
+        let size = len.next_power_of_two();
+        let omega = root_of_unity::<E>(size)?;
+
+        let mut f_values = self.coefficients.clone();
+        f_values.resize(size, Scalar::zero());
+        ntt(&mut f_values, &omega);
+        let mut g_values = g.coefficients.clone();
+        g_values.resize(size, Scalar::zero());
+        ntt(&mut g_values, &omega);
+
+        let mut h_values: Vec<_> = f_values.iter().zip(&g_values).map(|(a, b)| a * b).collect();
+        let omega_inv = omega.invert().expect("root of unity is not zero");
+        ntt(&mut h_values, &omega_inv);
+
+        let size_inv = Scalar::<E>::from(size as u64)
+            .invert()
+            .expect("size is less than group order");
+        h_values.truncate(len);
+        Ok(Polynomial::from_coefficients(
+            h_values.into_iter().map(|h| h * &size_inv).collect(),
+        ))
+    }
+
+    /// Computes remainder of dividing polynomial by monic polynomial `m`
+    ///
+    /// Long polynomials are divided via multiplying by inverse of reversed `m`, see
+    /// [inverse_mod_xk](Self::inverse_mod_xk).
+    pub(crate) fn rem_monic(&self, m: &Self) -> Self {
+        let a = &self.coefficients;
+        let deg_m = m.coefficients.len() - 1;
+        if a.len() <= deg_m {
+            return self.clone();
+        }
+        let quotient_len = a.len() - deg_m;
+
+        if deg_m <= SCHOOLBOOK_THRESHOLD || quotient_len <= SCHOOLBOOK_THRESHOLD {
+            let mut r = a.clone();
+            for i in (deg_m..r.len()).rev() {
+                let lead = r[i].clone();
+                for (j, m_j) in m.coefficients.iter().enumerate() {
+                    let k = i - deg_m + j;
+                    r[k] = &r[k] - &lead * m_j;
+                }
+            }
+            r.truncate(deg_m);
+            return Polynomial::from_coefficients(r);
+        }
+
+        // rev(q) = rev(a) / rev(m) mod x^quotient_len
+        let rev = |p: &Self| {
+            let coefs = p.coefficients.iter().rev().take(quotient_len).cloned();
+            Polynomial::from_coefficients(coefs.collect())
+        };
+        let mut rev_q = &rev(self) * &rev(m).inverse_mod_xk(quotient_len);
+        rev_q.coefficients.truncate(quotient_len);
+        let q = Polynomial::from_coefficients(rev_q.coefficients.into_iter().rev().collect());
+
+        let qm = &q * m;
+        Polynomial::from_coefficients(
+            a[..deg_m]
+                .iter()
+                .zip(qm.coefficients)
+                .map(|(a_i, qm_i)| a_i - qm_i)
+                .collect(),
+        )
+    }
+
+    /// Computes inverse of power series modulo `x^k` using Newton iteration
+    ///
+    /// Constant term must be equal to one.
+    fn inverse_mod_xk(&self, k: usize) -> Self {
+        let h = &self.coefficients;
+        let mut inverse = Polynomial::from_coefficients(vec![Scalar::one()]);
+        let mut precision = 1;
+        while precision < k {
+            precision = (2 * precision).min(k);
+            // g = g * (2 - h * g) mod x^precision
+            let h_low = Polynomial::from_coefficients(h[..precision.min(h.len())].to_vec());
+            let mut correction = (&h_low * &inverse).coefficients;
+            correction.resize(precision, Scalar::zero());
+            for c in correction.iter_mut() {
+                *c = -&*c;
+            }
+            correction[0] = &correction[0] + Scalar::from(2);
+            inverse = &inverse * &Polynomial::from_coefficients(correction);
+            inverse.coefficients.truncate(precision);
+        }
+        inverse
+    }
+}
+
+/// Polynomials shorter than that are multiplied and divided using schoolbook algorithms
+const SCHOOLBOOK_THRESHOLD: usize = 16;
+
+/// Error returned by [Polynomial::mul_ntt]: scalar field doesn't have required root of unity
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("NTT of size {required} is not supported: q-1 is divisible only by 2^{two_adicity}")]
+pub struct NttNotSupported {
+    /// Size of transform (a power of two)
+    pub required: usize,
+    /// Largest $s$ such that $2^s$ divides $q - 1$
+    pub two_adicity: usize,
+}
+
+/// Finds a primitive root of unity of order `size` (a power of two)
+fn root_of_unity<E: Curve>(size: usize) -> Result<Scalar<E>, NttNotSupported> {
+    let q_minus_one = Scalar::<E>::group_order() - 1;
+    let two_adicity = (0..)
+        .find(|&i| q_minus_one.test_bit(i))
+        .expect("q-1 is not zero");
+    let log_size = size.trailing_zeros() as usize;
+    if log_size > two_adicity {
+        return Err(NttNotSupported {
+            required: size,
+            two_adicity,
+        });
+    }
+
+    // c^((q-1)/2) = -1 for quadratic non-residue c, so c^((q-1)/size) has order exactly size
+    let minus_one = -Scalar::<E>::from(1);
+    let half = &q_minus_one >> 1;
+    let non_residue = (2u64..)
+        .map(Scalar::<E>::from)
+        .find(|c| PrimeField::pow_bigint(c, &half) == minus_one)
+        .expect("half of field elements are non-residues");
+    Ok(PrimeField::pow_bigint(
+        &non_residue,
+        &(q_minus_one >> log_size),
+    ))
+}
+
+/// Computes in place evaluations of polynomial at powers of `omega` (iterative Cooley-Tukey)
+///
+/// `values.len()` must be a power of two equal to the order of `omega`.
+fn ntt<E: Curve>(values: &mut [Scalar<E>], omega: &Scalar<E>) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let omega_len = PrimeField::pow_bigint(omega, &BigInt::from((n / len) as u64));
+        for chunk in values.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut w = Scalar::<E>::from(1);
+            for (u, v) in lo.iter_mut().zip(hi) {
+                let t = &*v * &w;
+                *v = &*u - &t;
+                *u = &*u + t;
+                w = w * &omega_len;
+            }
+        }
+        len *= 2;
+    }
 }
 
 /// Multiplies polynomial `f(x)` at scalar `s`, returning resulting polynomial `g(x) = s * f(x)`
@@ -425,6 +710,141 @@ impl<E: Curve> ops::Sub for &Polynomial<E> {
             self.coefficients()[len2..].to_vec()
         };
 
-        Polynomial::from_coefficients(overlapped.chain(tail.into_iter()).collect())
+        Polynomial::from_coefficients(overlapped.chain(tail).collect())
+    }
+}
+
+/// Multiplies two polynomials `f(x)` and `g(x)` returning resulting polynomial `h(x) = f(x) * g(x)`
+///
+/// Uses Karatsuba algorithm for long polynomials.
+///
+/// ## Example
+///
+/// ```rust
+/// # use curv::cryptographic_primitives::secret_sharing::Polynomial;
+/// use curv::elliptic::curves::{Secp256k1, Scalar};
+///
+/// let f = Polynomial::<Secp256k1>::sample_exact(2);
+/// let g = Polynomial::<Secp256k1>::sample_exact(3);
+/// let h = &f * &g;
+///
+/// assert_eq!(h.degree(), 5.into());
+/// let x = Scalar::<Secp256k1>::from(10);
+/// assert_eq!(h.evaluate(&x), f.evaluate(&x) * g.evaluate(&x));
+/// ```
+impl<E: Curve> ops::Mul for &Polynomial<E> {
+    type Output = Polynomial<E>;
+    fn mul(self, g: Self) -> Self::Output {
+        if self.coefficients.is_empty() || g.coefficients.is_empty() {
+            return Polynomial::from_coefficients(vec![]);
+        }
+        let product_len = self.coefficients.len() + g.coefficients.len() - 1;
+        if self.coefficients.len().min(g.coefficients.len()) <= SCHOOLBOOK_THRESHOLD {
+            let mut coefficients = vec![Scalar::zero(); product_len];
+            for (i, f_coef) in self.coefficients.iter().enumerate() {
+                for (j, g_coef) in g.coefficients.iter().enumerate() {
+                    coefficients[i + j] = &coefficients[i + j] + f_coef * g_coef;
+                }
+            }
+            return Polynomial::from_coefficients(coefficients);
+        }
+
+        // f = f0 + x^h f1, g = g0 + x^h g1
+        let h = self.coefficients.len().max(g.coefficients.len()) / 2;
+        let split = |p: &Polynomial<E>| {
+            let (low, high) = p.coefficients.split_at(h.min(p.coefficients.len()));
+            (
+                Polynomial::from_coefficients(low.to_vec()),
+                Polynomial::from_coefficients(high.to_vec()),
+            )
+        };
+        let (f0, f1) = split(self);
+        let (g0, g1) = split(g);
+        let z0 = &f0 * &g0;
+        let z2 = &f1 * &g1;
+        let z1 = &(&(&(&f0 + &f1) * &(&g0 + &g1)) - &z0) - &z2;
+
+        // Intermediate results may have zero high coefficients exceeding the product length
+        let mut coefficients = vec![Scalar::zero(); product_len.max(z1.coefficients.len() + h)];
+        for (offset, z) in [(0, z0), (h, z1), (2 * h, z2)] {
+            for (i, c) in z.coefficients.into_iter().enumerate() {
+                coefficients[offset + i] = &coefficients[offset + i] + c;
+            }
+        }
+        coefficients.truncate(product_len);
+        Polynomial::from_coefficients(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic::curves::{Bls12_381_1, Ed25519};
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(mul_ntt_of_small_polynomials);
+    fn mul_ntt_of_small_polynomials<E: Curve>() {
+        // Every supported curve has roots of unity of order 4
+        for (deg_f, deg_g) in [(0, 0), (0, 3), (1, 1), (1, 2)] {
+            let f = Polynomial::<E>::sample_exact(deg_f);
+            let g = Polynomial::<E>::sample_exact(deg_g);
+            assert_eq!(
+                f.mul_ntt(&g).unwrap().coefficients(),
+                (&f * &g).coefficients()
+            );
+        }
+        let zero = Polynomial::<E>::from_coefficients(vec![]);
+        let f = Polynomial::<E>::sample_exact(2);
+        assert!(f.mul_ntt(&zero).unwrap().coefficients().is_empty());
+    }
+
+    #[test]
+    fn mul_ntt_matches_naive_multiplication() {
+        for (deg_f, deg_g) in [(2, 5), (31, 32), (63, 64), (100, 37), (255, 256)] {
+            let f = Polynomial::<Bls12_381_1>::sample_exact(deg_f);
+            let g = Polynomial::<Bls12_381_1>::sample_exact(deg_g);
+            assert_eq!(
+                f.mul_ntt(&g).unwrap().coefficients(),
+                (&f * &g).coefficients(),
+                "deg f = {}, deg g = {}",
+                deg_f,
+                deg_g
+            );
+        }
+    }
+
+    test_for_all_curves!(rem_monic_of_long_polynomials);
+    fn rem_monic_of_long_polynomials<E: Curve>() {
+        // Covers both schoolbook and Newton division
+        for (deg_q, deg_m) in [(3, 5), (40, 10), (40, 30), (100, 64)] {
+            let q = Polynomial::<E>::sample_exact(deg_q);
+            let mut m = Polynomial::<E>::sample_exact(deg_m);
+            m.coefficients[usize::from(deg_m)] = Scalar::one();
+            let r = Polynomial::<E>::sample_exact(deg_m - 1);
+            let f = &(&q * &m) + &r;
+            assert_eq!(
+                f.rem_monic(&m).coefficients(),
+                r.coefficients(),
+                "deg q = {}, deg m = {}",
+                deg_q,
+                deg_m
+            );
+        }
+        let f = Polynomial::<E>::sample_exact(3);
+        let m = Polynomial::<E>::from_coefficients(vec![Scalar::random(); 5]);
+        assert_eq!(f.rem_monic(&m).coefficients(), f.coefficients());
+    }
+
+    #[test]
+    fn mul_ntt_is_not_supported_without_roots_of_unity() {
+        let f = Polynomial::<Ed25519>::sample_exact(2);
+        let g = Polynomial::<Ed25519>::sample_exact(3);
+        assert_eq!(
+            f.mul_ntt(&g).unwrap_err(),
+            NttNotSupported {
+                required: 8,
+                two_adicity: 2
+            }
+        );
     }
 }
@@ -0,0 +1,103 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Scalar};
+use crate::ErrorSS::{self, VerifyShareError};
+
+impl<E: Curve, H: Digest + Clone> VerifiableSS<E, H> {
+    /// Reconstructs a secret from `k > t+1` shares tolerating up to `(k - t - 1) / 2` corrupted ones
+    ///
+    /// Unlike [reconstruct_checked](Self::reconstruct_checked), doesn't rely on commitments, so it
+    /// can be used when shares are known to be dealt honestly but may be corrupted afterwards.
+    /// Interpolates polynomials through `(t+1)`-subsets of shares until finds one that is agreed
+    /// upon by a qualified majority: more than `(k + t) / 2` shares. Such polynomial is unique,
+    /// and it's the one used by the dealer if at most `(k - t - 1) / 2` shares are corrupted.
+    ///
+    /// Returns [ErrorSS::VerifyShareError] if no polynomial is agreed upon by a qualified
+    /// majority, ie. there are too many corrupted shares. Number of tried subsets grows
+    /// exponentially with number of corrupted shares, so it's only suitable for small `k`.
+    pub fn reconstruct_robust(
+        &self,
+        indices: &[u16],
+        shares: &[Scalar<E>],
+    ) -> Result<Scalar<E>, ErrorSS> {
+        self.parameters
+            .ensure_can_reconstruct(indices.len(), shares.len())?;
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        let k = shares.len();
+        let t = usize::from(self.parameters.threshold);
+
+        // Iterate over (t+1)-subsets in lexicographic order
+        let mut subset: Vec<usize> = (0..=t).collect();
+        loop {
+            let xs: Vec<_> = subset.iter().map(|&i| points[i].clone()).collect();
+            let ys: Vec<_> = subset.iter().map(|&i| shares[i].clone()).collect();
+            let polynomial = Polynomial::interpolate(&xs, &ys);
+            let agreed = points
+                .iter()
+                .zip(shares)
+                .filter(|(x, y)| &polynomial.evaluate(x) == *y)
+                .count();
+            if 2 * agreed > k + t {
+                return Ok(polynomial.evaluate(&Scalar::zero()));
+            }
+
+            match (0..=t).rev().find(|&i| subset[i] != i + k - t - 1) {
+                Some(i) => {
+                    subset[i] += 1;
+                    for j in i + 1..=t {
+                        subset[j] = subset[j - 1] + 1;
+                    }
+                }
+                None => return Err(VerifyShareError),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_reconstruct_robust);
+
+    fn test_reconstruct_robust<E: Curve>() {
+        let secret = Scalar::random();
+        let (vss_scheme, secret_shares) = VerifiableSS::<E, sha2::Sha256>::share(2, 5, &secret);
+        let indices: Vec<u16> = (0..5).collect();
+        let mut shares = secret_shares.to_vec();
+        assert_eq!(
+            vss_scheme.reconstruct_robust(&indices, &shares),
+            Ok(secret.clone())
+        );
+
+        // n = 2t+1 shares tolerate a single corrupted share, wherever it is
+        for corrupted in 0..5 {
+            let mut shares = shares.clone();
+            shares[corrupted] = Scalar::random();
+            assert_eq!(
+                vss_scheme.reconstruct_robust(&indices, &shares),
+                Ok(secret.clone())
+            );
+        }
+
+        // ...but not two of them
+        shares[0] = Scalar::random();
+        shares[3] = Scalar::random();
+        assert_eq!(
+            vss_scheme.reconstruct_robust(&indices, &shares),
+            Err(VerifyShareError)
+        );
+    }
+}
@@ -0,0 +1,448 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! ECDSA signatures over secp256k1
+//!
+//! Signatures are deterministic: the nonce is derived from the secret key and the message hash
+//! as described in [RFC 6979] (HMAC-SHA256). Produced signatures are always normalized to low-s
+//! form (`s ≤ q/2`), i.e. they are identical to ones produced by libsecp256k1.
+//!
+//! ```rust
+//! # use curv::arithmetic::*;
+//! # use curv::cryptographic_primitives::signatures::ecdsa;
+//! # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! # use sha2::{Digest, Sha256};
+//! let sk = Scalar::<Secp256k1>::random();
+//! let pk = Point::generator() * &sk;
+//!
+//! let msg_hash = BigInt::from_bytes(&Sha256::digest(b"hello world"));
+//! let signature = ecdsa::sign(&msg_hash, &sk);
+//! assert!(ecdsa::verify(&signature, &msg_hash, &pk).is_ok());
+//! ```
+//!
+//! [RFC 6979]: https://www.rfc-editor.org/rfc/rfc6979
+
+use hmac::{Hmac, Mac, NewMac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::arithmetic::traits::*;
+use crate::elliptic::curves::{Point, Scalar, Secp256k1};
+use crate::BigInt;
+
+pub use super::SigError;
+
+/// ECDSA signature `(r, s)`
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Signature {
+    pub r: Scalar<Secp256k1>,
+    pub s: Scalar<Secp256k1>,
+}
+
+impl Signature {
+    /// Serializes signature in compact form: 32 bytes of `r` followed by 32 bytes of `s`,
+    /// both big-endian
+    pub fn to_compact(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r.to_bytes());
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Parses signature in compact form (see [to_compact](Self::to_compact))
+    ///
+    /// Returns error if length isn't 64 bytes, or if `r` or `s` are not in range `[1; q)`
+    pub fn from_compact(bytes: &[u8]) -> Result<Self, SigError> {
+        if bytes.len() != 64 {
+            return Err(SigError::MalformedSignature);
+        }
+        Self::from_components(&bytes[..32], &bytes[32..])
+    }
+
+    /// Serializes signature in DER form: `SEQUENCE { r INTEGER, s INTEGER }`
+    pub fn to_der(&self) -> Vec<u8> {
+        let r = der_integer(&self.r.to_bytes());
+        let s = der_integer(&self.s.to_bytes());
+        let mut der = Vec::with_capacity(2 + r.len() + s.len());
+        der.push(0x30);
+        der.push((r.len() + s.len()) as u8);
+        der.extend_from_slice(&r);
+        der.extend_from_slice(&s);
+        der
+    }
+
+    /// Parses signature in DER form (see [to_der](Self::to_der))
+    ///
+    /// Only strict (minimal) DER encoding is accepted. Returns error if `r` or `s` are not in
+    /// range `[1; q)`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, SigError> {
+        let content = match bytes {
+            [0x30, len, content @ ..] if usize::from(*len) == content.len() => content,
+            _ => return Err(SigError::MalformedSignature),
+        };
+        let (r, rest) = parse_der_integer(content)?;
+        let (s, rest) = parse_der_integer(rest)?;
+        if !rest.is_empty() {
+            return Err(SigError::MalformedSignature);
+        }
+        Self::from_components(r, s)
+    }
+
+    fn from_components(r: &[u8], s: &[u8]) -> Result<Self, SigError> {
+        let r = Scalar::from_bytes(&left_pad_32(r)?).map_err(|_| SigError::MalformedSignature)?;
+        let s = Scalar::from_bytes(&left_pad_32(s)?).map_err(|_| SigError::MalformedSignature)?;
+        if r.is_zero() || s.is_zero() {
+            return Err(SigError::MalformedSignature);
+        }
+        Ok(Signature { r, s })
+    }
+
+    /// Checks whether `s ≤ q/2`
+    pub fn is_low_s(&self) -> bool {
+        self.s.to_bigint() <= half_group_order()
+    }
+}
+
+/// Signs a message hash
+///
+/// `msg_hash` is a 256-bit digest of the message interpreted as big-endian integer. Nonce is
+/// derived deterministically following RFC 6979, and resulting signature is normalized to
+/// low-s form.
+///
+/// ## Panics
+/// Panics if `sk` is zero, or if `msg_hash` is negative or longer than 256 bits.
+pub fn sign(msg_hash: &BigInt, sk: &Scalar<Secp256k1>) -> Signature {
+    assert!(!sk.is_zero(), "secret key must be nonzero");
+    let z = hash_to_scalar(msg_hash);
+
+    let mut nonces = Rfc6979Nonces::new(sk, &z);
+    loop {
+        let k = nonces.next_nonce();
+        let r_point = Point::generator() * &k;
+        let r = match r_point.x_coord() {
+            Some(x) => Scalar::<Secp256k1>::from_bigint(&x),
+            None => continue,
+        };
+        if r.is_zero() {
+            continue;
+        }
+        let k_inv = k.invert().expect("nonce is guaranteed to be nonzero");
+        let s = k_inv * (&z + &r * sk);
+        if s.is_zero() {
+            continue;
+        }
+        let s = if s.to_bigint() > half_group_order() {
+            -s
+        } else {
+            s
+        };
+        return Signature { r, s };
+    }
+}
+
+/// Verifies a signature of message hash
+///
+/// Both low-s and high-s signatures are accepted.
+///
+/// ## Panics
+/// Panics if `msg_hash` is negative or longer than 256 bits.
+pub fn verify(sig: &Signature, msg_hash: &BigInt, pk: &Point<Secp256k1>) -> Result<(), SigError> {
+    if sig.r.is_zero() || sig.s.is_zero() || pk.is_zero() {
+        return Err(SigError::InvalidSignature);
+    }
+    let z = hash_to_scalar(msg_hash);
+    let s_inv = sig.s.invert().ok_or(SigError::InvalidSignature)?;
+    let u1 = &z * &s_inv;
+    let u2 = &sig.r * &s_inv;
+    let r_point = Point::generator() * u1 + pk * u2;
+    match r_point.x_coord() {
+        Some(x) if Scalar::<Secp256k1>::from_bigint(&x) == sig.r => Ok(()),
+        _ => Err(SigError::InvalidSignature),
+    }
+}
+
+fn hash_to_scalar(msg_hash: &BigInt) -> Scalar<Secp256k1> {
+    assert!(
+        *msg_hash >= BigInt::zero() && msg_hash.bit_length() <= 256,
+        "msg_hash must be a 256-bit integer"
+    );
+    Scalar::from_bigint(msg_hash)
+}
+
+fn half_group_order() -> BigInt {
+    Scalar::<Secp256k1>::group_order() >> 1
+}
+
+/// Deterministic nonce generator (RFC 6979, Section 3.2) using HMAC-SHA256
+struct Rfc6979Nonces {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl Rfc6979Nonces {
+    fn new(sk: &Scalar<Secp256k1>, z: &Scalar<Secp256k1>) -> Self {
+        let x = sk.to_bytes();
+        let h1 = z.to_bytes();
+        let mut nonces = Rfc6979Nonces {
+            k: [0x00; 32],
+            v: [0x01; 32],
+        };
+        nonces.k = nonces.hmac(&[&nonces.v, &[0x00], &x, &h1]);
+        nonces.v = nonces.hmac(&[&nonces.v]);
+        nonces.k = nonces.hmac(&[&nonces.v, &[0x01], &x, &h1]);
+        nonces.v = nonces.hmac(&[&nonces.v]);
+        nonces
+    }
+
+    /// Returns next candidate `k ∈ [1; q)`
+    ///
+    /// If caller rejects a nonce (because resulting `r` or `s` is zero), it should call this
+    /// method again, as specified by RFC 6979
+    fn next_nonce(&mut self) -> Scalar<Secp256k1> {
+        loop {
+            // qlen = hlen = 256, so a single HMAC output is enough
+            self.v = self.hmac(&[&self.v]);
+            let k = BigInt::from_bytes(&self.v);
+            let candidate = if k.is_zero() || &k >= Scalar::<Secp256k1>::group_order() {
+                None
+            } else {
+                Some(Scalar::from_bigint(&k))
+            };
+            self.k = self.hmac(&[&self.v, &[0x00]]);
+            self.v = self.hmac(&[&self.v]);
+            if let Some(k) = candidate {
+                return k;
+            }
+        }
+    }
+
+    fn hmac(&self, data: &[&[u8]]) -> [u8; 32] {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.k).expect("HMAC accepts any key size");
+        for d in data {
+            mac.update(d);
+        }
+        mac.finalize().into_bytes().into()
+    }
+}
+
+/// Encodes unsigned big-endian integer as DER INTEGER
+fn der_integer(bytes: &[u8]) -> Vec<u8> {
+    let first_nonzero = bytes
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(bytes.len() - 1);
+    let bytes = &bytes[first_nonzero..];
+    let pad = bytes[0] & 0x80 != 0;
+    let len = bytes.len() + usize::from(pad);
+
+    let mut der = Vec::with_capacity(2 + len);
+    der.push(0x02);
+    der.push(len as u8);
+    if pad {
+        der.push(0x00);
+    }
+    der.extend_from_slice(bytes);
+    der
+}
+
+/// Parses DER INTEGER that must be non-negative and minimally encoded. Returns big-endian bytes
+/// of the integer (without leading zero) and the rest of input.
+fn parse_der_integer(input: &[u8]) -> Result<(&[u8], &[u8]), SigError> {
+    let (len, rest) = match input {
+        [0x02, len, rest @ ..] => (usize::from(*len), rest),
+        _ => return Err(SigError::MalformedSignature),
+    };
+    if len == 0 || len > rest.len() {
+        return Err(SigError::MalformedSignature);
+    }
+    let (int, rest) = rest.split_at(len);
+    match int {
+        // negative integer
+        [first, ..] if first & 0x80 != 0 => Err(SigError::MalformedSignature),
+        // leading zero is allowed only if the next byte has the high bit set
+        [0x00, next, ..] if next & 0x80 == 0 => Err(SigError::MalformedSignature),
+        [0x00, int @ ..] if !int.is_empty() => Ok((int, rest)),
+        _ => Ok((int, rest)),
+    }
+}
+
+fn left_pad_32(bytes: &[u8]) -> Result<[u8; 32], SigError> {
+    if bytes.len() > 32 {
+        return Err(SigError::MalformedSignature);
+    }
+    let mut padded = [0u8; 32];
+    padded[32 - bytes.len()..].copy_from_slice(bytes);
+    Ok(padded)
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    fn sha256(msg: &[u8]) -> BigInt {
+        BigInt::from_bytes(&Sha256::digest(msg))
+    }
+
+    // Widely used secp256k1 RFC 6979 test vectors (e.g. bitcoinjs), message is hashed with
+    // SHA-256. Signatures are normalized to low-s form, and match libsecp256k1 output.
+    #[test]
+    fn known_answer_test_vectors() {
+        let vectors: &[(&str, &[u8], &str)] = &[
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                b"Satoshi Nakamoto",
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+                 2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ),
+            (
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+                b"Satoshi Nakamoto",
+                "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0\
+                 6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5",
+            ),
+            (
+                "f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181",
+                b"Alan Turing",
+                "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c\
+                 58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea",
+            ),
+        ];
+        for (sk, msg, expected) in vectors {
+            let sk = Scalar::<Secp256k1>::from_bigint(&BigInt::from_hex(sk).unwrap());
+            let msg_hash = sha256(msg);
+            let signature = sign(&msg_hash, &sk);
+            assert_eq!(hex::encode(signature.to_compact()), *expected);
+            assert!(verify(&signature, &msg_hash, &(Point::generator() * &sk)).is_ok());
+        }
+    }
+
+    #[test]
+    fn matches_libsecp256k1() {
+        use secp256k1::{Message, PublicKey, SecretKey, SECP256K1};
+
+        for i in 0u32..20 {
+            let sk = Scalar::<Secp256k1>::random();
+            let msg_hash = sha256(&i.to_be_bytes());
+            let signature = sign(&msg_hash, &sk);
+
+            let secp_sk = SecretKey::from_slice(&sk.to_bytes()).unwrap();
+            let secp_msg = Message::from_slice(&msg_hash.to_bytes_array::<32>().unwrap()).unwrap();
+            let secp_signature = SECP256K1.sign(&secp_msg, &secp_sk);
+            assert_eq!(signature.to_compact(), secp_signature.serialize_compact());
+            assert_eq!(signature.to_der(), secp_signature.serialize_der().to_vec());
+
+            let secp_pk = PublicKey::from_secret_key(SECP256K1, &secp_sk);
+            let secp_signature =
+                secp256k1::Signature::from_compact(&signature.to_compact()).unwrap();
+            assert!(SECP256K1
+                .verify(&secp_msg, &secp_signature, &secp_pk)
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn signature_is_low_s() {
+        for _ in 0..20 {
+            let signature = sign(&sha256(b"message"), &Scalar::random());
+            assert!(signature.is_low_s());
+        }
+    }
+
+    #[test]
+    fn verify_rejects_invalid_signatures() {
+        let sk = Scalar::<Secp256k1>::random();
+        let pk = Point::generator() * &sk;
+        let msg_hash = sha256(b"message");
+        let signature = sign(&msg_hash, &sk);
+
+        // high-s form of the same signature is still valid
+        let high_s = Signature {
+            r: signature.r.clone(),
+            s: -&signature.s,
+        };
+        assert!(!high_s.is_low_s());
+        assert_eq!(verify(&high_s, &msg_hash, &pk), Ok(()));
+
+        let another_pk = Point::generator() * Scalar::random();
+        assert_eq!(
+            verify(&signature, &msg_hash, &another_pk),
+            Err(SigError::InvalidSignature)
+        );
+        assert_eq!(
+            verify(&signature, &sha256(b"another message"), &pk),
+            Err(SigError::InvalidSignature)
+        );
+        let tampered = Signature {
+            r: signature.r.clone(),
+            s: &signature.s + Scalar::from(1),
+        };
+        assert_eq!(
+            verify(&tampered, &msg_hash, &pk),
+            Err(SigError::InvalidSignature)
+        );
+        let zero_r = Signature {
+            r: Scalar::zero(),
+            s: signature.s.clone(),
+        };
+        assert_eq!(
+            verify(&zero_r, &msg_hash, &pk),
+            Err(SigError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn encodings_roundtrip() {
+        for i in 0u32..50 {
+            let signature = sign(&sha256(&i.to_be_bytes()), &Scalar::random());
+
+            let compact = signature.to_compact();
+            assert_eq!(Signature::from_compact(&compact), Ok(signature.clone()));
+
+            let der = signature.to_der();
+            assert!(der.len() <= 72);
+            assert_eq!(Signature::from_der(&der), Ok(signature.clone()));
+        }
+
+        // r and s with high bit set are padded with zero byte, small values are stripped
+        let signature = Signature {
+            r: -Scalar::from(1),
+            s: Scalar::from(0x80),
+        };
+        let der = signature.to_der();
+        assert_eq!(&der[..5], &[0x30, 0x27, 0x02, 0x21, 0x00]);
+        assert_eq!(&der[der.len() - 4..], &[0x02, 0x02, 0x00, 0x80]);
+        assert_eq!(Signature::from_der(&der), Ok(signature));
+    }
+
+    #[test]
+    fn malformed_encodings_are_rejected() {
+        let signature = sign(&sha256(b"message"), &Scalar::random());
+
+        let compact = signature.to_compact();
+        assert!(Signature::from_compact(&compact[..63]).is_err());
+        assert!(Signature::from_compact(&[0u8; 64]).is_err());
+        let mut overflow = compact;
+        overflow[..32].copy_from_slice(&[0xff; 32]);
+        assert!(Signature::from_compact(&overflow).is_err());
+
+        let der = signature.to_der();
+        let mut trailing = der.clone();
+        trailing.push(0);
+        assert!(Signature::from_der(&trailing).is_err());
+        assert!(Signature::from_der(&der[..der.len() - 1]).is_err());
+        // non-minimal encoding of s = 1
+        let non_minimal = [0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x01];
+        assert!(Signature::from_der(&non_minimal).is_err());
+        // negative r
+        let negative = [0x30, 0x06, 0x02, 0x01, 0x81, 0x02, 0x01, 0x01];
+        assert!(Signature::from_der(&negative).is_err());
+        let minimal = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01];
+        assert!(Signature::from_der(&minimal).is_ok());
+    }
+}
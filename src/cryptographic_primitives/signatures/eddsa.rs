@@ -0,0 +1,207 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Ed25519 signatures as specified in [RFC 8032]
+//!
+//! Secret key is a 32 bytes seed. It can be [expanded](expand_secret_key) into a secret scalar,
+//! so the same key can be used both for MPC arithmetic over [Ed25519] curve and for producing
+//! standard signatures.
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::signatures::eddsa;
+//! let sk = [42u8; 32];
+//! let pk = eddsa::public_key(&sk);
+//!
+//! let signature = eddsa::sign(b"hello world", &sk);
+//! assert!(eddsa::verify(b"hello world", &signature, &pk).is_ok());
+//! ```
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+
+use sha2::{Digest, Sha512};
+
+use crate::arithmetic::traits::*;
+use crate::elliptic::curves::{Ed25519, Point, Scalar};
+use crate::BigInt;
+
+pub use super::SigError;
+
+/// Expands a secret key into a secret scalar `a` and a `prefix` used for nonce derivation
+///
+/// Public key equals to `a * G`.
+pub fn expand_secret_key(sk: &[u8; 32]) -> (Scalar<Ed25519>, [u8; 32]) {
+    let h = Sha512::digest(sk);
+    let mut a = [0u8; 32];
+    a.copy_from_slice(&h[..32]);
+    a[0] &= 248;
+    a[31] &= 127;
+    a[31] |= 64;
+    let mut prefix = [0u8; 32];
+    prefix.copy_from_slice(&h[32..]);
+    (scalar_from_le_bytes(&a), prefix)
+}
+
+/// Derives an encoded public key from a secret key
+pub fn public_key(sk: &[u8; 32]) -> [u8; 32] {
+    let (a, _prefix) = expand_secret_key(sk);
+    encode_point(&(Point::generator() * a))
+}
+
+/// Signs a message
+pub fn sign(msg: &[u8], sk: &[u8; 32]) -> [u8; 64] {
+    let (a, prefix) = expand_secret_key(sk);
+    let pk = encode_point(&(Point::generator() * &a));
+
+    let r = scalar_from_le_bytes(&Sha512::new().chain(prefix).chain(msg).finalize());
+    let big_r = encode_point(&(Point::generator() * &r));
+    let k = challenge(&big_r, &pk, msg);
+    let s = r + k * a;
+
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&big_r);
+    signature[32..].copy_from_slice(&s.to_bytes());
+    signature
+}
+
+/// Verifies a signature of the message
+///
+/// Checks cofactorless equation `[S]B = R + [k]A`. Returns error if signature or public key
+/// are malformed (e.g. `S` is not less than group order, or public key is not a point of group
+/// order), or if signature doesn't match.
+pub fn verify(msg: &[u8], signature: &[u8; 64], pk: &[u8; 32]) -> Result<(), SigError> {
+    let public_key = Point::<Ed25519>::from_bytes(pk).map_err(|_| SigError::MalformedSignature)?;
+    let s = Scalar::<Ed25519>::from_bytes(&signature[32..])
+        .map_err(|_| SigError::MalformedSignature)?;
+    let big_r = &signature[..32];
+
+    let k = challenge(big_r, pk, msg);
+    let expected_r = Point::generator() * s - public_key * k;
+    if encode_point(&expected_r) == big_r {
+        Ok(())
+    } else {
+        Err(SigError::InvalidSignature)
+    }
+}
+
+fn challenge(big_r: &[u8], pk: &[u8], msg: &[u8]) -> Scalar<Ed25519> {
+    scalar_from_le_bytes(&Sha512::new().chain(big_r).chain(pk).chain(msg).finalize())
+}
+
+fn encode_point(point: &Point<Ed25519>) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded.copy_from_slice(&point.to_bytes(true));
+    encoded
+}
+
+/// Interprets bytes as little-endian integer, and reduces it modulo group order
+fn scalar_from_le_bytes(bytes: &[u8]) -> Scalar<Ed25519> {
+    let mut be_bytes = bytes.to_vec();
+    be_bytes.reverse();
+    Scalar::from_bigint(&BigInt::from_bytes(&be_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode<const N: usize>(hex_str: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        hex::decode_to_slice(hex_str, &mut bytes).unwrap();
+        bytes
+    }
+
+    // Test vectors from RFC 8032, Section 7.1
+    #[test]
+    fn rfc8032_test_vectors() {
+        let vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+        ];
+        for (sk, pk, msg, signature) in vectors {
+            let sk = decode::<32>(sk);
+            let pk = decode::<32>(pk);
+            let msg = hex::decode(msg).unwrap();
+            let signature = decode::<64>(signature);
+
+            assert_eq!(public_key(&sk), pk);
+            assert_eq!(sign(&msg, &sk), signature);
+            assert_eq!(verify(&msg, &signature, &pk), Ok(()));
+        }
+    }
+
+    #[test]
+    fn matches_cryptoxide() {
+        for i in 0u8..20 {
+            let sk = [i; 32];
+            let msg = vec![i; usize::from(i) * 7];
+            let (keypair, pk) = cryptoxide::ed25519::keypair(&sk);
+            assert_eq!(public_key(&sk), pk);
+            assert_eq!(
+                sign(&msg, &sk).to_vec(),
+                cryptoxide::ed25519::signature(&msg, &keypair).to_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn secret_scalar_matches_public_key() {
+        let sk = [7u8; 32];
+        let (a, _prefix) = expand_secret_key(&sk);
+        let pk = Point::<Ed25519>::from_bytes(&public_key(&sk)).unwrap();
+        assert_eq!(pk, Point::generator() * a);
+    }
+
+    #[test]
+    fn verify_rejects_invalid_signatures() {
+        let sk = [1u8; 32];
+        let pk = public_key(&sk);
+        let signature = sign(b"message", &sk);
+
+        assert_eq!(
+            verify(b"another message", &signature, &pk),
+            Err(SigError::InvalidSignature)
+        );
+        assert_eq!(
+            verify(b"message", &signature, &public_key(&[2u8; 32])),
+            Err(SigError::InvalidSignature)
+        );
+
+        let mut tampered_r = signature;
+        tampered_r[0] ^= 1;
+        assert!(verify(b"message", &tampered_r, &pk).is_err());
+
+        // S + group order encodes the same scalar, but must be rejected to prevent malleability
+        let s = &signature[32..];
+        let mut s_be = s.to_vec();
+        s_be.reverse();
+        let s_plus_l = BigInt::from_bytes(&s_be) + Scalar::<Ed25519>::group_order();
+        let mut s_plus_l = s_plus_l.to_bytes_array::<32>().unwrap();
+        s_plus_l.reverse();
+        let mut malleable = signature;
+        malleable[32..].copy_from_slice(&s_plus_l);
+        assert_eq!(
+            verify(b"message", &malleable, &pk),
+            Err(SigError::MalformedSignature)
+        );
+    }
+}
@@ -0,0 +1,21 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use thiserror::Error;
+
+pub mod ecdsa;
+pub mod eddsa;
+pub mod threshold_bls;
+
+/// Error returned by signature verification and parsing
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum SigError {
+    #[error("signature is not valid")]
+    InvalidSignature,
+    #[error("malformed signature encoding")]
+    MalformedSignature,
+}
@@ -0,0 +1,205 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Threshold BLS signatures over BLS12-381
+//!
+//! Secret key is shared via [VerifiableSS] over [Bls12_381_2], so the group public key is
+//! `commitments[0]` (a G2 point), and signatures are G1 points. Every shareholder produces
+//! a [partial signature](partial_sign) `H(m)^{share}`, any `t+1` of them can be [combined](combine)
+//! into a signature that verifies under the group public key.
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+//! # use curv::cryptographic_primitives::signatures::threshold_bls;
+//! # use curv::elliptic::curves::{Bls12_381_2, Scalar};
+//! let secret = Scalar::<Bls12_381_2>::random();
+//! let (vss, shares) = VerifiableSS::<Bls12_381_2, sha2::Sha256>::share(1, 3, &secret);
+//!
+//! let indices = [0, 2];
+//! let partials: Vec<_> = indices
+//!     .iter()
+//!     .map(|&i| threshold_bls::partial_sign(b"hello", &shares[usize::from(i)]))
+//!     .collect();
+//! let signature = threshold_bls::combine(&partials, &indices, &vss).unwrap();
+//! assert!(threshold_bls::verify(b"hello", &signature, &vss.commitments[0]).is_ok());
+//! ```
+//!
+//! BLS12-381 points are hashed via a pre-RFC draft of hash-to-curve (see
+//! [ECPoint::hash_to_curve](crate::elliptic::curves::ECPoint::hash_to_curve)), so signatures
+//! don't verify in implementations of the IETF BLS signature ciphersuites.
+
+use digest::Digest;
+
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::{ensure_distinct, VerifiableSS};
+use crate::elliptic::curves::bls12_381::Pair;
+use crate::elliptic::curves::{Bls12_381_1, Bls12_381_2, Point, Scalar};
+use crate::ErrorSS;
+
+pub use super::SigError;
+
+/// Domain separation tag used to hash a message to G1
+///
+/// It's curv-specific on purpose: underlying hash to curve doesn't conform to RFC 9380, so
+/// signatures must not be confused with ones of IETF BLS ciphersuites.
+pub const DST: &[u8] = b"CURV-THRESHOLD-BLS-SIG-G1";
+
+/// Signature `H(m)^{sk}`, a point in G1
+pub type Signature = Point<Bls12_381_1>;
+
+/// Hashes a message to G1
+pub fn hash_message(msg: &[u8]) -> Point<Bls12_381_1> {
+    Point::hash_to_curve(msg, DST)
+}
+
+/// Produces a partial signature `H(m)^{share}` using a secret share
+pub fn partial_sign(msg: &[u8], secret_share: &Scalar<Bls12_381_2>) -> Signature {
+    hash_message(msg) * to_g1_scalar(secret_share)
+}
+
+/// Verifies a partial signature of the party at (0-based) `index` against its share commitment
+///
+/// Returns [SigError::InvalidSignature] if `index` is `u16::MAX`: there's no share at such index.
+pub fn verify_partial<H: Digest + Clone>(
+    msg: &[u8],
+    partial: &Signature,
+    index: u16,
+    vss: &VerifiableSS<Bls12_381_2, H>,
+) -> Result<(), SigError> {
+    let index = index.checked_add(1).ok_or(SigError::InvalidSignature)?;
+    verify(msg, partial, &vss.get_point_commitment(index))
+}
+
+/// Combines partial signatures into a signature under the group public key
+///
+/// `partials[i]` must be produced by the party at (0-based) `indices[i]`, the same indexing as
+/// in [VerifiableSS::reconstruct]. Partials are combined via Lagrange interpolation in the
+/// exponent, using [VerifiableSS::map_share_to_new_params].
+///
+/// Returns [ErrorSS::MismatchedLengths] if `partials` and `indices` have different lengths,
+/// [ErrorSS::BelowThreshold] if there are fewer than `t+1` of them, and
+/// [ErrorSS::DuplicateIndex] if an index appears twice.
+pub fn combine<H: Digest + Clone>(
+    partials: &[Signature],
+    indices: &[u16],
+    vss: &VerifiableSS<Bls12_381_2, H>,
+) -> Result<Signature, ErrorSS> {
+    if partials.len() != indices.len() {
+        return Err(ErrorSS::MismatchedLengths);
+    }
+    if partials.len() < usize::from(vss.reconstruct_limit()) {
+        return Err(ErrorSS::BelowThreshold);
+    }
+    ensure_distinct(indices)?;
+    Ok(partials
+        .iter()
+        .zip(indices)
+        .map(|(partial, &index)| {
+            let lambda = VerifiableSS::<Bls12_381_2, H>::map_share_to_new_params(
+                &vss.parameters,
+                index,
+                indices,
+            );
+            partial * to_g1_scalar(&lambda)
+        })
+        .sum())
+}
+
+/// Verifies a signature by checking `e(sigma, G2) = e(H(m), pk)`
+pub fn verify(
+    msg: &[u8],
+    signature: &Signature,
+    public_key: &Point<Bls12_381_2>,
+) -> Result<(), SigError> {
+    if signature.is_zero() || public_key.is_zero() {
+        return Err(SigError::MalformedSignature);
+    }
+    let lhs = Pair::compute_pairing(signature, &Point::generator().to_point());
+    let rhs = Pair::compute_pairing(&hash_message(msg), public_key);
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(SigError::InvalidSignature)
+    }
+}
+
+/// G1 and G2 share the same scalar field
+fn to_g1_scalar(s: &Scalar<Bls12_381_2>) -> Scalar<Bls12_381_1> {
+    Scalar::from_raw(s.as_raw().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Sha256;
+
+    use super::*;
+
+    #[test]
+    fn sign_combine_verify_3_out_of_5() {
+        let secret = Scalar::<Bls12_381_2>::random();
+        let (vss, shares) = VerifiableSS::<Bls12_381_2, Sha256>::share(2, 5, &secret);
+        let msg = b"threshold bls";
+
+        let indices = [4, 1, 3];
+        let partials: Vec<_> = indices
+            .iter()
+            .map(|&i| partial_sign(msg, &shares[usize::from(i)]))
+            .collect();
+        for (partial, &i) in partials.iter().zip(&indices) {
+            assert_eq!(verify_partial(msg, partial, i, &vss), Ok(()));
+        }
+
+        let signature = combine(&partials, &indices, &vss).unwrap();
+        assert_eq!(signature, hash_message(msg) * to_g1_scalar(&secret));
+        assert_eq!(verify(msg, &signature, &vss.commitments[0]), Ok(()));
+        assert_eq!(
+            verify(b"another message", &signature, &vss.commitments[0]),
+            Err(SigError::InvalidSignature)
+        );
+
+        // Any other subset yields the same signature
+        let indices = [0, 2, 3];
+        let partials: Vec<_> = indices
+            .iter()
+            .map(|&i| partial_sign(msg, &shares[usize::from(i)]))
+            .collect();
+        assert_eq!(combine(&partials, &indices, &vss), Ok(signature));
+    }
+
+    #[test]
+    fn partial_signature_with_wrong_index_is_rejected() {
+        let secret = Scalar::<Bls12_381_2>::random();
+        let (vss, shares) = VerifiableSS::<Bls12_381_2, Sha256>::share(2, 5, &secret);
+        let partial = partial_sign(b"msg", &shares[0]);
+        assert_eq!(
+            verify_partial(b"msg", &partial, 1, &vss),
+            Err(SigError::InvalidSignature)
+        );
+        assert_eq!(
+            verify_partial(b"msg", &partial, u16::MAX, &vss),
+            Err(SigError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn combine_rejects_malformed_input() {
+        let secret = Scalar::<Bls12_381_2>::random();
+        let (vss, shares) = VerifiableSS::<Bls12_381_2, Sha256>::share(2, 5, &secret);
+        let partials: Vec<_> = shares.iter().map(|s| partial_sign(b"msg", s)).collect();
+        assert_eq!(
+            combine(&partials[..2], &[0, 1], &vss),
+            Err(ErrorSS::BelowThreshold)
+        );
+        assert_eq!(
+            combine(&partials[..3], &[0, 1], &vss),
+            Err(ErrorSS::MismatchedLengths)
+        );
+        assert_eq!(
+            combine(&partials[..3], &[0, 1, 1], &vss),
+            Err(ErrorSS::DuplicateIndex)
+        );
+    }
+}
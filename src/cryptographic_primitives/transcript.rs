@@ -0,0 +1,143 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Transcript of public protocol messages used to derive Fiat-Shamir challenges
+//!
+//! Every message is appended with a label, and both the label and the message are
+//! length-prefixed, so that messages cannot be confused with each other. Transcript is
+//! initialized with a protocol label, which provides domain separation between protocols.
+//! Challenges depend on everything appended before them, including previous challenges,
+//! so sub-protocols can be composed by sharing a single transcript.
+//!
+//! ```rust
+//! # use curv::cryptographic_primitives::transcript::Transcript;
+//! # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! let x = Scalar::<Secp256k1>::random();
+//! let y = Point::generator() * &x;
+//!
+//! let mut transcript = Transcript::new(b"my protocol");
+//! transcript.append_point(b"Y", &y);
+//! let challenge: Scalar<Secp256k1> = transcript.challenge_scalar(b"c");
+//! ```
+
+use sha2::{Digest, Sha512};
+
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+/// Domain separation tag used to derive challenge scalars
+const CHALLENGE_DST: &[u8] = b"CURV-TRANSCRIPT-CHALLENGE";
+
+/// Transcript of protocol messages, see [module level docs](self)
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: Sha512,
+}
+
+impl Transcript {
+    /// Starts a new transcript for protocol identified by `label`
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Transcript {
+            hasher: Sha512::new(),
+        };
+        transcript.append_message(b"protocol", label);
+        transcript
+    }
+
+    /// Appends arbitrary bytes to the transcript
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        for bytes in [label, message] {
+            self.hasher.update((bytes.len() as u64).to_be_bytes());
+            self.hasher.update(bytes);
+        }
+    }
+
+    /// Appends a point (in compressed form) to the transcript
+    pub fn append_point<E: Curve>(&mut self, label: &[u8], point: &Point<E>) {
+        self.append_message(label, &point.to_bytes(true))
+    }
+
+    /// Appends a scalar to the transcript
+    pub fn append_scalar<E: Curve>(&mut self, label: &[u8], scalar: &Scalar<E>) {
+        self.append_message(label, &scalar.to_bytes())
+    }
+
+    /// Derives a challenge scalar from everything appended to the transcript so far
+    ///
+    /// Challenge is appended to the transcript, so subsequent challenges are different.
+    pub fn challenge_scalar<E: Curve>(&mut self, label: &[u8]) -> Scalar<E> {
+        self.append_message(b"challenge", label);
+        let state = self.hasher.clone().finalize();
+        self.hasher.update(state);
+        Scalar::from_hash(&[&state], CHALLENGE_DST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(challenge_depends_on_order_of_messages);
+    fn challenge_depends_on_order_of_messages<E: Curve>() {
+        let p = Point::<E>::generator() * Scalar::random();
+        let s = Scalar::<E>::random();
+
+        let mut t1 = Transcript::new(b"test");
+        t1.append_point(b"p", &p);
+        t1.append_scalar(b"s", &s);
+
+        let mut t2 = Transcript::new(b"test");
+        t2.append_scalar(b"s", &s);
+        t2.append_point(b"p", &p);
+
+        let mut t3 = t1.clone();
+        assert_eq!(
+            t1.challenge_scalar::<E>(b"c"),
+            t3.challenge_scalar::<E>(b"c")
+        );
+        assert_ne!(
+            t3.challenge_scalar::<E>(b"c"),
+            t2.challenge_scalar::<E>(b"c")
+        );
+    }
+
+    test_for_all_curves!(challenge_is_domain_separated);
+    fn challenge_is_domain_separated<E: Curve>() {
+        let s = Scalar::<E>::random();
+        let challenge = |protocol: &[u8], label: &[u8], challenge_label: &[u8]| {
+            let mut t = Transcript::new(protocol);
+            t.append_scalar(label, &s);
+            t.challenge_scalar::<E>(challenge_label)
+        };
+
+        let c = challenge(b"protocol", b"s", b"c");
+        assert_eq!(c, challenge(b"protocol", b"s", b"c"));
+        assert_ne!(c, challenge(b"another protocol", b"s", b"c"));
+        assert_ne!(c, challenge(b"protocol", b"t", b"c"));
+        assert_ne!(c, challenge(b"protocol", b"s", b"d"));
+
+        // Label and message boundaries cannot be shifted
+        let mut t1 = Transcript::new(b"protocol");
+        t1.append_message(b"ab", b"c");
+        let mut t2 = Transcript::new(b"protocol");
+        t2.append_message(b"a", b"bc");
+        assert_ne!(
+            t1.challenge_scalar::<E>(b"c"),
+            t2.challenge_scalar::<E>(b"c")
+        );
+    }
+
+    #[test]
+    fn subsequent_challenges_differ() {
+        use crate::elliptic::curves::Secp256k1;
+
+        let mut t = Transcript::new(b"protocol");
+        let c1 = t.challenge_scalar::<Secp256k1>(b"c");
+        let c2 = t.challenge_scalar::<Secp256k1>(b"c");
+        assert_ne!(c1, c2);
+    }
+}
@@ -100,7 +100,6 @@ mod tests {
     use crate::elliptic::curves::Curve;
     use crate::test_for_all_curves;
     use crate::BigInt;
-    use std::convert::TryFrom;
 
     test_for_all_curves!(test_dh_key_exchange_random_shares);
     fn test_dh_key_exchange_random_shares<E: Curve>() {
@@ -121,10 +120,10 @@ mod tests {
 
     test_for_all_curves!(test_dh_key_exchange_fixed_shares);
     fn test_dh_key_exchange_fixed_shares<E: Curve>() {
-        let secret_party_1 = Scalar::try_from(&BigInt::from(1)).unwrap();
+        let secret_party_1 = Scalar::from(&BigInt::from(1));
         let (kg_party_one_first_message, kg_ec_key_pair_party1) =
             Party1FirstMessage::<E>::first_with_fixed_secret_share(secret_party_1);
-        let secret_party_2 = Scalar::try_from(&BigInt::from(2)).unwrap();
+        let secret_party_2 = Scalar::from(&BigInt::from(2));
 
         let (kg_party_two_first_message, kg_ec_key_pair_party2) =
             Party2FirstMessage::first_with_fixed_secret_share(secret_party_2.clone());
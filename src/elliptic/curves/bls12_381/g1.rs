@@ -23,6 +23,12 @@ use crate::BigInt;
 use super::scalar::FieldScalar;
 
 lazy_static::lazy_static! {
+    /// G1 co-factor `h = (x - 1)^2 / 3`
+    static ref COFACTOR: BigInt = BigInt::from_hex("396c8c005555e1568c00aaab0000aaab").unwrap();
+    /// Base field modulus, G2 is defined over its quadratic extension
+    pub(super) static ref FIELD_MODULUS: BigInt = BigInt::from_hex(
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"
+    ).unwrap();
     static ref GENERATOR: G1Point = G1Point {
         purpose: "generator",
         ge: PK::one(),
@@ -95,6 +101,10 @@ impl ECPoint for G1Point {
         &BASE_POINT2
     }
 
+    fn field_modulus() -> &'static BigInt {
+        &FIELD_MODULUS
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<G1Point, NotOnCurve> {
         let vec_x = x.to_bytes();
         let vec_y = y.to_bytes();
@@ -181,10 +191,31 @@ impl ECPoint for G1Point {
         }
     }
 
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> G1Point {
+        // pairing-plus implements a pre-RFC draft of hash-to-curve, outputs don't match
+        // RFC 9380 (see ECPoint::hash_to_curve)
+        let point = <G1 as HashToCurve<ExpandMsgXmd<old_sha2::Sha256>>>::hash_to_curve(msg, dst);
+        G1Point {
+            purpose: "hash_to_curve",
+            ge: point.into_affine(),
+        }
+    }
+
     fn check_point_order_equals_group_order(&self) -> bool {
         !self.is_zero() && self.ge.in_subgroup()
     }
 
+    fn is_in_prime_subgroup(&self) -> bool {
+        self.ge.in_subgroup()
+    }
+
+    fn clear_cofactor(&self) -> G1Point {
+        // pairing_plus checks subgroup membership whenever point is constructed, so `hP = (h mod q)P`
+        let mut result = self.scalar_mul(&FieldScalar::from_bigint(&COFACTOR));
+        result.purpose = "clear_cofactor";
+        result
+    }
+
     fn scalar_mul(&self, scalar: &Self::Scalar) -> G1Point {
         let result = self.ge.mul(scalar.underlying_ref().into_repr());
         G1Point {
@@ -193,6 +224,24 @@ impl ECPoint for G1Point {
         }
     }
 
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> G1Point {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        let bases: Vec<PK> = points.iter().map(|p| p.ge).collect();
+        let scalars: Vec<_> = scalars
+            .iter()
+            .map(|s| s.underlying_ref().into_repr())
+            .collect();
+        let scalars: Vec<&[u64; 4]> = scalars.iter().map(|s| &s.0).collect();
+        G1Point {
+            purpose: "multi_scalar_mul",
+            ge: PK::sum_of_products(&bases, &scalars).into_affine(),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> G1Point {
         let mut result = G1::from(self.ge);
         result.add_assign_mixed(&other.ge);
@@ -22,6 +22,10 @@ use crate::elliptic::curves::traits::*;
 use super::scalar::FieldScalar;
 
 lazy_static::lazy_static! {
+    /// G2 co-factor `h = (x^8 - 4x^7 + 5x^6 - 4x^4 + 6x^3 - 4x^2 - 4x + 13) / 9`
+    static ref COFACTOR: BigInt = BigInt::from_hex(
+        "5d543a95414e7f1091d50792876a202cd91de4547085abaa68a205b2e5a7ddfa628f1cb4d9e82ef21537e293a6691ae1616ec6e786f0c70cf1c38e31c7238e5"
+    ).unwrap();
     static ref GENERATOR: G2Point = G2Point {
         purpose: "generator",
         ge: PK::one(),
@@ -102,6 +106,10 @@ impl ECPoint for G2Point {
         &BASE_POINT2
     }
 
+    fn field_modulus() -> &'static BigInt {
+        &super::g1::FIELD_MODULUS
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<G2Point, NotOnCurve> {
         let vec_x = x.to_bytes();
         let vec_y = y.to_bytes();
@@ -188,10 +196,31 @@ impl ECPoint for G2Point {
         }
     }
 
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> G2Point {
+        // pairing-plus implements a pre-RFC draft of hash-to-curve, outputs don't match
+        // RFC 9380 (see ECPoint::hash_to_curve)
+        let point = <G2 as HashToCurve<ExpandMsgXmd<old_sha2::Sha256>>>::hash_to_curve(msg, dst);
+        G2Point {
+            purpose: "hash_to_curve",
+            ge: point.into_affine(),
+        }
+    }
+
     fn check_point_order_equals_group_order(&self) -> bool {
         !self.is_zero() && self.ge.in_subgroup()
     }
 
+    fn is_in_prime_subgroup(&self) -> bool {
+        self.ge.in_subgroup()
+    }
+
+    fn clear_cofactor(&self) -> G2Point {
+        // pairing_plus checks subgroup membership whenever point is constructed, so `hP = (h mod q)P`
+        let mut result = self.scalar_mul(&FieldScalar::from_bigint(&COFACTOR));
+        result.purpose = "clear_cofactor";
+        result
+    }
+
     fn scalar_mul(&self, scalar: &Self::Scalar) -> G2Point {
         let result = self.ge.mul(scalar.underlying_ref().into_repr());
         G2Point {
@@ -200,6 +229,24 @@ impl ECPoint for G2Point {
         }
     }
 
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> G2Point {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        let bases: Vec<PK> = points.iter().map(|p| p.ge).collect();
+        let scalars: Vec<_> = scalars
+            .iter()
+            .map(|s| s.underlying_ref().into_repr())
+            .collect();
+        let scalars: Vec<&[u64; 4]> = scalars.iter().map(|s| &s.0).collect();
+        G2Point {
+            purpose: "multi_scalar_mul",
+            ge: PK::sum_of_products(&bases, &scalars).into_affine(),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> G2Point {
         let mut result = G2::from(self.ge);
         result.add_assign_mixed(&other.ge);
@@ -80,6 +80,26 @@ mod tests {
         assert_eq!(res_a_power, res_b_power);
     }
 
+    // e(aP, bQ) = e(P, Q)^{ab}
+    #[test]
+    fn bilinearity() {
+        use ff_zeroize::PrimeField;
+
+        let p = Point::<Bls12_381_1>::generator().to_point();
+        let q = Point::<Bls12_381_2>::generator().to_point();
+        let a = Scalar::<Bls12_381_1>::random();
+        let b = Scalar::<Bls12_381_2>::random();
+
+        let e_ap_bq = Pair::compute_pairing(&(&p * &a), &(&q * &b));
+
+        let ab = Scalar::<Bls12_381_1>::from_raw(b.as_raw().clone()) * &a;
+        let e_p_q_ab = Pair::compute_pairing(&p, &q)
+            .e
+            .pow(ab.as_raw().underlying_ref().into_repr());
+        assert_eq!(e_ap_bq.e, e_p_q_ab);
+        assert_ne!(e_ap_bq.e, Fq12::one());
+    }
+
     // e(P,Q)e(P,R) = e(P, Q+ R)
     #[test]
     fn pairing() {
@@ -3,10 +3,12 @@ use std::fmt;
 use ff_zeroize::{Field, PrimeField, PrimeFieldRepr, ScalarEngine};
 use generic_array::GenericArray;
 use pairing_plus::bls12_381::{Fr, FrRepr};
-use rand::rngs::OsRng;
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
 use zeroize::Zeroizing;
 
 use crate::arithmetic::*;
+use crate::elliptic::curves::h2c::hash_to_scalar;
 use crate::elliptic::curves::traits::*;
 
 lazy_static::lazy_static! {
@@ -41,10 +43,10 @@ impl ECScalar for FieldScalar {
 
     type ScalarLength = typenum::U32;
 
-    fn random() -> FieldScalar {
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> FieldScalar {
         FieldScalar {
             purpose: "random",
-            fe: Zeroizing::new(Field::random(&mut OsRng)),
+            fe: Zeroizing::new(Field::random(rng)),
         }
     }
 
@@ -55,6 +57,22 @@ impl ECScalar for FieldScalar {
         }
     }
 
+    fn one() -> FieldScalar {
+        FieldScalar {
+            purpose: "one",
+            fe: Zeroizing::new(Field::one()),
+        }
+    }
+
+    fn from_u64(n: u64) -> FieldScalar {
+        FieldScalar {
+            purpose: "from_u64",
+            fe: Fr::from_repr(FrRepr::from(n))
+                .expect("u64 is less than group order")
+                .into(),
+        }
+    }
+
     fn from_bigint(n: &BigInt) -> FieldScalar {
         let bytes = n
             .modulus(Self::group_order())
@@ -95,6 +113,23 @@ impl ECScalar for FieldScalar {
         })
     }
 
+    fn deserialize_reduced(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != SECRET_KEY_SIZE {
+            return Err(DeserializationError);
+        }
+        // Reduce through field arithmetic rather than `BigInt`, which isn't constant-time
+        let mut wide = Zeroizing::new([0u8; 64]);
+        wide[64 - SECRET_KEY_SIZE..].copy_from_slice(bytes);
+        Ok(FieldScalar {
+            purpose: "deserialize_reduced",
+            fe: Self::from_bytes_wide(&wide).fe,
+        })
+    }
+
+    fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self {
+        hash_to_scalar::<Sha256, Self>(data, dst)
+    }
+
     fn add(&self, other: &Self) -> FieldScalar {
         let mut result = self.fe.clone();
         result.add_assign(&other.fe);
@@ -132,9 +167,16 @@ impl ECScalar for FieldScalar {
     }
 
     fn invert(&self) -> Option<FieldScalar> {
+        if self.is_zero() {
+            return None;
+        }
+        // Fermat's little theorem: s^-1 = s^(q-2). Field::inverse is variable-time, whereas
+        // exponentiation by public exponent is not
+        let mut exponent = Fr::char();
+        exponent.sub_noborrow(&FrRepr::from(2));
         Some(FieldScalar {
             purpose: "invert",
-            fe: Zeroizing::new(self.fe.inverse()?),
+            fe: Zeroizing::new(self.fe.pow(exponent)),
         })
     }
 
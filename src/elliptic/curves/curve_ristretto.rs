@@ -10,17 +10,22 @@ use std::convert::TryInto;
 use std::ptr;
 use std::sync::atomic;
 
-use curve25519_dalek::constants::{BASEPOINT_ORDER, RISTRETTO_BASEPOINT_POINT};
+use curve25519_dalek::constants::{
+    BASEPOINT_ORDER, RISTRETTO_BASEPOINT_POINT, RISTRETTO_BASEPOINT_TABLE,
+};
 use curve25519_dalek::ristretto::CompressedRistretto;
-use curve25519_dalek::traits::{Identity, IsIdentity};
+use curve25519_dalek::traits::{Identity, IsIdentity, VartimeMultiscalarMul};
 use generic_array::GenericArray;
-use rand::thread_rng;
-use sha2::{Digest, Sha256};
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256, Sha512};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::{Zeroize, Zeroizing};
 
 use crate::arithmetic::*;
+use crate::cryptographic_primitives::hashing::expand_message_xmd;
 use crate::elliptic::curves::traits::*;
 
+use super::h2c::hash_to_scalar;
 use super::traits::{ECPoint, ECScalar};
 
 lazy_static::lazy_static! {
@@ -29,6 +34,8 @@ lazy_static::lazy_static! {
         fe: BASEPOINT_ORDER.into(),
     }.to_bigint();
 
+    static ref FIELD_MODULUS: BigInt = BigInt::from(2u32).pow(255u32) - BigInt::from(19u32);
+
     static ref GENERATOR: RistrettoPoint = RistrettoPoint {
         purpose: "generator",
         ge: RISTRETTO_BASEPOINT_POINT,
@@ -88,10 +95,10 @@ impl ECScalar for RistrettoScalar {
 
     type ScalarLength = typenum::U32;
 
-    fn random() -> RistrettoScalar {
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> RistrettoScalar {
         RistrettoScalar {
             purpose: "random",
-            fe: SK::random(&mut thread_rng()).into(),
+            fe: SK::random(rng).into(),
         }
     }
 
@@ -102,6 +109,31 @@ impl ECScalar for RistrettoScalar {
         }
     }
 
+    fn one() -> RistrettoScalar {
+        RistrettoScalar {
+            purpose: "one",
+            fe: SK::one().into(),
+        }
+    }
+
+    fn from_u64(n: u64) -> RistrettoScalar {
+        RistrettoScalar {
+            purpose: "from_u64",
+            fe: SK::from(n).into(),
+        }
+    }
+
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.fe.ct_eq(&other.fe)
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        RistrettoScalar {
+            purpose: "conditional_select",
+            fe: SK::conditional_select(&a.fe, &b.fe, choice).into(),
+        }
+    }
+
     fn from_bigint(n: &BigInt) -> RistrettoScalar {
         let curve_order = RistrettoScalar::group_order();
         let mut bytes = n
@@ -135,6 +167,28 @@ impl ECScalar for RistrettoScalar {
         })
     }
 
+    fn deserialize_reduced(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let bytes: [u8; 32] = bytes.try_into().or(Err(DeserializationError))?;
+        Ok(RistrettoScalar {
+            purpose: "deserialize_reduced",
+            fe: SK::from_bytes_mod_order(bytes).into(),
+        })
+    }
+
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+        // dalek expects little-endian bytes
+        let mut le_bytes = Zeroizing::new(*bytes);
+        le_bytes.reverse();
+        RistrettoScalar {
+            purpose: "from_bytes_wide",
+            fe: SK::from_bytes_mod_order_wide(&le_bytes).into(),
+        }
+    }
+
+    fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self {
+        hash_to_scalar::<Sha512, Self>(data, dst)
+    }
+
     fn add(&self, other: &Self) -> RistrettoScalar {
         RistrettoScalar {
             purpose: "add",
@@ -226,6 +280,17 @@ impl ECPoint for RistrettoPoint {
         self.ge.is_identity()
     }
 
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.ge.ct_eq(&other.ge)
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        RistrettoPoint {
+            purpose: "conditional_select",
+            ge: PK::conditional_select(&a.ge, &b.ge, choice),
+        }
+    }
+
     fn generator() -> &'static RistrettoPoint {
         &GENERATOR
     }
@@ -234,6 +299,10 @@ impl ECPoint for RistrettoPoint {
         &BASE_POINT2
     }
 
+    fn field_modulus() -> &'static BigInt {
+        &FIELD_MODULUS
+    }
+
     fn from_coords(_x: &BigInt, _y: &BigInt) -> Result<RistrettoPoint, NotOnCurve> {
         // Underlying library intentionally hides x coordinate. There's no way to match if `x`
         // correspond to given `y`.
@@ -282,10 +351,32 @@ impl ECPoint for RistrettoPoint {
             })
     }
 
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> RistrettoPoint {
+        // Draft suite ristretto255_XMD:SHA-512_R255MAP_RO_, it's not part of RFC 9380 and isn't
+        // checked against test vectors (see ECPoint::hash_to_curve)
+        let uniform_bytes: [u8; 64] = expand_message_xmd::<Sha512>(msg, dst, 64)
+            .try_into()
+            .expect("expand_message_xmd returns exactly 64 bytes");
+        RistrettoPoint {
+            purpose: "hash_to_curve",
+            ge: PK::from_uniform_bytes(&uniform_bytes),
+        }
+    }
+
     fn check_point_order_equals_group_order(&self) -> bool {
         !self.is_zero()
     }
 
+    fn is_in_prime_subgroup(&self) -> bool {
+        // Ristretto encoding eliminates ed25519 torsion: the group is of prime order by construction
+        true
+    }
+
+    fn clear_cofactor(&self) -> RistrettoPoint {
+        // Ristretto group has no co-factor, so it is a no-op
+        *self
+    }
+
     fn scalar_mul(&self, fe: &Self::Scalar) -> RistrettoPoint {
         RistrettoPoint {
             purpose: "scalar_mul",
@@ -293,6 +384,28 @@ impl ECPoint for RistrettoPoint {
         }
     }
 
+    fn generator_mul(fe: &Self::Scalar) -> RistrettoPoint {
+        RistrettoPoint {
+            purpose: "generator_mul",
+            ge: &*fe.fe * &RISTRETTO_BASEPOINT_TABLE,
+        }
+    }
+
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> RistrettoPoint {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        RistrettoPoint {
+            purpose: "multi_scalar_mul",
+            ge: PK::vartime_multiscalar_mul(
+                scalars.iter().map(|s| &*s.fe),
+                points.iter().map(|p| &p.ge),
+            ),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> RistrettoPoint {
         RistrettoPoint {
             purpose: "add_point",
@@ -349,3 +462,27 @@ impl Zeroize for RistrettoPoint {
         atomic::compiler_fence(atomic::Ordering::SeqCst);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    use super::{ECPoint, GE};
+
+    #[test]
+    fn test_base_point2() {
+        // base_point2 is derived by hashing the generator with SHA256 and decoding the digest as
+        // a point. The value must never change, otherwise previously produced commitments can't
+        // be verified.
+        let g = GE::generator();
+        let hash = Sha256::digest(g.serialize_compressed().as_ref());
+        let expected = GE::deserialize(&hash).unwrap();
+
+        let base_point2 = GE::base_point2();
+        assert_eq!(base_point2, &expected);
+        assert_eq!(
+            hex::encode(base_point2.serialize_compressed()),
+            "b4aed8a647936906f61cce1e8115fd2f99a6be13eae8683271bc75fcc8bb6e1e"
+        );
+    }
+}
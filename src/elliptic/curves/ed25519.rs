@@ -13,13 +13,18 @@ use std::sync::atomic;
 use std::{fmt, ops, ptr, str};
 
 use cryptoxide::curve25519::*;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::traits::VartimeMultiscalarMul;
 use generic_array::GenericArray;
+use rand::{CryptoRng, RngCore};
+use sha2::Sha512;
 use zeroize::{Zeroize, Zeroizing};
 
 use crate::arithmetic::traits::*;
-use crate::cryptographic_primitives::hashing::Digest;
+use crate::cryptographic_primitives::hashing::{hash_to_field, Digest};
 use crate::BigInt;
 
+use super::h2c::{hash_to_scalar, inv0, is_square, sgn0};
 use super::traits::{ECPoint, ECScalar};
 use crate::elliptic::curves::{Curve, DeserializationError, NotOnCurve, PointCoords};
 
@@ -62,6 +67,7 @@ lazy_static::lazy_static! {
 }
 
 const FE_ZERO: Fe = Fe([0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+const FE_ONE: Fe = Fe([1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 const TWO_TIMES_SECRET_KEY_SIZE: usize = 64;
 
 /// Alias to [Edwards point](GeP3)
@@ -122,6 +128,47 @@ impl Curve for Ed25519 {
     const CURVE_NAME: &'static str = "ed25519";
 }
 
+/// Constant-time scalar arithmetic, enabled by `ct_field` feature
+///
+/// Scalars are converted to [dalek scalars](curve25519_dalek::scalar::Scalar), whose arithmetic
+/// is constant-time, instead of being routed through [BigInt].
+#[cfg(feature = "ct_field")]
+mod ct {
+    pub use curve25519_dalek::scalar::Scalar;
+    use zeroize::Zeroizing;
+
+    use super::{Ed25519Scalar, Fe, SK};
+
+    #[cfg(test)]
+    thread_local! {
+        /// Counts conversions to and from BigInt, used to check that arithmetic avoids them
+        pub static BIGINT_CONVERSIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Takes a slice of exactly 32 bytes
+    pub fn to_array(bytes: &[u8]) -> [u8; 32] {
+        let mut array = [0u8; 32];
+        array.copy_from_slice(bytes);
+        array
+    }
+
+    pub fn to_dalek(s: &Ed25519Scalar) -> Zeroizing<Scalar> {
+        Zeroizing::new(Scalar::from_bytes_mod_order(s.fe.to_bytes()))
+    }
+
+    pub fn from_dalek(s: &Scalar) -> Zeroizing<SK> {
+        Zeroizing::new(SK(Fe::from_bytes(s.as_bytes())))
+    }
+
+    pub fn binary_op(
+        a: &Ed25519Scalar,
+        b: &Ed25519Scalar,
+        op: impl FnOnce(&Scalar, &Scalar) -> Scalar,
+    ) -> Zeroizing<SK> {
+        from_dalek(&Zeroizing::new(op(&to_dalek(a), &to_dalek(b))))
+    }
+}
+
 impl ECScalar for Ed25519Scalar {
     type Underlying = SK;
 
@@ -129,12 +176,23 @@ impl ECScalar for Ed25519Scalar {
 
     // we chose to multiply by 8 (co-factor) all group elements to work in the prime order sub group.
     // each random fe is having its 3 first bits zeroed
-    fn random() -> Ed25519Scalar {
-        let rnd_bn = BigInt::sample_below(Self::group_order());
-        let rnd_bn_mul_8 = BigInt::mod_mul(&rnd_bn, &BigInt::from(8), Self::group_order());
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Ed25519Scalar {
+        // Excess bytes make bias of reduction modulo group order negligible
+        let mut bytes = Zeroizing::new([0u8; 64]);
+        rng.fill_bytes(&mut bytes[..]);
+        #[cfg(feature = "ct_field")]
+        let fe = ct::from_dalek(&Zeroizing::new(
+            ct::Scalar::from_bytes_mod_order_wide(&bytes) * ct::Scalar::from(8u8),
+        ));
+        #[cfg(not(feature = "ct_field"))]
+        let fe = {
+            let rnd_bn = BigInt::from_bytes(&bytes[..]).modulus(Self::group_order());
+            let rnd_bn_mul_8 = BigInt::mod_mul(&rnd_bn, &BigInt::from(8), Self::group_order());
+            Self::from_bigint(&rnd_bn_mul_8).fe
+        };
         Ed25519Scalar {
             purpose: "random",
-            fe: Self::from_bigint(&rnd_bn_mul_8).fe,
+            fe,
         }
     }
 
@@ -145,16 +203,30 @@ impl ECScalar for Ed25519Scalar {
         }
     }
 
+    fn one() -> Ed25519Scalar {
+        Ed25519Scalar {
+            purpose: "one",
+            fe: SK(FE_ONE).into(),
+        }
+    }
+
+    fn from_u64(n: u64) -> Ed25519Scalar {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&n.to_le_bytes());
+        Ed25519Scalar {
+            purpose: "from_u64",
+            fe: SK(Fe::from_bytes(&bytes)).into(),
+        }
+    }
+
     fn is_zero(&self) -> bool {
         self.fe.0 == FE_ZERO
     }
 
     fn from_bigint(n: &BigInt) -> Ed25519Scalar {
-        let mut v = BigInt::to_bytes(n);
-        if v.len() > TWO_TIMES_SECRET_KEY_SIZE {
-            v = v[0..TWO_TIMES_SECRET_KEY_SIZE].to_vec();
-        }
-
+        #[cfg(all(test, feature = "ct_field"))]
+        ct::BIGINT_CONVERSIONS.with(|c| c.set(c.get() + 1));
+        let mut v = BigInt::to_bytes(&n.modulus(Self::group_order()));
         let mut template = vec![0; TWO_TIMES_SECRET_KEY_SIZE - v.len()];
         template.extend_from_slice(&v);
         v = template;
@@ -167,6 +239,8 @@ impl ECScalar for Ed25519Scalar {
     }
 
     fn to_bigint(&self) -> BigInt {
+        #[cfg(all(test, feature = "ct_field"))]
+        ct::BIGINT_CONVERSIONS.with(|c| c.set(c.get() + 1));
         let mut t = self.fe.to_bytes().to_vec();
         t.reverse();
         BigInt::from_bytes(&t)
@@ -180,67 +254,131 @@ impl ECScalar for Ed25519Scalar {
         if bytes.len() != 32 {
             return Err(DeserializationError);
         }
+        #[cfg(feature = "ct_field")]
+        {
+            let bytes = ct::to_array(bytes);
+            let s = ct::Scalar::from_canonical_bytes(bytes).ok_or(DeserializationError)?;
+            Ok(Ed25519Scalar {
+                purpose: "deserialize",
+                fe: ct::from_dalek(&Zeroizing::new(s)),
+            })
+        }
+        #[cfg(not(feature = "ct_field"))]
+        {
+            let mut be_bytes = bytes.to_vec();
+            be_bytes.reverse();
+            if &BigInt::from_bytes(&be_bytes) >= Self::group_order() {
+                return Err(DeserializationError);
+            }
+            Ok(Ed25519Scalar {
+                purpose: "deserialize",
+                fe: SK(Fe::from_bytes(bytes)).into(),
+            })
+        }
+    }
+
+    fn deserialize_reduced(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != 32 {
+            return Err(DeserializationError);
+        }
+        #[cfg(feature = "ct_field")]
+        let fe = {
+            let bytes = ct::to_array(bytes);
+            ct::from_dalek(&Zeroizing::new(ct::Scalar::from_bytes_mod_order(bytes)))
+        };
+        #[cfg(not(feature = "ct_field"))]
+        let fe = {
+            let mut be_bytes = bytes.to_vec();
+            be_bytes.reverse();
+            Self::from_bigint(&BigInt::from_bytes(&be_bytes)).fe
+        };
         Ok(Ed25519Scalar {
-            purpose: "deserialize",
-            fe: SK(Fe::from_bytes(bytes)).into(),
+            purpose: "deserialize_reduced",
+            fe,
         })
     }
 
-    fn add(&self, other: &Self) -> Ed25519Scalar {
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+        // dalek expects little-endian bytes
+        let mut le_bytes = Zeroizing::new(*bytes);
+        le_bytes.reverse();
+        let s = Zeroizing::new(curve25519_dalek::scalar::Scalar::from_bytes_mod_order_wide(
+            &le_bytes,
+        ));
         Ed25519Scalar {
-            purpose: "add",
-            fe: Self::from_bigint(&BigInt::mod_add(
-                &self.to_bigint(),
-                &other.to_bigint(),
-                Self::group_order(),
-            ))
-            .fe,
+            purpose: "from_bytes_wide",
+            fe: SK(Fe::from_bytes(s.as_bytes())).into(),
         }
     }
 
+    fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self {
+        hash_to_scalar::<Sha512, Self>(data, dst)
+    }
+
+    fn add(&self, other: &Self) -> Ed25519Scalar {
+        #[cfg(feature = "ct_field")]
+        let fe = ct::binary_op(self, other, |a, b| a + b);
+        #[cfg(not(feature = "ct_field"))]
+        let fe = Self::from_bigint(&BigInt::mod_add(
+            &self.to_bigint(),
+            &other.to_bigint(),
+            Self::group_order(),
+        ))
+        .fe;
+        Ed25519Scalar { purpose: "add", fe }
+    }
+
     fn mul(&self, other: &Self) -> Ed25519Scalar {
-        Ed25519Scalar {
-            purpose: "mul",
-            fe: Self::from_bigint(&BigInt::mod_mul(
-                &self.to_bigint(),
-                &other.to_bigint(),
-                Self::group_order(),
-            ))
-            .fe,
-        }
+        #[cfg(feature = "ct_field")]
+        let fe = ct::binary_op(self, other, |a, b| a * b);
+        #[cfg(not(feature = "ct_field"))]
+        let fe = Self::from_bigint(&BigInt::mod_mul(
+            &self.to_bigint(),
+            &other.to_bigint(),
+            Self::group_order(),
+        ))
+        .fe;
+        Ed25519Scalar { purpose: "mul", fe }
     }
 
     fn sub(&self, other: &Self) -> Ed25519Scalar {
-        Ed25519Scalar {
-            purpose: "sub",
-            fe: Self::from_bigint(&BigInt::mod_sub(
-                &self.to_bigint(),
-                &other.to_bigint(),
-                Self::group_order(),
-            ))
-            .fe,
-        }
+        #[cfg(feature = "ct_field")]
+        let fe = ct::binary_op(self, other, |a, b| a - b);
+        #[cfg(not(feature = "ct_field"))]
+        let fe = Self::from_bigint(&BigInt::mod_sub(
+            &self.to_bigint(),
+            &other.to_bigint(),
+            Self::group_order(),
+        ))
+        .fe;
+        Ed25519Scalar { purpose: "sub", fe }
     }
 
     fn neg(&self) -> Self {
-        Ed25519Scalar {
-            purpose: "neg",
-            fe: Self::from_bigint(&BigInt::mod_sub(
-                &Self::zero().to_bigint(),
-                &self.to_bigint(),
-                Self::group_order(),
-            ))
-            .fe,
-        }
+        #[cfg(feature = "ct_field")]
+        let fe = ct::from_dalek(&Zeroizing::new(-&*ct::to_dalek(self)));
+        #[cfg(not(feature = "ct_field"))]
+        let fe = Self::from_bigint(&BigInt::mod_sub(
+            &Self::zero().to_bigint(),
+            &self.to_bigint(),
+            Self::group_order(),
+        ))
+        .fe;
+        Ed25519Scalar { purpose: "neg", fe }
     }
 
     fn invert(&self) -> Option<Ed25519Scalar> {
         if self.is_zero() {
             None
         } else {
+            // dalek inverts scalars in constant time, unlike BigInt::mod_inv
+            let s = Zeroizing::new(curve25519_dalek::scalar::Scalar::from_bytes_mod_order(
+                self.fe.to_bytes(),
+            ));
+            let s_inv = Zeroizing::new(s.invert());
             Some(Ed25519Scalar {
                 purpose: "invert",
-                fe: Self::from_bigint(&BigInt::mod_inv(&self.to_bigint(), Self::group_order())?).fe,
+                fe: SK(Fe::from_bytes(s_inv.as_bytes())).into(),
             })
         }
     }
@@ -327,6 +465,10 @@ impl ECPoint for Ed25519Point {
         &BASE_POINT2
     }
 
+    fn field_modulus() -> &'static BigInt {
+        &FIELD_MODULUS
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<Ed25519Point, NotOnCurve> {
         let is_odd = x.is_odd();
         let expected_x = xrecover(y, is_odd);
@@ -439,6 +581,24 @@ impl ECPoint for Ed25519Point {
         }
     }
 
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Ed25519Point {
+        hash_to_curve_elligator2(msg, dst)
+    }
+
+    fn is_in_prime_subgroup(&self) -> bool {
+        CompressedEdwardsY(self.ge.to_bytes())
+            .decompress()
+            .expect("point is guaranteed to be valid")
+            .is_torsion_free()
+    }
+
+    fn clear_cofactor(&self) -> Ed25519Point {
+        Ed25519Point {
+            purpose: "clear_cofactor",
+            ge: self.mul_u64(8).ge,
+        }
+    }
+
     fn scalar_mul(&self, fe: &Self::Scalar) -> Ed25519Point {
         let vec_0 = [
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -457,6 +617,37 @@ impl ECPoint for Ed25519Point {
         }
     }
 
+    fn generator_mul(fe: &Self::Scalar) -> Ed25519Point {
+        // cryptoxide implements fixed-base multiplication via precomputed table
+        Ed25519Point {
+            purpose: "generator_mul",
+            ge: ge_scalarmult_base(&fe.fe.to_bytes()),
+        }
+    }
+
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> Ed25519Point {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        // cryptoxide doesn't provide multiscalar multiplication, so we convert points and scalars
+        // to curve25519_dalek, which uses the same canonical encoding
+        let points = points.iter().map(|p| {
+            CompressedEdwardsY(p.ge.to_bytes())
+                .decompress()
+                .expect("point is guaranteed to be valid")
+        });
+        let scalars = scalars
+            .iter()
+            .map(|s| curve25519_dalek::scalar::Scalar::from_bytes_mod_order(s.fe.to_bytes()));
+        let result = EdwardsPoint::vartime_multiscalar_mul(scalars, points);
+        let mut ge = Ed25519Point::deserialize(result.compress().as_bytes())
+            .expect("dalek always outputs a valid point");
+        ge.purpose = "multi_scalar_mul";
+        ge
+    }
+
     fn add_point(&self, other: &Self) -> Ed25519Point {
         let pkpk = self.ge + other.ge.to_cached();
         let mut pk_p2_bytes = pkpk.to_p2().to_bytes();
@@ -543,6 +734,95 @@ fn expmod(b: &BigInt, e: &BigInt, m: &BigInt) -> BigInt {
     t
 }
 
+lazy_static::lazy_static! {
+    static ref FIELD_MODULUS: BigInt = BigInt::from(2u32).pow(255u32) - BigInt::from(19u32);
+    // Montgomery form of curve25519: v^2 = u^3 + Ju^2 + u
+    static ref MONTGOMERY_J: BigInt = BigInt::from(486_662);
+    // sqrt(-486664) with sgn0 = 0, used by rational map from curve25519 to edwards25519
+    static ref SQRT_MINUS_486664: BigInt = {
+        let p = &*FIELD_MODULUS;
+        let root = field_sqrt(&(p - BigInt::from(486_664))).unwrap();
+        if sgn0(&root) { p - root } else { root }
+    };
+}
+
+/// Hashes a message to ed25519 point following `edwards25519_XMD:SHA-512_ELL2_RO_` suite
+/// defined in RFC 9380
+///
+/// Computations are not constant time.
+fn hash_to_curve_elligator2(msg: &[u8], dst: &[u8]) -> Ed25519Point {
+    let u = hash_to_field::<Sha512>(msg, dst, 2, &FIELD_MODULUS, 48);
+    let q0 = map_to_curve_elligator2_edwards25519(&u[0]);
+    let q1 = map_to_curve_elligator2_edwards25519(&u[1]);
+    // clear cofactor h_eff = 8
+    let p = q0.add_point(&q1);
+    let p = p.add_point(&p);
+    let p = p.add_point(&p);
+    let mut p = p.add_point(&p);
+    p.purpose = "hash_to_curve";
+    p
+}
+
+/// Elligator 2 map to curve25519 composed with rational map to edwards25519
+/// (RFC 9380, Section 6.7.1 and Appendix D)
+fn map_to_curve_elligator2_edwards25519(u: &BigInt) -> Ed25519Point {
+    let p = &*FIELD_MODULUS;
+    let j = &*MONTGOMERY_J;
+    let minus_j = p - j;
+    // g(x) = x^3 + Jx^2 + x = ((x + J)x + 1)x
+    let g = |x: &BigInt| {
+        let t = BigInt::mod_add(x, j, p);
+        let t = BigInt::mod_add(&BigInt::mod_mul(&t, x, p), &BigInt::one(), p);
+        BigInt::mod_mul(&t, x, p)
+    };
+
+    // x1 = -J / (1 + Z * u^2), Z = 2
+    let tv1 = BigInt::mod_add(
+        &BigInt::mod_mul(&BigInt::from(2), &(u * u), p),
+        &BigInt::one(),
+        p,
+    );
+    let x1 = BigInt::mod_mul(&minus_j, &inv0(&tv1, p), p);
+    let x1 = if x1.is_zero() { minus_j.clone() } else { x1 };
+    let gx1 = g(&x1);
+    let (s, t) = if is_square(&gx1, p) {
+        let y = field_sqrt(&gx1).unwrap();
+        (x1, if sgn0(&y) { y } else { p - y })
+    } else {
+        let x2 = BigInt::mod_sub(&minus_j, &x1, p);
+        let y = field_sqrt(&g(&x2)).unwrap();
+        (x2, if sgn0(&y) { p - y } else { y })
+    };
+
+    // (x, y) = (sqrt(-486664) * s / t, (s - 1) / (s + 1))
+    let s_plus_one = BigInt::mod_add(&s, &BigInt::one(), p);
+    if t.is_zero() || s_plus_one.is_zero() {
+        return Ed25519Point::zero();
+    }
+    let x = BigInt::mod_mul(&BigInt::mod_mul(&SQRT_MINUS_486664, &s, p), &inv0(&t, p), p);
+    let y = BigInt::mod_mul(
+        &BigInt::mod_sub(&s, &BigInt::one(), p),
+        &inv0(&s_plus_one, p),
+        p,
+    );
+    Ed25519Point::from_coords(&x, &y).expect("rational map outputs a point on edwards25519")
+}
+
+/// Square root modulo `2^255 - 19`, returns `None` if `a` is not a square
+fn field_sqrt(a: &BigInt) -> Option<BigInt> {
+    let p = &*FIELD_MODULUS;
+    let mut root = BigInt::mod_pow(a, &((p + BigInt::from(3)) >> 3), p);
+    if BigInt::mod_mul(&root, &root, p) != a.modulus(p) {
+        let sqrt_minus_one = BigInt::mod_pow(&BigInt::from(2), &((p - BigInt::one()) >> 2), p);
+        root = BigInt::mod_mul(&root, &sqrt_minus_one, p);
+    }
+    if BigInt::mod_mul(&root, &root, p) == a.modulus(p) {
+        Some(root)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::arithmetic::traits::Converter;
@@ -592,4 +872,140 @@ mod tests {
             p = p * Scalar::from(2u16);
         }
     }
+
+    #[test]
+    fn test_base_point2() {
+        // base_point2 is derived by hashing the generator twice with SHA256, decoding the digest
+        // as a point, and multiplying it by the cofactor. The value must never change, otherwise
+        // previously produced commitments can't be verified.
+        use super::{FE, GE};
+        use crate::elliptic::curves::{ECPoint, ECScalar};
+        use sha2::{Digest, Sha256};
+
+        let g = GE::generator();
+        let hash = Sha256::digest(g.serialize_compressed().as_ref());
+        let hash = Sha256::digest(&hash);
+        let eight = FE::from_bigint(&BigInt::from(8));
+        let expected = GE::deserialize(&hash).unwrap().scalar_mul(&eight);
+
+        let base_point2 = GE::base_point2();
+        assert_eq!(base_point2, &expected);
+        assert_eq!(
+            hex::encode(base_point2.serialize_compressed()),
+            "c614d58236b79079b6745bb2fdfcab404efa6721fa2eb7b0d77c7b8d24452b08"
+        );
+    }
+
+    // Test vectors from RFC 9380, Appendix J.5.1
+    #[test]
+    fn hash_to_curve_test_vectors() {
+        use crate::elliptic::curves::ECPoint;
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        let vectors: &[(&[u8], &str, &str)] = &[
+            (
+                b"",
+                "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+                "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+            ),
+            (
+                b"abc",
+                "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+                "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+            ),
+        ];
+        for (msg, x, y) in vectors {
+            let point = super::hash_to_curve_elligator2(msg, DST);
+            assert_eq!(point.x_coord(), Some(BigInt::from_hex(x).unwrap()));
+            assert_eq!(point.y_coord(), Some(BigInt::from_hex(y).unwrap()));
+        }
+    }
+
+    #[test]
+    fn from_hash_known_answer() {
+        use super::FE;
+        use crate::elliptic::curves::ECScalar;
+
+        // expand_message_xmd (SHA-512) over length-prefixed data, reduced modulo group order.
+        // Expected value is computed independently of this crate, by a standalone RFC 9380
+        // expand_message_xmd implementation (checked against RFC test vectors) in Python
+        let scalar = FE::from_hash(&[b"hello", b"world"], b"CURV-TEST-V01-from_hash");
+        assert_eq!(
+            scalar.to_bigint(),
+            BigInt::from_hex("04422808663689b648759ad91324a92d32cfcb6859c0e0d4df3f33ebb0504f90")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn small_subgroup_points_are_rejected() {
+        use super::{FE, GE};
+        use crate::elliptic::curves::{ECPoint, ECScalar};
+
+        // Point of order 8
+        let torsion = GE::deserialize(
+            &hex::decode("c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a")
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(!torsion.is_zero());
+        assert!(!torsion.is_in_prime_subgroup());
+        assert!(torsion.clear_cofactor().is_zero());
+
+        let point = GE::generator_mul(&FE::random());
+        let tainted = point.add_point(&torsion);
+        assert!(point.is_in_prime_subgroup());
+        assert!(!tainted.is_in_prime_subgroup());
+        assert!(!tainted.check_point_order_equals_group_order());
+        assert_eq!(tainted.clear_cofactor(), point.clear_cofactor());
+        assert_eq!(
+            point.clear_cofactor(),
+            point.scalar_mul(&FE::from_bigint(&BigInt::from(8)))
+        );
+
+        // Point wrapper refuses to deserialize it
+        assert!(Point::<Ed25519>::from_bytes(&tainted.serialize_compressed()).is_err());
+        assert!(Point::<Ed25519>::from_bytes(&torsion.serialize_compressed()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "ct_field")]
+    fn scalar_arithmetic_avoids_bigint() {
+        use super::{ct::BIGINT_CONVERSIONS, FE};
+        use crate::arithmetic::{Modulo, Zero};
+        use crate::elliptic::curves::ECScalar;
+
+        let a = FE::random();
+        let b = FE::random();
+        let a_bytes = a.serialize();
+
+        BIGINT_CONVERSIONS.with(|c| c.set(0));
+        let sum = a.add(&b);
+        let product = a.mul(&b);
+        let difference = a.sub(&b);
+        let negation = a.neg();
+        let inverse = a.invert().unwrap();
+        let deserialized = FE::deserialize(&a_bytes).unwrap();
+        let reduced = FE::deserialize_reduced(&[0xff; 32]).unwrap();
+        let random = FE::random();
+        assert_eq!(BIGINT_CONVERSIONS.with(|c| c.get()), 0);
+
+        // Results match BigInt arithmetic
+        let q = FE::group_order();
+        let (a_bn, b_bn) = (a.to_bigint(), b.to_bigint());
+        assert_eq!(sum.to_bigint(), BigInt::mod_add(&a_bn, &b_bn, q));
+        assert_eq!(product.to_bigint(), BigInt::mod_mul(&a_bn, &b_bn, q));
+        assert_eq!(difference.to_bigint(), BigInt::mod_sub(&a_bn, &b_bn, q));
+        assert_eq!(
+            negation.to_bigint(),
+            BigInt::mod_sub(&BigInt::zero(), &a_bn, q)
+        );
+        assert_eq!(inverse.to_bigint(), BigInt::mod_inv(&a_bn, q).unwrap());
+        assert_eq!(deserialized, a);
+        assert_eq!(
+            reduced.to_bigint(),
+            BigInt::from_bytes(&[0xff; 32]).modulus(q)
+        );
+        assert!(random.to_bigint() < *q);
+    }
 }
@@ -0,0 +1,214 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use std::fmt;
+
+use crate::BigInt;
+
+use super::traits::{DeserializationError, ECScalar};
+use super::wrappers::Scalar;
+use super::Curve;
+
+/// Arithmetic of prime field
+///
+/// Exposes scalar field of the curve independently of point operations, so that generic field
+/// math (polynomials, FFT, etc.) can be written once and used with any curve. The trait is
+/// implemented by every [ECScalar] and by [Scalar] wrapper:
+///
+/// ```rust
+/// use curv::elliptic::curves::field::PrimeField;
+/// use curv::elliptic::curves::{Scalar, Secp256k1};
+///
+/// /// Evaluates polynomial given by coefficients starting from the constant term at `x`
+/// fn horner<F: PrimeField>(coefficients: &[F], x: &F) -> F {
+///     coefficients
+///         .iter()
+///         .rev()
+///         .fold(F::zero(), |acc, c| PrimeField::add(&PrimeField::mul(&acc, x), c))
+/// }
+///
+/// let coefficients = [Scalar::<Secp256k1>::from(1), Scalar::from(2), Scalar::from(3)];
+/// assert_eq!(horner(&coefficients, &Scalar::from(2)), Scalar::from(17));
+/// ```
+///
+/// Methods have the same names as methods of [ECScalar], so the trait is deliberately not
+/// re-exported from [curves](super) (importing `curves::*` brings only [ECScalar] into scope). If
+/// both traits are imported, methods need to be called in fully qualified form (e.g.
+/// `PrimeField::add(&a, &b)`).
+pub trait PrimeField: Clone + PartialEq + fmt::Debug + Sized {
+    /// Returns field modulus (it's equal to the group order)
+    fn modulus() -> &'static BigInt;
+    /// Constructs additive identity
+    fn zero() -> Self;
+    /// Constructs multiplicative identity
+    fn one() -> Self;
+    /// Calculates `(self + other) mod p`
+    fn add(&self, other: &Self) -> Self;
+    /// Calculates `(self - other) mod p`
+    fn sub(&self, other: &Self) -> Self;
+    /// Calculates `-self mod p`
+    fn neg(&self) -> Self;
+    /// Calculates `(self * other) mod p`
+    fn mul(&self, other: &Self) -> Self;
+    /// Calculates `self^-1 mod p`, returns None if self equals to zero
+    fn inv(&self) -> Option<Self>;
+    /// Calculates `self^exponent mod p`, see [ECScalar::pow_bigint]
+    ///
+    /// ## Panics
+    /// Panics if exponent is negative
+    fn pow_bigint(&self, exponent: &BigInt) -> Self;
+    /// Deserializes field element, see [ECScalar::deserialize]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError>;
+    /// Serializes field element, see [ECScalar::serialize]
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+impl<S: ECScalar> PrimeField for S {
+    fn modulus() -> &'static BigInt {
+        S::group_order()
+    }
+    fn zero() -> Self {
+        ECScalar::zero()
+    }
+    fn one() -> Self {
+        ECScalar::one()
+    }
+    fn add(&self, other: &Self) -> Self {
+        ECScalar::add(self, other)
+    }
+    fn sub(&self, other: &Self) -> Self {
+        ECScalar::sub(self, other)
+    }
+    fn neg(&self) -> Self {
+        ECScalar::neg(self)
+    }
+    fn mul(&self, other: &Self) -> Self {
+        ECScalar::mul(self, other)
+    }
+    fn inv(&self) -> Option<Self> {
+        ECScalar::invert(self)
+    }
+    fn pow_bigint(&self, exponent: &BigInt) -> Self {
+        ECScalar::pow_bigint(self, exponent)
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        ECScalar::deserialize(bytes)
+    }
+    fn to_bytes(&self) -> Vec<u8> {
+        ECScalar::serialize(self).to_vec()
+    }
+}
+
+impl<E: Curve> PrimeField for Scalar<E> {
+    fn modulus() -> &'static BigInt {
+        Scalar::<E>::group_order()
+    }
+    fn zero() -> Self {
+        Scalar::zero()
+    }
+    fn one() -> Self {
+        Scalar::from_raw(ECScalar::one())
+    }
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+    fn neg(&self) -> Self {
+        -self
+    }
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+    fn inv(&self) -> Option<Self> {
+        self.invert()
+    }
+    fn pow_bigint(&self, exponent: &BigInt) -> Self {
+        Scalar::from_raw(ECScalar::pow_bigint(self.as_raw(), exponent))
+    }
+    fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        Ok(Scalar::from_raw(ECScalar::deserialize(bytes)?))
+    }
+    fn to_bytes(&self) -> Vec<u8> {
+        ECScalar::serialize(self.as_raw()).to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arithmetic::traits::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(prime_field_agrees_with_ec_scalar);
+    fn prime_field_agrees_with_ec_scalar<E: Curve>() {
+        let (a, b) = (E::Scalar::random(), E::Scalar::random());
+
+        assert_eq!(
+            <E::Scalar as PrimeField>::modulus(),
+            E::Scalar::group_order()
+        );
+        assert_eq!(
+            <E::Scalar as PrimeField>::zero(),
+            <E::Scalar as ECScalar>::zero()
+        );
+        assert_eq!(
+            <E::Scalar as PrimeField>::one(),
+            <E::Scalar as ECScalar>::one()
+        );
+        assert_eq!(PrimeField::add(&a, &b), ECScalar::add(&a, &b));
+        assert_eq!(PrimeField::sub(&a, &b), ECScalar::sub(&a, &b));
+        assert_eq!(PrimeField::neg(&a), ECScalar::neg(&a));
+        assert_eq!(PrimeField::mul(&a, &b), ECScalar::mul(&a, &b));
+        assert_eq!(PrimeField::inv(&a), ECScalar::invert(&a));
+        assert_eq!(PrimeField::inv(&<E::Scalar as ECScalar>::zero()), None);
+        assert_eq!(PrimeField::to_bytes(&a), ECScalar::serialize(&a).to_vec());
+        assert_eq!(
+            <E::Scalar as PrimeField>::from_bytes(&PrimeField::to_bytes(&a)).unwrap(),
+            a
+        );
+        assert!(<E::Scalar as PrimeField>::from_bytes(&[1, 2, 3]).is_err());
+
+        // Scalar wrapper agrees with raw scalars
+        let (a_w, b_w) = (
+            Scalar::<E>::from_raw(a.clone()),
+            Scalar::<E>::from_raw(b.clone()),
+        );
+        assert_eq!(
+            PrimeField::add(&a_w, &b_w).into_raw(),
+            PrimeField::add(&a, &b)
+        );
+        assert_eq!(
+            PrimeField::mul(&a_w, &b_w).into_raw(),
+            PrimeField::mul(&a, &b)
+        );
+        assert_eq!(
+            PrimeField::inv(&a_w).unwrap().into_raw(),
+            PrimeField::inv(&a).unwrap()
+        );
+        assert_eq!(<Scalar<E> as PrimeField>::one(), Scalar::from(1));
+        assert_eq!(PrimeField::to_bytes(&a_w), PrimeField::to_bytes(&a));
+    }
+
+    test_for_all_curves!(prime_field_pow_bigint);
+    fn prime_field_pow_bigint<E: Curve>() {
+        let a = Scalar::<E>::random();
+        assert_eq!(PrimeField::pow_bigint(&a, &BigInt::zero()), Scalar::from(1));
+        assert_eq!(PrimeField::pow_bigint(&a, &BigInt::from(1)), a);
+        assert_eq!(PrimeField::pow_bigint(&a, &BigInt::from(3)), &a * &a * &a);
+
+        // Fermat's little theorem
+        let q = Scalar::<E>::group_order();
+        assert_eq!(PrimeField::pow_bigint(&a, &(q - 1)), Scalar::from(1));
+        assert_eq!(PrimeField::pow_bigint(&a, &(q - 2)), a.invert().unwrap());
+        assert_eq!(
+            PrimeField::pow_bigint(a.as_raw(), &(q - 2)),
+            a.as_raw().invert().unwrap()
+        );
+    }
+}
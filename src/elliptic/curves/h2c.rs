@@ -0,0 +1,92 @@
+//! Prime field helpers shared by BigInt-based hash to curve implementations (see RFC 9380)
+
+use digest::{BlockInput, Digest};
+
+use crate::arithmetic::*;
+use crate::cryptographic_primitives::hashing::expand_message_xmd;
+
+use super::traits::ECScalar;
+
+/// Generic implementation of [ECScalar::from_hash]
+pub(super) fn hash_to_scalar<H: Digest + BlockInput, S: ECScalar>(data: &[&[u8]], dst: &[u8]) -> S {
+    let mut msg = Vec::with_capacity(data.iter().map(|d| 8 + d.len()).sum());
+    for d in data {
+        msg.extend_from_slice(&(d.len() as u64).to_be_bytes());
+        msg.extend_from_slice(d);
+    }
+    let uniform_bytes = expand_message_xmd::<H>(&msg, dst, 64);
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(&uniform_bytes);
+    S::from_bytes_wide(&wide)
+}
+
+/// Computes `a^-1 mod p`, or zero if `a` is zero
+pub(super) fn inv0(a: &BigInt, p: &BigInt) -> BigInt {
+    BigInt::mod_inv(a, p).unwrap_or_else(BigInt::zero)
+}
+
+/// Checks whether `a` is a quadratic residue modulo `p` (zero is considered a square)
+pub(super) fn is_square(a: &BigInt, p: &BigInt) -> bool {
+    let exp = (p - BigInt::one()) >> 1;
+    let legendre = BigInt::mod_pow(a, &exp, p);
+    legendre.is_zero() || legendre == BigInt::one()
+}
+
+/// `sgn0` function for prime fields: returns `true` if `a` is odd
+pub(super) fn sgn0(a: &BigInt) -> bool {
+    a.is_odd()
+}
+
+/// `(a * b + c) mod p`
+pub(super) fn mul_add(a: &BigInt, b: &BigInt, c: &BigInt, p: &BigInt) -> BigInt {
+    BigInt::mod_add(&BigInt::mod_mul(a, b, p), c, p)
+}
+
+/// Evaluates a polynomial with given coefficients (lowest degree goes first) at `x` modulo `p`
+pub(super) fn eval_poly(coefs: &[BigInt], x: &BigInt, p: &BigInt) -> BigInt {
+    coefs
+        .iter()
+        .rev()
+        .fold(BigInt::zero(), |acc, coef| mul_add(&acc, x, coef, p))
+}
+
+/// Simplified Shallue-van de Woestijne-Ulas map to curve `y^2 = x^3 + Ax + B` (RFC 9380, Section 6.6.2)
+///
+/// Requires `A ≠ 0`, `B ≠ 0`, and `p = 3 mod 4`.
+pub(super) fn map_to_curve_simple_swu(
+    u: &BigInt,
+    a: &BigInt,
+    b: &BigInt,
+    z: &BigInt,
+    p: &BigInt,
+) -> (BigInt, BigInt) {
+    // g(x) = x^3 + Ax + B = (x^2 + A)x + B
+    let g = |x: &BigInt| mul_add(&mul_add(x, x, a, p), x, b, p);
+
+    let z_u2 = BigInt::mod_mul(z, &BigInt::mod_mul(u, u, p), p);
+    let tv1 = inv0(&mul_add(&z_u2, &z_u2, &z_u2, p), p);
+    let x1 = if tv1.is_zero() {
+        BigInt::mod_mul(b, &inv0(&BigInt::mod_mul(z, a, p), p), p)
+    } else {
+        let minus_b_over_a =
+            BigInt::mod_sub(&BigInt::zero(), &BigInt::mod_mul(b, &inv0(a, p), p), p);
+        BigInt::mod_mul(
+            &minus_b_over_a,
+            &BigInt::mod_add(&BigInt::one(), &tv1, p),
+            p,
+        )
+    };
+    let gx1 = g(&x1);
+    let (x, gx) = if is_square(&gx1, p) {
+        (x1, gx1)
+    } else {
+        let x2 = BigInt::mod_mul(&z_u2, &x1, p);
+        let gx2 = g(&x2);
+        (x2, gx2)
+    };
+    let mut y = BigInt::mod_pow(&gx, &((p + BigInt::one()) >> 2), p);
+    if sgn0(u) != sgn0(&y) {
+        y = BigInt::mod_sub(&BigInt::zero(), &y, p);
+    }
+    (x, y)
+}
@@ -79,6 +79,8 @@
 pub mod bls12_381;
 pub mod curve_ristretto;
 pub mod ed25519;
+pub mod field;
+mod h2c;
 pub mod p256;
 pub mod secp256_k1;
 
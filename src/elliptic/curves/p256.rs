@@ -10,12 +10,16 @@ use p256::elliptic_curve::Field;
 use p256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar};
 
 use generic_array::GenericArray;
-use rand::{thread_rng, Rng};
+use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
+use super::h2c::{hash_to_scalar, map_to_curve_simple_swu};
 use super::traits::{ECPoint, ECScalar};
 use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::hashing::hash_to_field;
 use crate::elliptic::curves::{Curve, DeserializationError, NotOnCurve, PointCoords};
 use crate::BigInt;
 
@@ -27,7 +31,7 @@ lazy_static::lazy_static! {
         g[0] = 0x04;
         g[1..33].copy_from_slice(&BASE_POINT2_X);
         g[33..].copy_from_slice(&BASE_POINT2_Y);
-        EncodedPoint::from_bytes(&g).unwrap()
+        EncodedPoint::from_bytes(g).unwrap()
     };
 
     static ref BASE_POINT2: Secp256r1Point = Secp256r1Point {
@@ -39,6 +43,17 @@ lazy_static::lazy_static! {
         purpose: "generator",
         ge: AffinePoint::generator()
     };
+
+    static ref GENERATOR_TABLE: GeneratorTable = GeneratorTable::new();
+
+    static ref FIELD_MODULUS: BigInt = BigInt::from_hex(
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+    ).unwrap();
+    static ref CURVE_A: BigInt = &*FIELD_MODULUS - BigInt::from(3);
+    static ref CURVE_B: BigInt = BigInt::from_hex(
+        "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"
+    ).unwrap();
+    static ref SSWU_Z: BigInt = &*FIELD_MODULUS - BigInt::from(10);
 }
 
 /* X coordinate of a point of unknown discrete logarithm.
@@ -93,11 +108,10 @@ impl ECScalar for Secp256r1Scalar {
 
     type ScalarLength = typenum::U32;
 
-    fn random() -> Secp256r1Scalar {
-        let mut rng = thread_rng();
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Secp256r1Scalar {
         let scalar = loop {
             let mut bytes = FieldBytes::default();
-            rng.fill(&mut bytes[..]);
+            rng.fill_bytes(&mut bytes[..]);
             let element = Scalar::from_repr(bytes);
             if bool::from(element.is_some()) {
                 break element.unwrap();
@@ -116,10 +130,35 @@ impl ECScalar for Secp256r1Scalar {
         }
     }
 
+    fn one() -> Secp256r1Scalar {
+        Secp256r1Scalar {
+            purpose: "one",
+            fe: Scalar::ONE.into(),
+        }
+    }
+
+    fn from_u64(n: u64) -> Secp256r1Scalar {
+        Secp256r1Scalar {
+            purpose: "from_u64",
+            fe: Scalar::from(n).into(),
+        }
+    }
+
     fn is_zero(&self) -> bool {
         bool::from(self.fe.is_zero())
     }
 
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.fe.ct_eq(&other.fe)
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Secp256r1Scalar {
+            purpose: "conditional_select",
+            fe: SK::conditional_select(&a.fe, &b.fe, choice).into(),
+        }
+    }
+
     fn from_bigint(n: &BigInt) -> Secp256r1Scalar {
         let curve_order = Secp256r1Scalar::group_order();
         let n_reduced = n
@@ -156,6 +195,18 @@ impl ECScalar for Secp256r1Scalar {
         }
     }
 
+    fn deserialize_reduced(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let bytes = <[u8; 32]>::try_from(bytes).or(Err(DeserializationError))?;
+        Ok(Secp256r1Scalar {
+            purpose: "deserialize_reduced",
+            fe: Scalar::from_be_bytes_reduced(FieldBytes::from(bytes)).into(),
+        })
+    }
+
+    fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self {
+        hash_to_scalar::<Sha256, Self>(data, dst)
+    }
+
     fn add(&self, other: &Self) -> Secp256r1Scalar {
         Secp256r1Scalar {
             purpose: "add",
@@ -248,6 +299,17 @@ impl ECPoint for Secp256r1Point {
         bool::from(self.ge.is_identity())
     }
 
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.ge.ct_eq(&other.ge)
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Secp256r1Point {
+            purpose: "conditional_select",
+            ge: PK::conditional_select(&a.ge, &b.ge, choice),
+        }
+    }
+
     fn generator() -> &'static Secp256r1Point {
         &GENERATOR
     }
@@ -256,6 +318,10 @@ impl ECPoint for Secp256r1Point {
         &BASE_POINT2
     }
 
+    fn field_modulus() -> &'static BigInt {
+        &FIELD_MODULUS
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<Secp256r1Point, NotOnCurve> {
         let x_arr = x.to_bytes_array::<32>().ok_or(NotOnCurve)?;
         let y_arr = y.to_bytes_array::<32>().ok_or(NotOnCurve)?;
@@ -331,11 +397,34 @@ impl ECPoint for Secp256r1Point {
         }
     }
 
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Secp256r1Point {
+        let p = &*FIELD_MODULUS;
+        let u = hash_to_field::<Sha256>(msg, dst, 2, p, 48);
+        let (x0, y0) = map_to_curve_simple_swu(&u[0], &CURVE_A, &CURVE_B, &SSWU_Z, p);
+        let (x1, y1) = map_to_curve_simple_swu(&u[1], &CURVE_A, &CURVE_B, &SSWU_Z, p);
+        let q0 = Self::from_coords(&x0, &y0).expect("SSWU outputs a point on P-256");
+        let q1 = Self::from_coords(&x1, &y1).expect("SSWU outputs a point on P-256");
+        Secp256r1Point {
+            purpose: "hash_to_curve",
+            ge: q0.add_point(&q1).ge,
+        }
+    }
+
     fn check_point_order_equals_group_order(&self) -> bool {
         // This curve has cofactor=1 => any nonzero point has order GROUP_ORDER
         !self.is_zero()
     }
 
+    fn is_in_prime_subgroup(&self) -> bool {
+        // The whole group of curve points has prime order
+        true
+    }
+
+    fn clear_cofactor(&self) -> Secp256r1Point {
+        // Cofactor=1, multiplying at it is a no-op
+        *self
+    }
+
     fn scalar_mul(&self, fe: &Self::Scalar) -> Secp256r1Point {
         Secp256r1Point {
             purpose: "scalar_mul",
@@ -343,6 +432,50 @@ impl ECPoint for Secp256r1Point {
         }
     }
 
+    fn generator_mul(fe: &Self::Scalar) -> Secp256r1Point {
+        Secp256r1Point {
+            purpose: "generator_mul",
+            ge: GENERATOR_TABLE.mul(&fe.fe).to_affine(),
+        }
+    }
+
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> Secp256r1Point {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        // Straus' method with 4-bit window: every point gets a table of its 16 first multiples,
+        // and all the points share the same chain of doublings
+        let tables: Vec<[ProjectivePoint; 16]> = points
+            .iter()
+            .map(|p| {
+                let mut table = [ProjectivePoint::IDENTITY; 16];
+                for i in 1..16 {
+                    table[i] = table[i - 1] + p.ge;
+                }
+                table
+            })
+            .collect();
+        let scalars: Vec<FieldBytes> = scalars.iter().map(|s| s.fe.to_repr()).collect();
+
+        let mut acc = ProjectivePoint::IDENTITY;
+        for i in 0..64 {
+            for _ in 0..4 {
+                acc = acc.double();
+            }
+            let shift = if i % 2 == 0 { 4 } else { 0 };
+            for (table, s) in tables.iter().zip(&scalars) {
+                acc += table[usize::from((s[i / 2] >> shift) & 0xf)];
+            }
+        }
+
+        Secp256r1Point {
+            purpose: "multi_scalar_mul",
+            ge: acc.to_affine(),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> Self {
         Secp256r1Point {
             purpose: "add_point",
@@ -387,6 +520,44 @@ impl Zeroize for Secp256r1Point {
     }
 }
 
+/// Precomputed multiples of the generator: `table[i][j] = j·16^i·G`
+///
+/// Scalar is split into 64 4-bit windows `k = Σ k_i·16^i`, then `k·G = Σ table[i][k_i]`, which
+/// takes 64 additions and no doublings. Table entries are selected in constant time.
+struct GeneratorTable([[AffinePoint; 16]; 64]);
+
+impl GeneratorTable {
+    fn new() -> Self {
+        let mut table = [[AffinePoint::identity(); 16]; 64];
+        let mut base = ProjectivePoint::GENERATOR;
+        for window in table.iter_mut() {
+            let mut multiple = ProjectivePoint::IDENTITY;
+            for entry in window.iter_mut().skip(1) {
+                multiple += base;
+                *entry = multiple.to_affine();
+            }
+            // base = 16^{i+1}·G
+            base = multiple + base;
+        }
+        GeneratorTable(table)
+    }
+
+    fn mul(&self, scalar: &Scalar) -> ProjectivePoint {
+        let bytes = scalar.to_bytes();
+        let mut result = ProjectivePoint::IDENTITY;
+        for (i, window) in self.0.iter().enumerate() {
+            let byte = bytes[31 - i / 2];
+            let nibble = if i % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+            let mut entry = AffinePoint::identity();
+            for (j, candidate) in (0u8..).zip(window) {
+                entry.conditional_assign(candidate, j.ct_eq(&nibble));
+            }
+            result += entry;
+        }
+        result
+    }
+}
+
 impl PartialEq for Secp256r1Point {
     fn eq(&self, other: &Self) -> bool {
         self.ge == other.ge
@@ -401,6 +572,35 @@ mod tests {
 
     use super::{ECPoint, GE};
 
+    #[test]
+    fn point_from_bytes_rejects_invalid_uncompressed_encodings() {
+        use crate::elliptic::curves::{Point, PointFromBytesError, Secp256r1};
+
+        let valid = GE::generator().serialize_uncompressed().to_vec();
+        let is_deserialization_error = |bytes: &[u8]| {
+            matches!(
+                Point::<Secp256r1>::from_bytes(bytes),
+                Err(PointFromBytesError::DeserializationError)
+            )
+        };
+        assert!(Point::<Secp256r1>::from_bytes(&valid).is_ok());
+
+        // y coordinate is changed, the point is not on curve
+        let mut bytes = valid.clone();
+        bytes[64] ^= 1;
+        assert!(is_deserialization_error(&bytes));
+        // x coordinate exceeds field modulus
+        let mut bytes = valid.clone();
+        bytes[1..33].copy_from_slice(&[0xff; 32]);
+        assert!(is_deserialization_error(&bytes));
+        // wrong header
+        let mut bytes = valid.clone();
+        bytes[0] = 0x02;
+        assert!(is_deserialization_error(&bytes));
+        // wrong length
+        assert!(is_deserialization_error(&valid[..64]));
+    }
+
     #[test]
     fn test_base_point2() {
         /* Show that base_point2() is returning a point of unknown discrete logarithm.
@@ -425,4 +625,32 @@ mod tests {
             base_point2
         );
     }
+
+    // Test vectors from RFC 9380, Appendix J.1.1
+    #[test]
+    fn hash_to_curve_test_vectors() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+        let vectors: &[(&[u8], &str, &str)] = &[
+            (
+                b"",
+                "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+            ),
+            (
+                b"abc",
+                "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+            ),
+            (
+                b"abcdef0123456789",
+                "65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+                "cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3",
+            ),
+        ];
+        for (msg, x, y) in vectors {
+            let point = GE::hash_to_curve(msg, DST);
+            assert_eq!(point.x_coord(), Some(BigInt::from_hex(x).unwrap()));
+            assert_eq!(point.y_coord(), Some(BigInt::from_hex(y).unwrap()));
+        }
+    }
 }
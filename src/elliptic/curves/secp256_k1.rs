@@ -16,26 +16,33 @@
 // The Public Key codec: Point <> SecretKey
 //
 
+use std::convert::TryFrom;
 use std::ops;
 use std::ops::Deref;
 use std::ptr;
 use std::sync::atomic;
 
 use generic_array::GenericArray;
+use rand::{CryptoRng, RngCore};
 use secp256k1::constants::{
     self, GENERATOR_X, GENERATOR_Y, SECRET_KEY_SIZE, UNCOMPRESSED_PUBLIC_KEY_SIZE,
 };
 use secp256k1::{PublicKey, SecretKey, SECP256K1};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 use zeroize::{Zeroize, Zeroizing};
 
 use crate::arithmetic::*;
 
+use super::h2c::hash_to_scalar;
 use super::traits::*;
+use super::{Point, PointFromBytesError, ZeroPointError};
 
 lazy_static::lazy_static! {
     static ref CURVE_ORDER: BigInt = BigInt::from_bytes(&constants::CURVE_ORDER);
 
+    static ref FIELD_MODULUS: BigInt = BigInt::from_bytes(&constants::FIELD_SIZE);
+
     static ref GENERATOR_UNCOMRESSED: [u8; 65] = {
         let mut g = [0u8; 65];
         g[0] = 0x04;
@@ -109,6 +116,17 @@ impl ops::DerefMut for PK {
     }
 }
 
+/// Parses public key in compressed (33 bytes) or uncompressed (65 bytes) form
+impl TryFrom<&[u8]> for PK {
+    type Error = DeserializationError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        PublicKey::from_slice(bytes)
+            .map(PK)
+            .map_err(|_| DeserializationError)
+    }
+}
+
 impl Zeroize for SK {
     fn zeroize(&mut self) {
         let sk = self.0.as_mut_ptr();
@@ -160,8 +178,15 @@ impl ECScalar for Secp256k1Scalar {
 
     type ScalarLength = typenum::U32;
 
-    fn random() -> Secp256k1Scalar {
-        let sk = SK(SecretKey::new(&mut rand_legacy::thread_rng()));
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Secp256k1Scalar {
+        let sk = loop {
+            let mut bytes = [0u8; SECRET_KEY_SIZE];
+            rng.fill_bytes(&mut bytes);
+            // Rejects zero and integers exceeding group order
+            if let Ok(sk) = SecretKey::from_slice(&bytes) {
+                break SK(sk);
+            }
+        };
         Secp256k1Scalar {
             purpose: "random",
             fe: Zeroizing::new(Some(sk)),
@@ -175,6 +200,30 @@ impl ECScalar for Secp256k1Scalar {
         }
     }
 
+    fn one() -> Secp256k1Scalar {
+        Secp256k1Scalar {
+            purpose: "one",
+            fe: Zeroizing::new(Some(SK(secp256k1::key::ONE_KEY))),
+        }
+    }
+
+    fn from_u64(n: u64) -> Secp256k1Scalar {
+        if n == 0 {
+            return Secp256k1Scalar {
+                purpose: "from_u64",
+                fe: Self::zero().fe,
+            };
+        }
+        let mut bytes = [0u8; SECRET_KEY_SIZE];
+        bytes[SECRET_KEY_SIZE - 8..].copy_from_slice(&n.to_be_bytes());
+        Secp256k1Scalar {
+            purpose: "from_u64",
+            fe: Zeroizing::new(Some(SK(
+                SecretKey::from_slice(&bytes).expect("n is in (0, order) and exactly 32 bytes")
+            ))),
+        }
+    }
+
     fn is_zero(&self) -> bool {
         self.fe.is_none()
     }
@@ -227,6 +276,23 @@ impl ECScalar for Secp256k1Scalar {
         })
     }
 
+    fn deserialize_reduced(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != SECRET_KEY_SIZE {
+            return Err(DeserializationError);
+        }
+        // Reduce through field arithmetic rather than `BigInt`, which isn't constant-time
+        let mut wide = Zeroizing::new([0u8; 64]);
+        wide[64 - SECRET_KEY_SIZE..].copy_from_slice(bytes);
+        Ok(Secp256k1Scalar {
+            purpose: "deserialize_reduced",
+            fe: Self::from_bytes_wide(&wide).fe,
+        })
+    }
+
+    fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self {
+        hash_to_scalar::<Sha256, Self>(data, dst)
+    }
+
     fn add(&self, other: &Self) -> Secp256k1Scalar {
         let fe = match (&*self.fe, &*other.fe) {
             (None, right) => right.clone(),
@@ -282,11 +348,15 @@ impl ECScalar for Secp256k1Scalar {
     }
 
     fn invert(&self) -> Option<Secp256k1Scalar> {
-        let n = self.to_bigint();
-        let n_inv = BigInt::mod_inv(&n, Self::group_order());
-        n_inv.map(|i| Secp256k1Scalar {
+        if self.is_zero() {
+            return None;
+        }
+        // Fermat's little theorem: s^-1 = s^(q-2). Exponent is public, and multiplications are
+        // done by libsecp256k1 in constant time, unlike BigInt::mod_inv
+        let result = self.pow_bigint(&(Self::group_order() - 2));
+        Some(Secp256k1Scalar {
             purpose: "invert",
-            fe: Self::from_bigint(&i).fe,
+            fe: result.fe,
         })
     }
 
@@ -342,14 +412,19 @@ impl ECPoint for Secp256k1Point {
         &BASE_POINT2
     }
 
+    fn field_modulus() -> &'static BigInt {
+        &FIELD_MODULUS
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<Self, NotOnCurve> {
-        let vec_x = x.to_bytes();
-        let vec_y = y.to_bytes();
         const COOR_SIZE: usize = (UNCOMPRESSED_PUBLIC_KEY_SIZE - 1) / 2;
+        // Coordinates that don't fit into 32 bytes can't be on curve
+        let x_arr = x.to_bytes_array::<COOR_SIZE>().ok_or(NotOnCurve)?;
+        let y_arr = y.to_bytes_array::<COOR_SIZE>().ok_or(NotOnCurve)?;
         let mut point = [0u8; UNCOMPRESSED_PUBLIC_KEY_SIZE];
         point[0] = 0x04;
-        point[1 + COOR_SIZE - vec_x.len()..1 + COOR_SIZE].copy_from_slice(&vec_x);
-        point[1 + (2 * COOR_SIZE) - vec_y.len()..].copy_from_slice(&vec_y);
+        point[1..1 + COOR_SIZE].copy_from_slice(&x_arr);
+        point[1 + COOR_SIZE..].copy_from_slice(&y_arr);
 
         debug_assert_eq!(x, &BigInt::from_bytes(&point[1..1 + COOR_SIZE]));
         debug_assert_eq!(y, &BigInt::from_bytes(&point[1 + COOR_SIZE..]));
@@ -428,11 +503,25 @@ impl ECPoint for Secp256k1Point {
         }
     }
 
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Secp256k1Point {
+        hash_to_curve::hash_to_curve_sswu(msg, dst)
+    }
+
     fn check_point_order_equals_group_order(&self) -> bool {
         // This curve has cofactor=1 => any nonzero point has order GROUP_ORDER
         !self.is_zero()
     }
 
+    fn is_in_prime_subgroup(&self) -> bool {
+        // The whole group of curve points has prime order
+        true
+    }
+
+    fn clear_cofactor(&self) -> Secp256k1Point {
+        // Cofactor=1, multiplying at it is a no-op
+        *self
+    }
+
     fn scalar_mul(&self, scalar: &Self::Scalar) -> Secp256k1Point {
         let mut res = *self;
         res.scalar_mul_assign(scalar);
@@ -453,6 +542,31 @@ impl ECPoint for Secp256k1Point {
         }
     }
 
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> Secp256k1Point {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        // libsecp256k1 doesn't expose multiscalar multiplication, but we can at least sum up
+        // all the products at once instead of normalizing every intermediate sum
+        let products: Vec<PK> = points
+            .iter()
+            .zip(scalars)
+            .filter_map(|(p, s)| p.scalar_mul(s).ge)
+            .collect();
+        let ge = if products.is_empty() {
+            None
+        } else {
+            let keys: Vec<&PublicKey> = products.iter().map(|pk| &pk.0).collect();
+            PublicKey::combine_keys(&keys).ok().map(PK) // sum might be a point at infinity
+        };
+        Secp256k1Point {
+            purpose: "multi_scalar_mul",
+            ge,
+        }
+    }
+
     fn add_point(&self, other: &Self) -> Secp256k1Point {
         let ge = match (&self.ge, &other.ge) {
             (None, right) => *right,
@@ -516,11 +630,152 @@ impl Zeroize for Secp256k1Point {
     }
 }
 
+/// DER-encoded `AlgorithmIdentifier` of secp256k1 public key: `id-ecPublicKey` with `secp256k1`
+/// named curve parameters
+const SPKI_ALGORITHM_IDENTIFIER: [u8; 18] = [
+    0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b, 0x81, 0x04,
+    0x00, 0x0a,
+];
+
+impl Point<Secp256k1> {
+    /// Encodes a public key as DER `SubjectPublicKeyInfo` structure (as defined in [RFC 5480]),
+    /// the same format as OpenSSL outputs
+    ///
+    /// `compressed` specifies in which form the point is encoded into `subjectPublicKey` bit string.
+    /// Returns error if point is zero, as it can't be represented in this format.
+    ///
+    /// [RFC 5480]: https://www.rfc-editor.org/rfc/rfc5480#section-2
+    pub fn to_der(&self, compressed: bool) -> Result<Vec<u8>, ZeroPointError> {
+        self.ensure_nonzero()?;
+        let key = self.to_bytes(compressed);
+        let bit_string_len = key.len() + 1;
+        let content_len = SPKI_ALGORITHM_IDENTIFIER.len() + 2 + bit_string_len;
+
+        let mut der = Vec::with_capacity(2 + content_len);
+        der.extend_from_slice(&[0x30, content_len as u8]);
+        der.extend_from_slice(&SPKI_ALGORITHM_IDENTIFIER);
+        // BIT STRING with zero unused bits
+        der.extend_from_slice(&[0x03, bit_string_len as u8, 0x00]);
+        der.extend_from_slice(&key);
+        Ok(der)
+    }
+
+    /// Derives Ethereum address of a public key
+    ///
+    /// Address is the last 20 bytes of `keccak256` hash of uncompressed point encoding without
+    /// its `0x04` prefix. Returns error if point is zero.
+    pub fn to_eth_address(&self) -> Result<[u8; 20], ZeroPointError> {
+        use sha3::{Digest, Keccak256};
+
+        self.ensure_nonzero()?;
+        let hash = Keccak256::digest(&self.to_bytes(false)[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        Ok(address)
+    }
+
+    /// Decodes a public key from DER `SubjectPublicKeyInfo` structure
+    ///
+    /// Accepts both compressed and uncompressed points. Returns error if encoding is not a strict
+    /// DER, if it specifies algorithm or curve other than secp256k1, or if the point is not valid.
+    pub fn from_der(bytes: &[u8]) -> Result<Self, PointFromBytesError> {
+        let header_len = 2 + SPKI_ALGORITHM_IDENTIFIER.len() + 3;
+        if bytes.len() <= header_len
+            || bytes[0] != 0x30
+            || usize::from(bytes[1]) != bytes.len() - 2
+            || bytes[2..20] != SPKI_ALGORITHM_IDENTIFIER
+            || bytes[20] != 0x03
+            || usize::from(bytes[21]) != bytes.len() - 22
+            || bytes[22] != 0x00
+        {
+            return Err(PointFromBytesError::DeserializationError);
+        }
+        let point = Point::from_bytes(&bytes[header_len..])?;
+        if point.is_zero() {
+            return Err(PointFromBytesError::DeserializationError);
+        }
+        Ok(point)
+    }
+}
+
 pub mod hash_to_curve {
+    use sha2::Sha256;
+
+    use crate::cryptographic_primitives::hashing::hash_to_field;
+    use crate::elliptic::curves::h2c::{eval_poly, inv0, map_to_curve_simple_swu};
     use crate::elliptic::curves::wrappers::{Point, Scalar};
     use crate::{arithmetic::traits::*, BigInt};
 
-    use super::Secp256k1;
+    use super::{ECPoint, Secp256k1, Secp256k1Point};
+
+    lazy_static::lazy_static! {
+        static ref P: BigInt = BigInt::from_hex(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+        ).unwrap();
+        // Curve E' isogenous to secp256k1: y^2 = x^3 + A'x + B'
+        static ref ISO_A: BigInt = BigInt::from_hex(
+            "3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533"
+        ).unwrap();
+        static ref ISO_B: BigInt = BigInt::from(1771);
+        static ref Z: BigInt = &*P - BigInt::from(11);
+        // Coefficients of 3-isogeny map E' -> secp256k1 (RFC 9380, Appendix E.1)
+        static ref X_NUM: [BigInt; 4] = hex_array([
+            "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+            "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+            "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+            "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+        ]);
+        static ref X_DEN: [BigInt; 3] = hex_array([
+            "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+            "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+            "01",
+        ]);
+        static ref Y_NUM: [BigInt; 4] = hex_array([
+            "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+            "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+            "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+            "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+        ]);
+        static ref Y_DEN: [BigInt; 4] = hex_array([
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+            "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+            "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+            "01",
+        ]);
+    }
+
+    fn hex_array<const N: usize>(hex: [&str; N]) -> [BigInt; N] {
+        hex.map(|h| BigInt::from_hex(h).unwrap())
+    }
+
+    /// Hashes a message to secp256k1 point following `secp256k1_XMD:SHA-256_SSWU_RO_` suite
+    /// defined in RFC 9380
+    ///
+    /// Computations are not constant time.
+    pub(super) fn hash_to_curve_sswu(msg: &[u8], dst: &[u8]) -> Secp256k1Point {
+        let u = hash_to_field::<Sha256>(msg, dst, 2, &P, 48);
+        let q0 = map_to_curve(&u[0]);
+        let q1 = map_to_curve(&u[1]);
+        q0.add_point(&q1)
+    }
+
+    /// Simplified SWU map to E' composed with 3-isogeny map to secp256k1
+    fn map_to_curve(u: &BigInt) -> Secp256k1Point {
+        let p = &*P;
+        let (x, y) = map_to_curve_simple_swu(u, &ISO_A, &ISO_B, &Z, p);
+
+        let x_den = eval_poly(&*X_DEN, &x, p);
+        let y_den = eval_poly(&*Y_DEN, &x, p);
+        if x_den.is_zero() || y_den.is_zero() {
+            // exceptional case of isogeny map
+            return Secp256k1Point::zero();
+        }
+        let x_num = eval_poly(&*X_NUM, &x, p);
+        let y_num = eval_poly(&*Y_NUM, &x, p);
+        let x = BigInt::mod_mul(&x_num, &inv0(&x_den, p), p);
+        let y = BigInt::mod_mul(&y, &BigInt::mod_mul(&y_num, &inv0(&y_den, p), p), p);
+        Secp256k1Point::from_coords(&x, &y).expect("isogeny map outputs a point on secp256k1")
+    }
 
     /// Takes uniformly distributed bytes and produces secp256k1 point with unknown logarithm
     ///
@@ -548,7 +803,33 @@ pub mod hash_to_curve {
 
     #[cfg(test)]
     mod tests {
-        use super::generate_random_point;
+        use super::{generate_random_point, hash_to_curve_sswu};
+        use crate::arithmetic::traits::*;
+        use crate::elliptic::curves::ECPoint;
+        use crate::BigInt;
+
+        // Test vectors from RFC 9380, Appendix J.8.1
+        #[test]
+        fn hash_to_curve_test_vectors() {
+            const DST: &[u8] = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+            let vectors: &[(&[u8], &str, &str)] = &[
+                (
+                    b"",
+                    "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                    "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+                ),
+                (
+                    b"abc",
+                    "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                    "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+                ),
+            ];
+            for (msg, x, y) in vectors {
+                let point = hash_to_curve_sswu(msg, DST);
+                assert_eq!(point.x_coord(), Some(BigInt::from_hex(x).unwrap()));
+                assert_eq!(point.y_coord(), Some(BigInt::from_hex(y).unwrap()));
+            }
+        }
 
         #[test]
         fn generates_point() {
@@ -598,4 +879,237 @@ mod test {
             base_point2
         );
     }
+
+    #[test]
+    fn from_coords_rejects_oversized_coordinates() {
+        let g = GE::generator();
+        let x = g.x_coord().unwrap();
+        let y = g.y_coord().unwrap();
+        let too_big = BigInt::from(1) << 256;
+
+        assert!(GE::from_coords(&(&x + &too_big), &y).is_err());
+        assert!(GE::from_coords(&x, &(&y + &too_big)).is_err());
+        assert!(GE::from_coords(&x, &y).is_ok());
+    }
+
+    #[test]
+    fn random_scalar_with_seeded_rng_matches_test_vector() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        use crate::elliptic::curves::ECScalar;
+
+        // The first 32 bytes of ChaCha20 keystream are below group order, so they are taken as is
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let scalar = super::Secp256k1Scalar::random_with_rng(&mut rng);
+        assert_eq!(
+            scalar.to_bigint().to_hex(),
+            "b2f7f581d6de3c06a822fd6e7e8265fbc00f8401696a5bdc34f5a6d2ff3f922f"
+        );
+    }
+
+    #[test]
+    fn eth_address_test_vectors() {
+        use crate::elliptic::curves::{Point, Scalar};
+
+        let vectors = [
+            ("01", "7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+            (
+                "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+                "2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            ),
+        ];
+        for (sk, address) in vectors {
+            let sk = Scalar::<super::Secp256k1>::from_bigint(&BigInt::from_hex(sk).unwrap());
+            let pk = Point::generator() * sk;
+            assert_eq!(hex::encode(pk.to_eth_address().unwrap()), address);
+        }
+        assert!(Point::<super::Secp256k1>::zero().to_eth_address().is_err());
+    }
+
+    #[test]
+    fn from_coords_rejects_points_not_on_curve() {
+        let p =
+            BigInt::from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+                .unwrap();
+        let on_curve = |x: &BigInt, y: &BigInt| {
+            BigInt::mod_pow(y, &BigInt::from(2), &p)
+                == BigInt::mod_add(
+                    &BigInt::mod_pow(x, &BigInt::from(3), &p),
+                    &BigInt::from(7),
+                    &p,
+                )
+        };
+
+        let g = GE::generator();
+        let x = g.x_coord().unwrap();
+        let y = g.y_coord().unwrap();
+        assert!(on_curve(&x, &y));
+
+        let off_curve = [
+            (x.clone(), &y + BigInt::from(1)),
+            (&x + BigInt::from(1), y.clone()),
+            // Point at infinity has no affine coordinates
+            (BigInt::zero(), BigInt::zero()),
+            // Non-canonical coordinates: x + p still fits into 32 bytes
+            (&x + &p, y.clone()),
+            (BigInt::from(1), BigInt::from(1)),
+        ];
+        for (x, y) in off_curve {
+            assert!(GE::from_coords(&x, &y).is_err(), "({}, {})", x, y);
+            assert!(
+                crate::elliptic::curves::Point::<super::Secp256k1>::from_coords(&x, &y).is_err()
+            );
+        }
+
+        // Every accepted point satisfies the curve equation
+        let point = GE::generator_mul(
+            &<super::Secp256k1Scalar as crate::elliptic::curves::ECScalar>::random(),
+        );
+        let (x, y) = (point.x_coord().unwrap(), point.y_coord().unwrap());
+        assert!(on_curve(&x, &y));
+        assert_eq!(GE::from_coords(&x, &y).unwrap(), point);
+    }
+
+    #[test]
+    fn public_key_try_from_bytes() {
+        use std::convert::TryFrom;
+
+        use super::PK;
+
+        let point = GE::generator_mul(
+            &<super::Secp256k1Scalar as crate::elliptic::curves::ECScalar>::random(),
+        );
+        let pk = point.underlying_ref().unwrap();
+        let compressed = point.serialize_compressed();
+        let uncompressed = point.serialize_uncompressed();
+
+        assert_eq!(PK::try_from(&compressed[..]).unwrap(), pk);
+        assert_eq!(PK::try_from(&uncompressed[..]).unwrap(), pk);
+        assert!(PK::try_from(&compressed[1..]).is_err());
+        assert!(PK::try_from(&uncompressed[..64]).is_err());
+        assert!(PK::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_malformed_bytes() {
+        let mut bytes = GE::generator().serialize_compressed().to_vec();
+        // wrong length
+        assert!(GE::deserialize(&bytes[1..]).is_err());
+        // wrong header
+        bytes[0] = 0x05;
+        assert!(GE::deserialize(&bytes).is_err());
+    }
+
+    #[test]
+    fn point_to_bytes_roundtrip_in_both_formats() {
+        use crate::elliptic::curves::{Point, Scalar, Secp256k1};
+
+        for _ in 0..10 {
+            let point = Point::<Secp256k1>::generator() * Scalar::random();
+
+            let compressed = point.to_bytes(true);
+            assert_eq!(compressed.len(), 33);
+            assert!(compressed[0] == 0x02 || compressed[0] == 0x03);
+            assert_eq!(Point::from_bytes(&compressed).unwrap(), point);
+
+            let uncompressed = point.to_bytes(false);
+            assert_eq!(uncompressed.len(), 65);
+            assert_eq!(uncompressed[0], 0x04);
+            assert_eq!(uncompressed[1..33], compressed[1..]);
+            assert_eq!(Point::from_bytes(&uncompressed).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn public_key_der_matches_openssl() {
+        use crate::elliptic::curves::{Point, Scalar, Secp256k1};
+
+        // `openssl ec -pubout -outform DER [-conv_form compressed]`
+        let sk = "15a995e090322727c7b5074f0e5c1413e15186796b12fd6c2cd4572219826225";
+        let uncompressed_der = "3056301006072a8648ce3d020106052b8104000a0342000468beddc0c7ee0d2fa19f4b5dcf00828eeee13f5cd14e98a80861d55724f9fef26e5af48b779bc5087af2b4008e9b69633727c11975c16a300fba6ed232cf48d8";
+        let compressed_der = "3036301006072a8648ce3d020106052b8104000a0322000268beddc0c7ee0d2fa19f4b5dcf00828eeee13f5cd14e98a80861d55724f9fef2";
+
+        let sk = Scalar::<Secp256k1>::from_bigint(&BigInt::from_hex(sk).unwrap());
+        let pk = Point::generator() * sk;
+        assert_eq!(hex::encode(pk.to_der(false).unwrap()), uncompressed_der);
+        assert_eq!(hex::encode(pk.to_der(true).unwrap()), compressed_der);
+
+        for der in [uncompressed_der, compressed_der] {
+            let decoded = Point::<Secp256k1>::from_der(&hex::decode(der).unwrap()).unwrap();
+            assert_eq!(decoded, pk);
+        }
+    }
+
+    #[test]
+    fn public_key_der_rejects_malformed_input() {
+        use crate::elliptic::curves::{Point, Scalar, Secp256k1};
+
+        assert!(Point::<Secp256k1>::zero().to_der(false).is_err());
+
+        let pk = Point::<Secp256k1>::generator() * Scalar::random();
+        let der = pk.to_der(true).unwrap();
+        assert!(Point::<Secp256k1>::from_der(&der[..der.len() - 1]).is_err());
+        assert!(Point::<Secp256k1>::from_der(&[]).is_err());
+
+        // curve OID is not secp256k1
+        let mut other_curve = der.clone();
+        other_curve[19] = 0x22;
+        assert!(Point::<Secp256k1>::from_der(&other_curve).is_err());
+
+        // nonzero number of unused bits
+        let mut unused_bits = der.clone();
+        unused_bits[22] = 0x01;
+        assert!(Point::<Secp256k1>::from_der(&unused_bits).is_err());
+
+        // zero point
+        let mut zero = der;
+        zero[23..].copy_from_slice(&[0; 33]);
+        assert!(Point::<Secp256k1>::from_der(&zero).is_err());
+    }
+
+    #[test]
+    fn point_from_bytes_rejects_invalid_uncompressed_encodings() {
+        use crate::elliptic::curves::{Point, PointFromBytesError, Secp256k1};
+
+        let valid = GE::generator().serialize_uncompressed().to_vec();
+        let is_deserialization_error = |bytes: &[u8]| {
+            matches!(
+                Point::<Secp256k1>::from_bytes(bytes),
+                Err(PointFromBytesError::DeserializationError)
+            )
+        };
+        assert!(Point::<Secp256k1>::from_bytes(&valid).is_ok());
+
+        // y coordinate is changed, the point is not on curve
+        let mut bytes = valid.clone();
+        bytes[64] ^= 1;
+        assert!(is_deserialization_error(&bytes));
+        // x coordinate exceeds field modulus
+        let mut bytes = valid.clone();
+        bytes[1..33].copy_from_slice(&[0xff; 32]);
+        assert!(is_deserialization_error(&bytes));
+        // wrong header
+        let mut bytes = valid.clone();
+        bytes[0] = 0x02;
+        assert!(is_deserialization_error(&bytes));
+        // wrong length
+        assert!(is_deserialization_error(&valid[..64]));
+    }
+
+    #[test]
+    fn from_hash_known_answer() {
+        use super::FE;
+        use crate::elliptic::curves::ECScalar;
+
+        // expand_message_xmd (SHA-256) over length-prefixed data, reduced modulo group order.
+        // Expected value doesn't come from this crate: it's derived with Python hashlib
+        // following RFC 9380, see from_hash_known_answer in ed25519 module
+        let scalar = FE::from_hash(&[b"hello", b"world"], b"CURV-TEST-V01-from_hash");
+        assert_eq!(
+            scalar.to_bigint(),
+            BigInt::from_hex("9516c0244022a087e47875904bac59fbd516fe60f225c2e8990be4fb244eaec2")
+                .unwrap()
+        );
+    }
 }
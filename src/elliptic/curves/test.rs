@@ -3,6 +3,8 @@
 use std::iter;
 
 use rand::{rngs::OsRng, Rng};
+use subtle::Choice;
+use typenum::Unsigned;
 
 use crate::arithmetic::*;
 use crate::test_for_all_curves;
@@ -28,6 +30,13 @@ fn valid_zero_point<E: Curve>() {
 test_for_all_curves!(zero_point_arithmetic);
 fn zero_point_arithmetic<E: Curve>() {
     let zero_point = E::Point::zero();
+    let generator = E::Point::generator();
+    assert!(
+        generator.add_point(&generator.neg_point()).is_zero(),
+        "G + (-G) = O"
+    );
+    assert!(generator.sub_point(generator).is_zero(), "G - G = O");
+
     let point = E::Point::generator().scalar_mul(&random_nonzero_scalar());
 
     assert_eq!(zero_point.add_point(&point), point, "O + P = P");
@@ -53,6 +62,32 @@ fn scalar_modulo_curve_order<E: Curve>() {
     assert_eq!(s, E::Scalar::from_bigint(&BigInt::from(1)));
 }
 
+test_for_all_curves!(one_and_zero_scalars);
+fn one_and_zero_scalars<E: Curve>() {
+    let one = E::Scalar::one();
+    assert_eq!(one, E::Scalar::from_bigint(&BigInt::from(1)));
+    assert_eq!(one.to_bigint(), BigInt::from(1));
+
+    let x: E::Scalar = random_nonzero_scalar();
+    assert_eq!(one.mul(&x), x, "1 * x = x");
+    assert_eq!(E::Scalar::zero().add(&x), x, "0 + x = x");
+}
+
+test_for_all_curves!(random_scalar_with_seeded_rng_is_reproducible);
+fn random_scalar_with_seeded_rng_is_reproducible<E: Curve>() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    let mut rng = ChaCha20Rng::seed_from_u64(42);
+    let a: E::Scalar = ECScalar::random_with_rng(&mut rng);
+    let b: E::Scalar = ECScalar::random_with_rng(&mut rng);
+    assert_ne!(a, b);
+
+    let mut rng = ChaCha20Rng::seed_from_u64(42);
+    assert_eq!(a, E::Scalar::random_with_rng(&mut rng));
+    assert_eq!(b, E::Scalar::random_with_rng(&mut rng));
+}
+
 test_for_all_curves!(zero_scalar_arithmetic);
 fn zero_scalar_arithmetic<E: Curve>() {
     let s: E::Scalar = random_nonzero_scalar();
@@ -104,6 +139,19 @@ fn zero_point_serialization<E: Curve>() {
     assert_eq!(point, point_from_uncompressed);
 }
 
+test_for_all_curves!(generator_is_cached);
+fn generator_is_cached<E: Curve>() {
+    let g1: &E::Point = ECPoint::generator();
+    let g2: &E::Point = ECPoint::generator();
+    assert!(std::ptr::eq(g1, g2));
+
+    // Cached value equals to a freshly computed generator
+    let fresh = E::Point::generator_mul(&E::Scalar::one());
+    assert_eq!(*g1, fresh);
+    let decoded = E::Point::deserialize(&fresh.serialize_compressed()).unwrap();
+    assert_eq!(*g1, decoded);
+}
+
 test_for_all_curves!(generator_mul_curve_order_is_zero);
 fn generator_mul_curve_order_is_zero<E: Curve>() {
     let g: &E::Point = ECPoint::generator();
@@ -112,6 +160,25 @@ fn generator_mul_curve_order_is_zero<E: Curve>() {
     assert!(g.scalar_mul(&s).add_point(g).is_zero());
 }
 
+test_for_all_curves!(generator_mul_matches_variable_base_mul);
+fn generator_mul_matches_variable_base_mul<E: Curve>() {
+    let g: &E::Point = ECPoint::generator();
+    let q = E::Scalar::group_order();
+    let scalars = [
+        E::Scalar::zero(),
+        E::Scalar::one(),
+        E::Scalar::from_u64(0xf0f0_ffff),
+        E::Scalar::from_bigint(&(q - 1)),
+        // Every 4-bit window is non-zero
+        E::Scalar::from_bigint(&(BigInt::from(2).pow(q.bit_length() as u32 - 1) - 1)),
+        random_nonzero_scalar(),
+        random_nonzero_scalar(),
+    ];
+    for s in &scalars {
+        assert_eq!(E::Point::generator_mul(s), g.scalar_mul(s), "{:?}", s);
+    }
+}
+
 test_for_all_curves!(scalar_behaves_the_same_as_bigint);
 fn scalar_behaves_the_same_as_bigint<E: Curve>() {
     let mut rng = OsRng;
@@ -203,6 +270,40 @@ fn from_coords_produces_the_same_point<E: Curve>() {
     assert_eq!(p, p2);
 }
 
+test_for_all_curves!(from_coords_rejects_points_not_on_curve);
+fn from_coords_rejects_points_not_on_curve<E: Curve>() {
+    if E::CURVE_NAME == "ristretto" {
+        // Coordinates are never accepted
+        let y = BigInt::from(1);
+        assert!(<E::Point as ECPoint>::from_coords(&BigInt::zero(), &y).is_err());
+        return;
+    }
+    let p: E::Point = ECPoint::generator_mul(&random_nonzero_scalar());
+    let coords = p.coords().unwrap();
+    let one = BigInt::one();
+    assert!(<E::Point as ECPoint>::from_coords(&coords.x, &(&coords.y + &one)).is_err());
+    assert!(<E::Point as ECPoint>::from_coords(&(&coords.x + &one), &coords.y).is_err());
+    assert!(<E::Point as ECPoint>::from_coords(&coords.y, &coords.x).is_err());
+}
+
+test_for_all_curves!(zero_point_coords);
+fn zero_point_coords<E: Curve>() {
+    let zero = E::Point::zero();
+    match E::CURVE_NAME {
+        // Identity of Edwards curve is an affine point
+        "ed25519" => {
+            let coords = zero.coords().unwrap();
+            assert_eq!((coords.x, coords.y), (BigInt::zero(), BigInt::one()));
+            assert_eq!(zero.x_coord(), Some(BigInt::zero()));
+            assert_eq!(zero.y_coord(), Some(BigInt::one()));
+        }
+        _ => {
+            assert!(zero.coords().is_none());
+            assert!(zero.x_coord().is_none());
+        }
+    }
+}
+
 test_for_all_curves!(test_point_addition);
 fn test_point_addition<E: Curve>() {
     let a: E::Scalar = random_nonzero_scalar();
@@ -306,6 +407,58 @@ fn serialize_deserialize_scalar<E: Curve>() {
     }
 }
 
+test_for_all_curves!(serialized_scalar_is_zero_padded_integer);
+fn serialized_scalar_is_zero_padded_integer<E: Curve>() {
+    let little_endian = matches!(E::CURVE_NAME, "ed25519" | "ristretto");
+    let to_be = |bytes: &[u8]| {
+        let mut bytes = bytes.to_vec();
+        if little_endian {
+            bytes.reverse();
+        }
+        bytes
+    };
+    let len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+
+    for n in [BigInt::zero(), BigInt::one(), BigInt::from(0x0102u16)] {
+        let scalar = E::Scalar::from_bigint(&n);
+        let bytes = to_be(&scalar.serialize());
+        assert_eq!(bytes.len(), len);
+        assert_eq!(BigInt::from_bytes(&bytes), n);
+        assert_eq!(bytes[len - 2..], n.to_bytes_array::<2>().unwrap());
+    }
+
+    let scalar: E::Scalar = random_nonzero_scalar();
+    assert_eq!(
+        BigInt::from_bytes(&to_be(&scalar.serialize())),
+        scalar.to_bigint()
+    );
+
+    // Reduced deserialization accepts any integer of the right length
+    let max = vec![0xffu8; len];
+    let reduced = E::Scalar::deserialize_reduced(&max).unwrap();
+    assert_eq!(
+        reduced.to_bigint(),
+        BigInt::from_bytes(&max).modulus(E::Scalar::group_order())
+    );
+    assert!(E::Scalar::deserialize(&max).is_err());
+    assert_eq!(
+        E::Scalar::deserialize(&reduced.serialize()).unwrap(),
+        reduced
+    );
+}
+
+test_for_all_curves!(scalar_from_u64_matches_from_bigint);
+fn scalar_from_u64_matches_from_bigint<E: Curve>() {
+    for n in [0, 1, 5, 0xff, 0x0102_0304_0506_0708, u64::MAX - 1, u64::MAX] {
+        let expected = E::Scalar::from_bigint(&BigInt::from(n));
+        assert_eq!(E::Scalar::from_u64(n), expected);
+        assert_eq!(E::Scalar::from_u64(n).to_bigint(), BigInt::from(n));
+    }
+    assert_eq!(E::Scalar::from_u64(0), E::Scalar::zero());
+    assert!(E::Scalar::from_u64(0).is_zero());
+    assert_eq!(E::Scalar::from_u64(1), E::Scalar::one());
+}
+
 test_for_all_curves!(scalar_invert);
 fn scalar_invert<E: Curve>() {
     let n: E::Scalar = random_nonzero_scalar();
@@ -314,6 +467,72 @@ fn scalar_invert<E: Curve>() {
     assert_eq!(n.mul(&n_inv), ECScalar::from_bigint(&BigInt::one()))
 }
 
+test_for_all_curves!(scalar_invert_edge_cases);
+fn scalar_invert_edge_cases<E: Curve>() {
+    let one: E::Scalar = ECScalar::one();
+    assert_eq!(one.invert(), Some(one.clone()));
+
+    let minus_one: E::Scalar = one.neg();
+    assert_eq!(minus_one.invert(), Some(minus_one.clone()));
+
+    let two: E::Scalar = ECScalar::from_bigint(&BigInt::from(2));
+    let two_inv = two.invert().unwrap();
+    assert_eq!(two_inv.mul(&two), one);
+    assert_eq!(
+        two_inv.to_bigint(),
+        (E::Scalar::group_order() + BigInt::one()) / BigInt::from(2)
+    );
+}
+
+// Timing-sensitive, run with `cargo test --release -- --ignored`
+test_for_all_curves!([#[ignore]] scalar_invert_timing_does_not_depend_on_scalar);
+fn scalar_invert_timing_does_not_depend_on_scalar<E: Curve>() {
+    use std::time::{Duration, Instant};
+
+    // Compares median inversion time of scalars with very different structure. Variable-time
+    // algorithms (e.g. extended Euclid) invert small scalars in a few iterations
+    fn median_invert_time<S: ECScalar>(scalars: &[S]) -> Duration {
+        let mut timings: Vec<_> = scalars
+            .iter()
+            .map(|s| {
+                let start = Instant::now();
+                std::hint::black_box(s.invert());
+                start.elapsed()
+            })
+            .collect();
+        timings.sort_unstable();
+        timings[timings.len() / 2]
+    }
+
+    let small: Vec<E::Scalar> = iter::repeat_with(|| ECScalar::from_bigint(&BigInt::from(2)))
+        .take(1000)
+        .collect();
+    let random: Vec<E::Scalar> = iter::repeat_with(random_nonzero_scalar)
+        .take(1000)
+        .collect();
+    let (small, random) = (median_invert_time(&small), median_invert_time(&random));
+    let ratio = small.as_secs_f64() / random.as_secs_f64();
+    assert!(
+        (0.5..2.).contains(&ratio),
+        "small: {:?}, random: {:?}",
+        small,
+        random
+    );
+}
+
+test_for_all_curves!(glob_import_does_not_make_scalar_methods_ambiguous);
+fn glob_import_does_not_make_scalar_methods_ambiguous<E: Curve>() {
+    // Imports curves the same way downstream crates do. Method calls below wouldn't compile if
+    // another trait with the same method names (e.g. PrimeField) was re-exported from `curves`
+    #[allow(unused_imports)]
+    use crate::elliptic::curves::*;
+    let (a, b): (E::Scalar, E::Scalar) = (random_nonzero_scalar(), random_nonzero_scalar());
+    assert_eq!(a.add(&b).sub(&b), a);
+    assert_eq!(a.mul(&b).mul(&b.invert().unwrap()), a);
+    assert!(a.add(&a.neg()).is_zero());
+    assert_eq!(E::Scalar::zero().add(&E::Scalar::one()), E::Scalar::one());
+}
+
 test_for_all_curves!(zero_scalar_invert);
 fn zero_scalar_invert<E: Curve>() {
     let n: E::Scalar = ECScalar::zero();
@@ -321,11 +540,35 @@ fn zero_scalar_invert<E: Curve>() {
     assert!(n_inv.is_none())
 }
 
+test_for_all_curves!(scalar_pow);
+fn scalar_pow<E: Curve>() {
+    let s: E::Scalar = random_nonzero_scalar();
+    let one: E::Scalar = ECScalar::one();
+    assert_eq!(s.pow(0), one);
+    assert_eq!(s.pow(1), s);
+    assert_eq!(s.pow(3), s.mul(&s).mul(&s));
+    assert_eq!(s.pow(10), s.pow(5).mul(&s.pow(5)));
+    // 2^64 - 1 = (2^32 - 1)(2^32 + 1)
+    assert_eq!(s.pow(u64::MAX), s.pow((1 << 32) - 1).pow((1 << 32) + 1));
+    assert_eq!(<E::Scalar as ECScalar>::zero().pow(0), one);
+    assert!(<E::Scalar as ECScalar>::zero().pow(5).is_zero());
+    assert_eq!(
+        s.pow(0xdead_beef_cafe),
+        s.pow_bigint(&BigInt::from(0xdead_beef_cafe_u64))
+    );
+
+    let q = E::Scalar::group_order();
+    assert_eq!(s.pow_bigint(&BigInt::zero()), one);
+    assert_eq!(s.pow_bigint(&(q - 1)), one);
+    assert_eq!(s.pow_bigint(&(q - 2)), s.invert().unwrap());
+}
+
 test_for_all_curves!(point_negation);
 fn point_negation<E: Curve>() {
     let p1 = <E::Point as ECPoint>::generator_mul(&random_nonzero_scalar());
     let p2 = p1.neg_point();
     assert_eq!(p1.add_point(&p2), ECPoint::zero());
+    assert!(E::Point::zero().neg_point().is_zero(), "-O = O");
 }
 
 test_for_all_curves!(point_assign_negation);
@@ -345,6 +588,7 @@ fn scalar_negation<E: Curve>() {
     let s1: E::Scalar = random_nonzero_scalar();
     let s2 = s1.neg();
     assert_eq!(s1.add(&s2), E::Scalar::zero());
+    assert!(E::Scalar::zero().neg().is_zero(), "-0 = 0");
 }
 
 test_for_all_curves!(scalar_assign_negation);
@@ -358,3 +602,407 @@ fn scalar_assign_negation<E: Curve>() {
     };
     assert_eq!(s_neg_1, s_neg_2);
 }
+
+/// Encodes `n` in the same byte order as `S::serialize` does
+fn encode_integer<S: ECScalar>(n: &BigInt) -> Vec<u8> {
+    let len = S::ScalarLength::to_usize();
+    let bytes = n.to_bytes();
+    let mut encoded = vec![0u8; len - bytes.len()];
+    encoded.extend_from_slice(&bytes);
+    let one = S::from_bigint(&BigInt::one()).serialize();
+    if one[0] == 1 {
+        // scalar is serialized in little-endian
+        encoded.reverse();
+    }
+    encoded
+}
+
+test_for_all_curves!(deserialize_scalar_rejects_out_of_range);
+fn deserialize_scalar_rejects_out_of_range<E: Curve>() {
+    let q = E::Scalar::group_order();
+
+    let too_long = vec![1u8; <E::Scalar as ECScalar>::ScalarLength::to_usize() + 1];
+    assert!(E::Scalar::deserialize(&too_long).is_err());
+    assert!(E::Scalar::deserialize_reduced(&too_long).is_err());
+
+    let zero = encode_integer::<E::Scalar>(&BigInt::zero());
+    assert!(E::Scalar::deserialize(&zero).unwrap().is_zero());
+    assert!(E::Scalar::deserialize_reduced(&zero).unwrap().is_zero());
+
+    let q_bytes = encode_integer::<E::Scalar>(q);
+    assert!(E::Scalar::deserialize(&q_bytes).is_err());
+    assert!(E::Scalar::deserialize_reduced(&q_bytes).unwrap().is_zero());
+
+    let q_minus_one = q - 1;
+    let q_minus_one_bytes = encode_integer::<E::Scalar>(&q_minus_one);
+    assert_eq!(
+        E::Scalar::deserialize(&q_minus_one_bytes).unwrap(),
+        E::Scalar::from_bigint(&q_minus_one)
+    );
+    assert_eq!(
+        E::Scalar::deserialize_reduced(&q_minus_one_bytes).unwrap(),
+        E::Scalar::from_bigint(&q_minus_one)
+    );
+
+    let q_plus_one = encode_integer::<E::Scalar>(&(q + 1));
+    assert!(E::Scalar::deserialize(&q_plus_one).is_err());
+    assert_eq!(
+        E::Scalar::deserialize_reduced(&q_plus_one).unwrap(),
+        E::Scalar::from_bigint(&BigInt::one())
+    );
+}
+
+test_for_all_curves!(base_point2_is_independent_generator);
+fn base_point2_is_independent_generator<E: Curve>() {
+    let g: &E::Point = ECPoint::generator();
+    let h: &E::Point = ECPoint::base_point2();
+    assert_ne!(g, h);
+    assert!(!h.is_zero());
+    assert!(h.check_point_order_equals_group_order());
+
+    let h_deserialized: E::Point = ECPoint::deserialize(h.serialize_compressed().as_ref()).unwrap();
+    assert_eq!(h, &h_deserialized);
+}
+
+test_for_all_curves!(prime_subgroup_points_survive_cofactor_clearing);
+fn prime_subgroup_points_survive_cofactor_clearing<E: Curve>() {
+    let zero = E::Point::zero();
+    assert!(zero.is_in_prime_subgroup());
+    assert!(zero.clear_cofactor().is_zero());
+
+    let point = E::Point::generator_mul(&random_nonzero_scalar());
+    assert!(point.is_in_prime_subgroup());
+    let cleared = point.clear_cofactor();
+    assert!(cleared.is_in_prime_subgroup());
+    assert!(cleared.check_point_order_equals_group_order());
+}
+
+test_for_all_curves!(group_order_and_field_modulus_match_published_constants);
+fn group_order_and_field_modulus_match_published_constants<E: Curve>() {
+    let (order, modulus) = match E::CURVE_NAME {
+        "secp256k1" => (
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        ),
+        "secp256r1" => (
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        ),
+        "ed25519" | "ristretto" => (
+            "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed",
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+        ),
+        "bls12_381_1" | "bls12_381_2" => (
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+        ),
+        name => panic!("unknown curve {}", name),
+    };
+    assert_eq!(E::Scalar::group_order(), &BigInt::from_hex(order).unwrap());
+    assert_eq!(
+        E::Point::field_modulus(),
+        &BigInt::from_hex(modulus).unwrap()
+    );
+}
+
+test_for_all_curves!(from_bigint_reduces_modulo_group_order);
+fn from_bigint_reduces_modulo_group_order<E: Curve>() {
+    let q = E::Scalar::group_order();
+    let five = E::Scalar::from_bigint(&BigInt::from(5));
+    assert_eq!(E::Scalar::from_bigint(&(q + BigInt::from(5))), five);
+    assert_eq!(E::Scalar::from_bigint(&(q * BigInt::from(3) + 5)), five);
+    assert!(E::Scalar::from_bigint(q).is_zero());
+    assert_eq!(
+        E::Scalar::from_bigint(&BigInt::from(-1)),
+        E::Scalar::from_bigint(&(q - 1))
+    );
+
+    // Hash-sized inputs are fine too
+    let wide = BigInt::from(2).pow(512) - 1;
+    assert_eq!(E::Scalar::from_bigint(&wide).to_bigint(), wide.modulus(q));
+}
+
+test_for_all_curves!(point_equality_ignores_representation);
+fn point_equality_ignores_representation<E: Curve>() {
+    let a: E::Scalar = random_nonzero_scalar();
+    let b: E::Scalar = random_nonzero_scalar();
+
+    let p1 = E::Point::generator_mul(&a.add(&b));
+    let p2 = E::Point::generator_mul(&a).add_point(&E::Point::generator_mul(&b));
+    let p3 = E::Point::generator().scalar_mul(&a.add(&b));
+    let p4 = E::Point::multi_scalar_mul(
+        &[E::Point::generator().clone(), E::Point::generator().clone()],
+        &[a.clone(), b.clone()],
+    );
+    let p5 = p2.neg_point().neg_point();
+    let p6: E::Point = ECPoint::deserialize(&p3.serialize_uncompressed()).unwrap();
+    for p in [&p2, &p3, &p4, &p5, &p6] {
+        assert_eq!(&p1, p);
+        assert_eq!(p1.serialize_compressed(), p.serialize_compressed());
+    }
+
+    let zero1 = p1.sub_point(&p2);
+    let zero2 = E::Point::generator_mul(&E::Scalar::zero());
+    assert_eq!(zero1, E::Point::zero());
+    assert_eq!(zero1, zero2);
+}
+
+test_for_all_curves!(mul_u64_matches_scalar_mul);
+fn mul_u64_matches_scalar_mul<E: Curve>() {
+    let point = E::Point::generator_mul(&random_nonzero_scalar());
+    assert!(point.mul_u64(0).is_zero());
+    assert_eq!(point.mul_u64(1), point);
+    assert_eq!(point.mul_u64(2), point.add_point(&point));
+    for n in [3, 8, 255, 1 << 20, u64::MAX] {
+        assert_eq!(
+            point.mul_u64(n),
+            point.scalar_mul(&E::Scalar::from_bigint(&BigInt::from(n)))
+        );
+    }
+    assert!(E::Point::zero().mul_u64(12345).is_zero());
+}
+
+test_for_all_curves!(multi_scalar_mul_matches_naive_sum);
+fn multi_scalar_mul_matches_naive_sum<E: Curve>() {
+    for n in [0, 1, 2, 7, 33] {
+        let mut points: Vec<E::Point> = (0..n)
+            .map(|_| E::Point::generator_mul(&E::Scalar::random()))
+            .collect();
+        let mut scalars: Vec<E::Scalar> = (0..n).map(|_| E::Scalar::random()).collect();
+        if n > 2 {
+            points[0] = E::Point::zero();
+            scalars[1] = E::Scalar::zero();
+            scalars[2] = E::Scalar::from_bigint(&BigInt::from(1));
+        }
+
+        let expected = points
+            .iter()
+            .zip(&scalars)
+            .fold(E::Point::zero(), |acc, (p, s)| {
+                acc.add_point(&p.scalar_mul(s))
+            });
+        assert_eq!(E::Point::multi_scalar_mul(&points, &scalars), expected);
+    }
+
+    // Result can be a zero point
+    let p = E::Point::generator_mul(&E::Scalar::random());
+    let s = E::Scalar::random();
+    let sum = E::Point::multi_scalar_mul(&[p.clone(), p], &[s.clone(), s.neg()]);
+    assert!(sum.is_zero());
+}
+
+test_for_all_curves!(batch_invert_matches_invert);
+fn batch_invert_matches_invert<E: Curve>() {
+    let mut scalars: Vec<E::Scalar> = (0..50).map(|_| random_nonzero_scalar()).collect();
+    scalars[0] = E::Scalar::zero();
+    scalars[17] = E::Scalar::zero();
+    scalars[49] = E::Scalar::zero();
+    let expected: Vec<Option<E::Scalar>> = scalars.iter().map(|s| s.invert()).collect();
+
+    let mut inverted = scalars.clone();
+    let zeros = E::Scalar::batch_invert(&mut inverted);
+
+    for i in 0..scalars.len() {
+        assert_eq!(zeros[i], expected[i].is_none());
+        match &expected[i] {
+            Some(inv) => assert_eq!(&inverted[i], inv),
+            None => assert_eq!(inverted[i], scalars[i]),
+        }
+    }
+
+    let mut empty: [E::Scalar; 0] = [];
+    assert!(E::Scalar::batch_invert(&mut empty).is_empty());
+}
+
+test_for_all_curves!(hash_to_curve_is_deterministic_and_domain_separated);
+fn hash_to_curve_is_deterministic_and_domain_separated<E: Curve>() {
+    let dst1 = b"CURV-TEST-V01-CS01-dst1";
+    let dst2 = b"CURV-TEST-V01-CS01-dst2";
+
+    let p1 = E::Point::hash_to_curve(b"message", dst1);
+    assert_eq!(p1, E::Point::hash_to_curve(b"message", dst1));
+    assert_ne!(p1, E::Point::hash_to_curve(b"message", dst2));
+    assert_ne!(p1, E::Point::hash_to_curve(b"another message", dst1));
+
+    assert!(p1.check_point_order_equals_group_order());
+    assert_ne!(&p1, E::Point::generator());
+}
+
+test_for_all_curves!(from_hash_is_domain_separated);
+fn from_hash_is_domain_separated<E: Curve>() {
+    let dst: &[u8] = b"CURV-TEST-V01-from_hash";
+    let s = E::Scalar::from_hash(&[b"ab", b"c"], dst);
+    assert_eq!(s, E::Scalar::from_hash(&[b"ab", b"c"], dst));
+    assert_ne!(s, E::Scalar::from_hash(&[b"a", b"bc"], dst));
+    assert_ne!(s, E::Scalar::from_hash(&[b"abc"], dst));
+    assert_ne!(
+        s,
+        E::Scalar::from_hash(&[b"ab", b"c"], b"CURV-TEST-V01-another-dst")
+    );
+    assert_ne!(
+        E::Scalar::from_hash(&[], dst),
+        E::Scalar::from_hash(&[b""], dst)
+    );
+}
+
+test_for_all_curves!(from_bytes_wide_agrees_with_bigint_reduction);
+fn from_bytes_wide_agrees_with_bigint_reduction<E: Curve>() {
+    let q = E::Scalar::group_order();
+    let mut order = [0u8; 64];
+    order[32..].copy_from_slice(&q.to_bytes_array::<32>().unwrap());
+    let mut inputs = vec![[0u8; 64], [0xff; 64], order];
+    for _ in 0..20 {
+        let mut bytes = [0u8; 64];
+        OsRng.fill(&mut bytes[..]);
+        inputs.push(bytes);
+    }
+
+    for bytes in inputs {
+        let expected = BigInt::from_bytes(&bytes).modulus(q);
+        assert_eq!(E::Scalar::from_bytes_wide(&bytes).to_bigint(), expected);
+    }
+    assert!(E::Scalar::from_bytes_wide(&order).is_zero());
+}
+
+test_for_all_curves!(from_bytes_wide_known_answer);
+fn from_bytes_wide_known_answer<E: Curve>() {
+    // Input is big-endian integer 0x000102...3f. Expected values are that integer modulo group
+    // order, computed with Python big integers, so they don't depend on BigInt backends
+    let mut bytes = [0u8; 64];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let expected = match E::CURVE_NAME {
+        "ed25519" | "ristretto" => {
+            "b70c9bbef3e09049a6e109fa2776446f6db2cb3f1566db88c0ce540df7ae673"
+        }
+        "secp256k1" => "76730d0e2c1f94d0a845c9e5f7ee405eefef04abf8e3ce754279c7d6b07c7885",
+        "secp256r1" => "5fb8781887929169f79e6d824949750c77570a0c1e0e7ad29ad8e239ae0c0caa",
+        "bls12_381_1" | "bls12_381_2" => {
+            "6d31d8684aab1a3910d9770d3affb7e74ac05cee3b11e7ca194c48de6e4f23ec"
+        }
+        name => panic!("unknown curve {}", name),
+    };
+    assert_eq!(
+        E::Scalar::from_bytes_wide(&bytes).to_bigint().to_hex(),
+        expected
+    );
+}
+
+test_for_all_curves!(from_bytes_wide_is_not_biased);
+fn from_bytes_wide_is_not_biased<E: Curve>() {
+    // See from_hash_is_not_biased
+    const N: usize = 4000;
+    let half_q = E::Scalar::group_order() / BigInt::from(2);
+    let lower_half = (0..N)
+        .filter(|_| {
+            let mut bytes = [0u8; 64];
+            OsRng.fill(&mut bytes[..]);
+            E::Scalar::from_bytes_wide(&bytes).to_bigint() < half_q
+        })
+        .count();
+    let tolerance = 5 * ((N / 4) as f64).sqrt() as usize;
+    assert!(
+        (N / 2).abs_diff(lower_half) <= tolerance,
+        "lower half: {}",
+        lower_half
+    );
+}
+
+test_for_all_curves!(from_hash_is_not_biased);
+fn from_hash_is_not_biased<E: Curve>() {
+    // Uniformly distributed scalar falls into the lower half of `[0; q)` with probability 1/2,
+    // and each of its low 128 bits is set with probability (negligibly close to) 1/2. Counts
+    // must be within 5 standard deviations (`5 * sqrt(N/4)`) from N/2.
+    const N: usize = 4000;
+    let half_q = E::Scalar::group_order() / BigInt::from(2);
+
+    let mut lower_half = 0;
+    let mut bit_counts = vec![0; 128];
+    for i in 0..N as u32 {
+        let s = E::Scalar::from_hash(&[&i.to_be_bytes()], b"CURV-TEST-V01-bias").to_bigint();
+        if s < half_q {
+            lower_half += 1;
+        }
+        for (bit, count) in bit_counts.iter_mut().enumerate() {
+            if s.test_bit(bit) {
+                *count += 1;
+            }
+        }
+    }
+
+    let tolerance = 5 * ((N / 4) as f64).sqrt() as usize;
+    let within_bounds = |count: usize| (N / 2).abs_diff(count) <= tolerance;
+    assert!(within_bounds(lower_half), "lower half: {}", lower_half);
+    for (bit, &count) in bit_counts.iter().enumerate() {
+        assert!(within_bounds(count), "bit {}: {}", bit, count);
+    }
+}
+
+test_for_all_curves!(ct_eq_agrees_with_eq);
+fn ct_eq_agrees_with_eq<E: Curve>() {
+    let a = random_nonzero_scalar::<E::Scalar>();
+    let b = a.add(&E::Scalar::from_bigint(&BigInt::from(1)));
+    let zero = E::Scalar::zero();
+    for (x, y) in [(&a, &a), (&a, &b), (&zero, &zero), (&a, &zero), (&zero, &b)] {
+        assert_eq!(bool::from(x.ct_eq(y)), x == y);
+    }
+    assert!(bool::from(a.ct_eq(&E::Scalar::from_bigint(&a.to_bigint()))));
+
+    let p = E::Point::generator_mul(&a);
+    let q = E::Point::generator_mul(&b);
+    let o = E::Point::zero();
+    for (x, y) in [(&p, &p), (&p, &q), (&o, &o), (&p, &o), (&o, &q)] {
+        assert_eq!(bool::from(x.ct_eq(y)), x == y);
+    }
+    assert!(bool::from(p.ct_eq(
+        &E::Point::deserialize(&p.serialize_uncompressed()).unwrap()
+    )));
+}
+
+test_for_all_curves!(conditional_select_picks_chosen_value);
+fn conditional_select_picks_chosen_value<E: Curve>() {
+    let a: E::Scalar = random_nonzero_scalar();
+    let b: E::Scalar = random_nonzero_scalar();
+    let zero = E::Scalar::zero();
+    for (x, y) in [(&a, &b), (&zero, &b), (&a, &zero)] {
+        assert_eq!(&E::Scalar::conditional_select(x, y, Choice::from(0)), x);
+        assert_eq!(&E::Scalar::conditional_select(x, y, Choice::from(1)), y);
+    }
+
+    let p = E::Point::generator_mul(&a);
+    let q = E::Point::generator_mul(&b);
+    let zero = E::Point::zero();
+    for (x, y) in [(&p, &q), (&zero, &q), (&p, &zero)] {
+        assert_eq!(&E::Point::conditional_select(x, y, Choice::from(0)), x);
+        assert_eq!(&E::Point::conditional_select(x, y, Choice::from(1)), y);
+    }
+}
+
+test_for_all_curves!(scalar_is_zeroized_on_drop);
+fn scalar_is_zeroized_on_drop<E: Curve>() {
+    use std::mem::{size_of, ManuallyDrop};
+
+    let mut scalar = Box::new(ManuallyDrop::new(random_nonzero_scalar::<E::Scalar>()));
+    let underlying = scalar.underlying_ref() as *const _ as *const u8;
+    let len = size_of::<<E::Scalar as ECScalar>::Underlying>();
+    let read_underlying = || {
+        (0..len)
+            .map(|i| unsafe { std::ptr::read_volatile(underlying.add(i)) })
+            .collect::<Vec<u8>>()
+    };
+    let before = read_underlying();
+
+    // Best-effort check: the scalar is dropped, but the box still owns the memory it occupied,
+    // so we can look at what's left there
+    unsafe { ManuallyDrop::drop(&mut scalar) };
+    let after = read_underlying();
+
+    // Memory is not necessarily all zeroes (e.g. zeroized `Option` is overwritten with `None`
+    // which leaves payload bytes undefined), but none of the secret bytes may remain
+    let leaked = before
+        .chunks(8)
+        .zip(after.chunks(8))
+        .any(|(b, a)| b == a && b.iter().any(|x| *x != 0));
+    assert!(!leaked, "before: {:?}, after: {:?}", before, after);
+}
@@ -8,10 +8,13 @@
 use std::fmt;
 
 use generic_array::{ArrayLength, GenericArray};
+use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use typenum::Unsigned;
 use zeroize::Zeroize;
 
+use crate::arithmetic::traits::*;
 use crate::BigInt;
 
 /// Elliptic curve implementation
@@ -44,23 +47,122 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + Send + Sync + 'static {
     type ScalarLength: ArrayLength<u8> + Unsigned;
 
     /// Samples a random scalar
-    fn random() -> Self;
+    ///
+    /// Uses [thread_rng](rand::thread_rng) as a source of randomness, see
+    /// [random_with_rng](Self::random_with_rng).
+    fn random() -> Self {
+        Self::random_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Samples a random scalar using given source of randomness
+    ///
+    /// Allows deterministic sampling (e.g. for test vectors) and custom entropy sources.
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self;
 
     /// Constructs a zero scalar
     fn zero() -> Self;
+    /// Constructs a scalar equal to one
+    fn one() -> Self;
+    /// Constructs a scalar `n % group_order` from a machine integer
+    ///
+    /// Unlike [from_bigint](Self::from_bigint), it builds the scalar natively without going
+    /// through [BigInt].
+    fn from_u64(n: u64) -> Self;
     /// Checks if the scalar equals to zero
     fn is_zero(&self) -> bool {
         self == &Self::zero()
     }
+    /// Checks whether two scalars are equal in constant time
+    ///
+    /// `PartialEq` implementations are not guaranteed to be constant time: they may exit early
+    /// on the first differing limb. Comparing public values with `==` is fine (e.g. commitments
+    /// in [VerifiableSS::validate_share] or proofs in [DLogProof::verify]), but comparisons
+    /// where at least one side is secret (secret shares, reconstructed secrets, private keys,
+    /// nonces) should use `ct_eq` instead.
+    ///
+    /// Default implementation compares [serialized](Self::serialize) scalars in constant time.
+    ///
+    /// [VerifiableSS::validate_share]: crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS::validate_share
+    /// [DLogProof::verify]: crate::cryptographic_primitives::proofs::sigma_dlog::DLogProof::verify
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.serialize()
+            .as_slice()
+            .ct_eq(other.serialize().as_slice())
+    }
+    /// Returns `a` if `choice == 0`, or `b` if `choice == 1`, in constant time
+    ///
+    /// Default implementation selects [serialized](Self::serialize) scalars byte by byte in
+    /// constant time, and deserializes the result.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let (a, b) = (a.serialize(), b.serialize());
+        let selected: Vec<u8> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| u8::conditional_select(a, b, choice))
+            .collect();
+        Self::deserialize(&selected).expect("selected bytes encode one of given scalars")
+    }
 
     /// Constructs a scalar `n % group_order`
+    ///
+    /// Never panics: any integer, including negative ones and ones exceeding group order
+    /// (e.g. hash outputs), is reduced into `[0, group_order)`.
+    ///
+    /// Conversion is potentially variable-time as it's done via BigInt, avoid it for secret
+    /// scalars if timing side channels are a concern (see `ct_field` feature).
     fn from_bigint(n: &BigInt) -> Self;
     /// Converts a scalar to BigInt
+    ///
+    /// Conversion is potentially variable-time, see [from_bigint](Self::from_bigint).
     fn to_bigint(&self) -> BigInt;
     /// Serializes scalar into bytes
+    ///
+    /// Output always has [ScalarLength](Self::ScalarLength) bytes (integer is zero-padded).
+    /// Byte order is the one native to the curve:
+    /// * secp256k1, secp256r1 (p256), bls12_381: big-endian
+    /// * ed25519, ristretto: little-endian (canonical encoding used by curve25519-dalek)
     fn serialize(&self) -> GenericArray<u8, Self::ScalarLength>;
-    /// Deserializes scalar from bytes
+    /// Deserializes scalar from bytes in the same format as [serialize](Self::serialize) output
+    ///
+    /// Returns error if bytes length doesn't match [ScalarLength](Self::ScalarLength), or if
+    /// encoded integer is not less than [group order](Self::group_order)
     fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>;
+    /// Deserializes scalar from bytes reducing it modulo group order
+    ///
+    /// Unlike [deserialize](Self::deserialize), it accepts encoded integers that exceed group order.
+    /// Returns error if bytes length doesn't match [ScalarLength](Self::ScalarLength).
+    fn deserialize_reduced(bytes: &[u8]) -> Result<Self, DeserializationError>;
+    /// Constructs a scalar from 64 bytes (big-endian integer) reduced modulo group order
+    ///
+    /// This is the correct way to derive a scalar from hash output (e.g. Fiat-Shamir challenge
+    /// or deterministic nonce): expand the hash into 64 bytes and reduce them with this function.
+    /// Reducing a 32-byte digest is biased for curves whose order is far from `2^256` (e.g.
+    /// ed25519), while bias of wide reduction is negligible. [from_hash](Self::from_hash) uses
+    /// it as well.
+    ///
+    /// Default implementation evaluates the integer over 64-bit words via Horner's scheme using
+    /// scalar arithmetic, so it's constant time as long as [from_u64](Self::from_u64), `add`
+    /// and `mul` are. ed25519 and ristretto use wide reduction of curve25519-dalek.
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+        let two_32 = Self::from_u64(1 << 32);
+        let two_64 = two_32.mul(&two_32);
+        bytes.chunks_exact(8).fold(Self::zero(), |acc, chunk| {
+            let word = chunk.iter().fold(0u64, |w, b| (w << 8) | u64::from(*b));
+            acc.mul(&two_64).add(&Self::from_u64(word))
+        })
+    }
+    /// Derives a scalar from a hash of `data`
+    ///
+    /// Every element of `data` is prefixed with its length (8 bytes, big-endian) before hashing,
+    /// so distinct splits of the same bytes (e.g. `["ab", "c"]` and `["a", "bc"]`) produce different
+    /// scalars. Concatenated input is expanded into 64 bytes via [expand_message_xmd] using the
+    /// curve's hash function and domain separation tag `dst`, and then reduced modulo
+    /// [group order](Self::group_order). Wide reduction makes the bias negligible.
+    ///
+    /// SHA-512 is used for ed25519 and ristretto, SHA-256 for other curves.
+    ///
+    /// [expand_message_xmd]: crate::cryptographic_primitives::hashing::expand_message_xmd
+    fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self;
 
     /// Calculates `(self + other) mod group_order`
     fn add(&self, other: &Self) -> Self;
@@ -69,9 +171,67 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + Send + Sync + 'static {
     /// Calculates `(self - other) mod group_order`
     fn sub(&self, other: &Self) -> Self;
     /// Calculates `-self mod group_order`
+    ///
+    /// `s.add(&s.neg())` is always zero. Wrappers expose it as unary `-` operator.
     fn neg(&self) -> Self;
     /// Calculates `self^-1 (mod group_order)`, returns None if self equals to zero
+    ///
+    /// Scalar being inverted is often secret (e.g. a nonce in ECDSA), so implementations must
+    /// not leak it through timing: sequence of operations may only depend on whether scalar is
+    /// zero. Every backend either uses constant-time inversion of underlying library, or
+    /// computes `self^(q-2)` via Fermat's little theorem.
     fn invert(&self) -> Option<Self>;
+    /// Inverts every scalar in the slice in place
+    ///
+    /// Implements Montgomery's trick: inverting `n` scalars costs a single inversion and `3(n-1)`
+    /// multiplications. Zero scalars are left untouched. Returns a mask where `mask[i]` is `true`
+    /// if `scalars[i]` is zero and hasn't been inverted.
+    fn batch_invert(scalars: &mut [Self]) -> Vec<bool> {
+        let zeros: Vec<bool> = scalars.iter().map(|s| s.is_zero()).collect();
+
+        // partial[i] is a product of all nonzero scalars preceding scalars[i]
+        let mut partial = Vec::with_capacity(scalars.len());
+        let mut acc = Self::one();
+        for (s, &is_zero) in scalars.iter().zip(&zeros) {
+            partial.push(acc.clone());
+            if !is_zero {
+                acc.mul_assign(s);
+            }
+        }
+
+        let mut acc_inv = acc
+            .invert()
+            .expect("product of nonzero scalars can't be zero");
+        for ((s, &is_zero), partial) in scalars.iter_mut().zip(&zeros).zip(&partial).rev() {
+            if is_zero {
+                continue;
+            }
+            let s_inv = acc_inv.mul(partial);
+            acc_inv.mul_assign(s);
+            *s = s_inv;
+        }
+        zeros
+    }
+    /// Calculates `self^exp mod group_order`
+    ///
+    /// Same as [pow_bigint](Self::pow_bigint), but iterates over bits of `exp` directly, without
+    /// converting it into `BigInt`.
+    fn pow(&self, exp: u64) -> Self {
+        let bits = 64 - exp.leading_zeros() as usize;
+        square_and_multiply(self, (0..bits).rev().map(|i| (exp >> i) & 1 == 1))
+    }
+    /// Calculates `self^exp mod group_order`
+    ///
+    /// Default implementation uses square-and-multiply: its running time depends on the exponent
+    /// (but not on `self`), so exponent is assumed to be public. E.g. `s.pow_bigint(&(q - 2))` is
+    /// an inverse of nonzero `s` by Fermat's little theorem.
+    ///
+    /// ## Panics
+    /// Panics if exponent is negative
+    fn pow_bigint(&self, exp: &BigInt) -> Self {
+        assert!(!BigInt::is_negative(exp), "exponent must be non-negative");
+        square_and_multiply(self, (0..exp.bit_length()).rev().map(|i| exp.test_bit(i)))
+    }
     /// Calculates `(self + other) mod group_order`, and assigns result to `self`
     fn add_assign(&mut self, other: &Self) {
         *self = self.add(other)
@@ -100,6 +260,18 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + Send + Sync + 'static {
     fn from_underlying(u: Self::Underlying) -> Self;
 }
 
+/// Raises `base` to the power given by exponent bits, most significant first
+fn square_and_multiply<S: ECScalar>(base: &S, bits: impl Iterator<Item = bool>) -> S {
+    let mut result = S::one();
+    for bit in bits {
+        result = result.mul(&result);
+        if bit {
+            result = result.mul(base);
+        }
+    }
+    result
+}
+
 /// Point on elliptic curve
 ///
 /// ## Note
@@ -111,6 +283,11 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + Send + Sync + 'static {
 /// Trait exposes various methods that make elliptic curve arithmetic. The point can
 /// be [zero](ECPoint::zero). Unlike [ECScalar], ECPoint isn't required to zeroize its value on drop,
 /// but it implements [Zeroize] trait so you can force zeroizing policy on your own.
+///
+/// `PartialEq` compares points, not their representations: implementors that keep points in
+/// projective (or otherwise non-unique) form must compare them as if they were
+/// [compressed](ECPoint::serialize_compressed), so the same point computed in different ways
+/// is always equal to itself.
 pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'static {
     /// Scalar value the point can be multiplied at
     type Scalar: ECScalar;
@@ -138,6 +315,31 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
     fn is_zero(&self) -> bool {
         self == &Self::zero()
     }
+    /// Checks whether two points are equal in constant time
+    ///
+    /// Use it instead of `==` when point is derived from secret data and comparison shouldn't
+    /// leak where points differ. See [ECScalar::ct_eq] for details.
+    ///
+    /// Default implementation compares [compressed](Self::serialize_compressed) points in
+    /// constant time.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.serialize_compressed()
+            .as_slice()
+            .ct_eq(other.serialize_compressed().as_slice())
+    }
+    /// Returns `a` if `choice == 0`, or `b` if `choice == 1`, in constant time
+    ///
+    /// Default implementation selects [compressed](Self::serialize_compressed) points byte by
+    /// byte in constant time, and deserializes the result.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let (a, b) = (a.serialize_compressed(), b.serialize_compressed());
+        let selected: Vec<u8> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| u8::conditional_select(a, b, choice))
+            .collect();
+        Self::deserialize(&selected).expect("selected bytes encode one of given points")
+    }
 
     /// Curve generator
     ///
@@ -148,6 +350,11 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
     ///
     /// We provide an alternative generator value and prove that it was picked randomly
     fn base_point2() -> &'static Self;
+    /// Returns characteristic `p` of the base field over which the curve is defined
+    ///
+    /// Point coordinates are integers modulo `p`. For curves defined over extension fields
+    /// (like BLS12-381 G2 defined over `Fp²`), it's a characteristic of the underlying prime field.
+    fn field_modulus() -> &'static BigInt;
 
     /// Constructs a curve point from its coordinates
     ///
@@ -158,6 +365,11 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
     /// Returns `y` coordinate of the point, or `None` if point is at infinity
     fn y_coord(&self) -> Option<BigInt>;
     /// Returns point coordinates (`x` and `y`), or `None` if point is at infinity
+    ///
+    /// Coordinates are affine, and can be turned back into the point via
+    /// [from_coords](Self::from_coords). Edwards curves (ed25519) return Edwards coordinates, and
+    /// their identity is an affine point `(0, 1)` rather than a point at infinity. Ristretto
+    /// doesn't expose coordinates, so it always returns `None`.
     fn coords(&self) -> Option<PointCoords>;
 
     /// Serializes point into bytes in compressed
@@ -173,6 +385,39 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
     /// Whether point in compressed or uncompressed form will be deducted from its size
     fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>;
 
+    /// Hashes a message to curve point
+    ///
+    /// `dst` is a domain separation tag, it must be unique for every protocol (and for every use
+    /// of the function within the protocol). The suite depends on the curve:
+    ///
+    /// | Curve       | Suite                                  | Standard                      |
+    /// |-------------|----------------------------------------|-------------------------------|
+    /// | secp256k1   | `secp256k1_XMD:SHA-256_SSWU_RO_`       | [RFC 9380]                    |
+    /// | secp256r1   | `P256_XMD:SHA-256_SSWU_RO_`            | [RFC 9380]                    |
+    /// | ed25519     | `edwards25519_XMD:SHA-512_ELL2_RO_`    | [RFC 9380]                    |
+    /// | ristretto   | `ristretto255_XMD:SHA-512_R255MAP_RO_` | draft, non-interoperable\*    |
+    /// | bls12_381_1 | `BLS12381G1_XMD:SHA-256_SSWU_RO_`      | draft, non-interoperable\*\*  |
+    /// | bls12_381_2 | `BLS12381G2_XMD:SHA-256_SSWU_RO_`      | draft, non-interoperable\*\*  |
+    ///
+    /// secp256k1, secp256r1 and ed25519 implement `hash_to_curve` (random oracle encoding) as
+    /// defined in RFC 9380, and are checked against its test vectors (Appendix J).
+    ///
+    /// \* The ristretto suite only appeared in drafts of the spec, RFC 9380 doesn't define it.
+    /// Message is expanded into 64 bytes via `expand_message_xmd` with SHA-512, which are then
+    /// mapped to a point by the one-way map of [RFC 9496] (section 4.3.4). The result isn't
+    /// checked against any published test vectors, so it must not be relied on for
+    /// interoperability with other implementations.
+    ///
+    /// \*\* BLS12-381 points are hashed by [pairing_plus], which implements an earlier draft of
+    /// the spec: it uses different `sgn0` function, so outputs don't match RFC 9380 test vectors
+    /// and other BLS12-381 implementations.
+    ///
+    /// Resulting point belongs to the prime order subgroup, its discrete logarithm is unknown.
+    ///
+    /// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+    /// [RFC 9496]: https://www.rfc-editor.org/rfc/rfc9496.html
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self;
+
     /// Checks that order of this point equals to [group order](ECScalar::group_order)
     ///
     /// Generally, point might be composition of different subgroups points: `P = sG + kT` (`G` —
@@ -193,8 +438,26 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
         self_at_q.add_point_assign(self);
         !self.is_zero() && self_at_q.is_zero()
     }
+    /// Checks that the point belongs to the prime order subgroup, ie. `qP = 0`
+    ///
+    /// Unlike [check_point_order_equals_group_order](Self::check_point_order_equals_group_order),
+    /// zero point is considered to be in the subgroup. Points received from untrusted source must
+    /// be checked before use on curves with co-factor ≠ 1, otherwise a small subgroup component
+    /// may leak information about secret scalars they are multiplied at.
+    fn is_in_prime_subgroup(&self) -> bool {
+        self.is_zero() || self.check_point_order_equals_group_order()
+    }
+    /// Multiplies the point at curve co-factor `h`
+    ///
+    /// Resulting point always belongs to the prime order subgroup: small subgroup component
+    /// of the point is eliminated. Note that it's not a projection, `hP ≠ P` for points that are
+    /// already in the subgroup unless `h = 1`.
+    fn clear_cofactor(&self) -> Self;
 
     /// Multiplies the point at scalar value
+    ///
+    /// Never fails: multiplying [zero](Self::zero) point, or multiplying at zero scalar, results
+    /// into zero point.
     fn scalar_mul(&self, scalar: &Self::Scalar) -> Self;
     /// Multiplies curve generator at given scalar
     ///
@@ -203,12 +466,57 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
     fn generator_mul(scalar: &Self::Scalar) -> Self {
         Self::generator().scalar_mul(scalar)
     }
+    /// Computes linear combination of points: `points[0] * scalars[0] + ... + points[n-1] * scalars[n-1]`
+    ///
+    /// Default implementation multiplies every point separately and sums up the results. Curves
+    /// override it with simultaneous multiplication (Straus/Pippenger) when underlying library
+    /// supports it. Implementation is not guaranteed to be constant time, so it's only suitable
+    /// for public values (e.g. verifying commitments).
+    ///
+    /// ## Panics
+    /// Panics if `points` and `scalars` have different lengths.
+    fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        points
+            .iter()
+            .zip(scalars)
+            .fold(Self::zero(), |acc, (p, s)| acc.add_point(&p.scalar_mul(s)))
+    }
     /// Adds two points
+    ///
+    /// Never fails: zero point is a neutral element (`P + O = P`), and adding a point to its
+    /// negation results into zero point (`P + (-P) = O`), which can be checked via
+    /// [is_zero](Self::is_zero).
     fn add_point(&self, other: &Self) -> Self;
     /// Substrates `other` from `self`
+    ///
+    /// Never fails, `P - P` results into zero point.
     fn sub_point(&self, other: &Self) -> Self;
     /// Negates point
+    ///
+    /// `P.add_point(&P.neg_point())` is always a zero point. Wrappers expose it as unary `-`
+    /// operator.
     fn neg_point(&self) -> Self;
+    /// Multiplies the point at small integer `n`
+    ///
+    /// Uses double-and-add, which is cheaper than [scalar_mul](Self::scalar_mul) for small
+    /// multipliers. Unlike `scalar_mul`, `n` is not reduced modulo group order, so multiplying
+    /// a point outside of prime order subgroup (eg. [clearing co-factor](Self::clear_cofactor))
+    /// is well-defined. Runs in variable time depending on `n`, which must not be secret.
+    fn mul_u64(&self, n: u64) -> Self {
+        let mut result = Self::zero();
+        for i in (0..64 - n.leading_zeros()).rev() {
+            result = result.add_point(&result);
+            if (n >> i) & 1 == 1 {
+                result.add_point_assign(self);
+            }
+        }
+        result
+    }
 
     /// Multiplies the point at scalar value, assigns result to `self`
     fn scalar_mul_assign(&mut self, scalar: &Self::Scalar) {
@@ -236,6 +544,10 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + Sync + Send + 'sta
 }
 
 /// Affine coordinates of a point
+///
+/// Coordinates are not validated and may not correspond to any point on the curve. Use
+/// [Point::from_coords](super::wrappers::Point::from_coords) to obtain a point, which checks
+/// the curve equation and point order.
 #[derive(Serialize, Deserialize)]
 pub struct PointCoords {
     pub x: BigInt,
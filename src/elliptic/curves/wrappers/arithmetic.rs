@@ -488,4 +488,40 @@ mod test {
             }
         }
     }
+
+    crate::test_for_all_curves!(scalar_operators_agree_for_all_operand_kinds);
+    fn scalar_operators_agree_for_all_operand_kinds<E: Curve>() {
+        let a = Scalar::<E>::random();
+        let b = Scalar::<E>::random();
+
+        let sum = a.to_bigint() + b.to_bigint();
+        let expected = Scalar::<E>::from_bigint(&sum);
+        assert_eq!(&a + &b, expected);
+        assert_eq!(&a + b.clone(), expected);
+        assert_eq!(a.clone() + &b, expected);
+        assert_eq!(a.clone() + b.clone(), expected);
+        assert_eq!(&b + &a, expected);
+
+        let expected = Scalar::<E>::from_bigint(&(a.to_bigint() * b.to_bigint()));
+        assert_eq!(&a * &b, expected);
+        assert_eq!(&a * b.clone(), expected);
+        assert_eq!(a.clone() * &b, expected);
+        assert_eq!(a.clone() * b.clone(), expected);
+        assert_eq!(&b * &a, expected);
+
+        let expected = Scalar::<E>::from_bigint(&(a.to_bigint() - b.to_bigint()));
+        assert_eq!(&a - &b, expected);
+        assert_eq!(&a - b.clone(), expected);
+        assert_eq!(a.clone() - &b, expected);
+        assert_eq!(a.clone() - b.clone(), expected);
+        assert_eq!(&b - &a, -&expected);
+        assert_eq!(-expected.clone(), -&expected);
+
+        // Results are reduced modulo group order
+        let minus_one = -Scalar::<E>::one();
+        assert_eq!(minus_one.to_bigint(), Scalar::<E>::group_order() - 1);
+        assert_eq!(&minus_one + Scalar::from(2), Scalar::one());
+        assert_eq!(&minus_one * &minus_one, Scalar::one());
+        assert_eq!(&a * &b + &a, &a * (&b + Scalar::one()));
+    }
 }
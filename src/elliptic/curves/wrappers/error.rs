@@ -22,6 +22,24 @@ pub enum PointFromBytesError {
     InvalidPoint(MismatchedPointOrder),
 }
 
+/// Error returned by [FromStr](std::str::FromStr) implementation of [Point](super::Point)
+#[derive(Debug, Error)]
+pub enum PointFromHexError {
+    #[error("malformed hex string")]
+    InvalidHex,
+    #[error(transparent)]
+    InvalidEncoding(PointFromBytesError),
+}
+
+/// Error returned by [FromStr](std::str::FromStr) implementation of [Scalar](super::Scalar)
+#[derive(Debug, Error)]
+pub enum ScalarFromHexError {
+    #[error("malformed hex string")]
+    InvalidHex,
+    #[error(transparent)]
+    InvalidEncoding(DeserializationError),
+}
+
 #[derive(Debug, Error)]
 pub enum PointFromCoordsError {
     #[error("{}", NotOnCurve)]
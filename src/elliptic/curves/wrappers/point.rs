@@ -1,11 +1,16 @@
-use std::{fmt, iter};
+use std::{fmt, iter, str::FromStr};
+
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::elliptic::curves::traits::*;
 use crate::BigInt;
 
 use super::{
-    error::{MismatchedPointOrder, PointFromBytesError, PointFromCoordsError, ZeroPointError},
-    EncodedPoint, Generator,
+    error::{
+        MismatchedPointOrder, PointFromBytesError, PointFromCoordsError, PointFromHexError,
+        ZeroPointError,
+    },
+    EncodedPoint, Generator, Scalar,
 };
 use crate::elliptic::curves::wrappers::encoded_point::EncodedPointChoice;
 
@@ -39,7 +44,7 @@ use crate::elliptic::curves::wrappers::encoded_point::EncodedPointChoice;
 ///
 /// ## Arithmetics
 ///
-/// You can add, subtract two points, or multiply point at scalar:
+/// You can add, subtract two points, negate a point, or multiply point at scalar:
 ///
 /// ```rust
 /// # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
@@ -50,6 +55,10 @@ use crate::elliptic::curves::wrappers::encoded_point::EncodedPointChoice;
 /// ) -> Point<Secp256k1> {
 ///     a + b * c
 /// }
+///
+/// fn negation(a: &Point<Secp256k1>) -> Point<Secp256k1> {
+///     -a
+/// }
 /// ```
 #[repr(transparent)]
 pub struct Point<E: Curve> {
@@ -85,6 +94,13 @@ impl<E: Curve> Point<E> {
         unsafe { Self::from_raw_ref_unchecked(p) }
     }
 
+    /// Returns characteristic of the field over which the curve is defined
+    ///
+    /// See [ECPoint::field_modulus](crate::elliptic::curves::ECPoint::field_modulus).
+    pub fn field_modulus() -> &'static BigInt {
+        E::Point::field_modulus()
+    }
+
     /// Constructs zero point
     ///
     /// Zero point (or curve neutral element) is usually denoted as `O`. Its property: `forall A. A + O = A`.
@@ -106,6 +122,49 @@ impl<E: Curve> Point<E> {
         self.as_raw().is_zero()
     }
 
+    /// Computes linear combination `points[0] * scalars[0] + ... + points[n-1] * scalars[n-1]`
+    ///
+    /// It's significantly faster than multiplying every point separately (see
+    /// [ECPoint::multi_scalar_mul]). Computation is not constant time, avoid using it with secret
+    /// scalars.
+    ///
+    /// With `parallel` feature enabled, inputs of at least 256 points are split between all
+    /// available CPU cores. Result is the same as of serial computation.
+    ///
+    /// ## Panics
+    /// Panics if `points` and `scalars` have different lengths.
+    pub fn multi_scalar_mul(points: &[Self], scalars: &[Scalar<E>]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must be of the same length"
+        );
+        let points: Vec<E::Point> = points.iter().map(|p| p.as_raw().clone()).collect();
+        let scalars: Vec<E::Scalar> = scalars.iter().map(|s| s.as_raw().clone()).collect();
+        #[cfg(feature = "parallel")]
+        let result = if points.len() >= PARALLEL_MSM_THRESHOLD {
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            parallel_multi_scalar_mul(&points, &scalars, threads)
+        } else {
+            E::Point::multi_scalar_mul(&points, &scalars)
+        };
+        #[cfg(not(feature = "parallel"))]
+        let result = E::Point::multi_scalar_mul(&points, &scalars);
+        // Safety: linear combination of points of group order is either zero or has group order
+        unsafe { Self::from_raw_unchecked(result) }
+    }
+
+    /// Hashes a message to curve point
+    ///
+    /// `dst` is a domain separation tag, it must be unique for every protocol. Resulting point
+    /// has unknown discrete logarithm. See [ECPoint::hash_to_curve] for hash-to-curve suites
+    /// used by every curve: only some of them conform to RFC 9380.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        let point = E::Point::hash_to_curve(msg, dst);
+        // Safety: hash_to_curve outputs a point in prime order subgroup
+        unsafe { Self::from_raw_unchecked(point) }
+    }
+
     /// Returns point coordinates
     ///
     /// Point might not have coordinates (specifically, "point at infinity" doesn't), in this case
@@ -243,6 +302,15 @@ impl<E: Curve> Point<E> {
         &*(raw_point as *const E::Point as *const Self)
     }
 
+    /// Returns `a` if `choice == 0`, or `b` if `choice == 1`, in constant time
+    ///
+    /// See [ECPoint::conditional_select].
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let raw_point = E::Point::conditional_select(a.as_raw(), b.as_raw(), choice);
+        // Safety: raw_point is either `a` or `b`, both are valid points
+        unsafe { Self::from_raw_unchecked(raw_point) }
+    }
+
     /// Returns a reference to low-level point implementation
     ///
     /// Typically, you don't need to work with `ECPoint` trait directly. `Point<E>` wrapper
@@ -264,6 +332,32 @@ impl<E: Curve> Point<E> {
     }
 }
 
+/// Minimal number of points for which [Point::multi_scalar_mul] is computed in parallel
+#[cfg(feature = "parallel")]
+const PARALLEL_MSM_THRESHOLD: usize = 256;
+
+/// Splits linear combination into (at most) `threads` chunks which are computed in parallel
+#[cfg(any(feature = "parallel", test))]
+fn parallel_multi_scalar_mul<P: ECPoint>(points: &[P], scalars: &[P::Scalar], threads: usize) -> P {
+    let chunk_size = msm_chunk_size(points.len(), threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = points
+            .chunks(chunk_size)
+            .zip(scalars.chunks(chunk_size))
+            .map(|(points, scalars)| scope.spawn(move || P::multi_scalar_mul(points, scalars)))
+            .collect();
+        handles.into_iter().fold(P::zero(), |acc, handle| {
+            acc.add_point(&handle.join().expect("multi_scalar_mul thread panicked"))
+        })
+    })
+}
+
+/// Size of chunks `len` points are split into, so that there are at most `threads` chunks
+#[cfg(any(feature = "parallel", test))]
+fn msm_chunk_size(len: usize, threads: usize) -> usize {
+    len.div_ceil(threads.max(1)).max(1)
+}
+
 impl<E: Curve> PartialEq for Point<E> {
     fn eq(&self, other: &Self) -> bool {
         self.raw_point.eq(&other.raw_point)
@@ -272,6 +366,13 @@ impl<E: Curve> PartialEq for Point<E> {
 
 impl<E: Curve> Eq for Point<E> {}
 
+/// Constant time comparison (see [ECPoint::ct_eq])
+impl<E: Curve> ConstantTimeEq for Point<E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ECPoint::ct_eq(&self.raw_point, &other.raw_point)
+    }
+}
+
 impl<E: Curve> PartialEq<Generator<E>> for Point<E> {
     fn eq(&self, other: &Generator<E>) -> bool {
         self.as_raw().eq(other.as_raw())
@@ -291,6 +392,22 @@ impl<E: Curve> fmt::Debug for Point<E> {
     }
 }
 
+/// Formats a point as hex string of its compressed form
+impl<E: Curve> fmt::Display for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&hex::encode(&*self.to_bytes(true)))
+    }
+}
+
+/// Parses a point from hex string of its (un)compressed form, see [from_bytes](Point::from_bytes)
+impl<E: Curve> FromStr for Point<E> {
+    type Err = PointFromHexError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s).map_err(|_| PointFromHexError::InvalidHex)?;
+        Point::from_bytes(&bytes).map_err(PointFromHexError::InvalidEncoding)
+    }
+}
+
 impl<E: Curve> From<Generator<E>> for Point<E> {
     fn from(g: Generator<E>) -> Self {
         // Safety: curve generator order must be equal to group_order
@@ -309,3 +426,67 @@ impl<'p, E: Curve> iter::Sum<&'p Point<E>> for Point<E> {
         iter.fold(Point::zero(), |acc, p| acc + p)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(parallel_multi_scalar_mul_matches_serial);
+    fn parallel_multi_scalar_mul_matches_serial<E: Curve>() {
+        let points: Vec<E::Point> = (0..20)
+            .map(|_| E::Point::generator_mul(&E::Scalar::random()))
+            .collect();
+        let scalars: Vec<E::Scalar> = (0..20).map(|_| E::Scalar::random()).collect();
+
+        let serial = E::Point::multi_scalar_mul(&points, &scalars);
+        for threads in [0, 1, 3, 7, 20, 32] {
+            assert_eq!(
+                parallel_multi_scalar_mul(&points, &scalars, threads),
+                serial,
+                "threads = {}",
+                threads
+            );
+        }
+        assert!(parallel_multi_scalar_mul::<E::Point>(&[], &[], 4).is_zero());
+        for threads in [1, 3, 7, 20, 32] {
+            let chunks = points.chunks(msm_chunk_size(points.len(), threads)).count();
+            assert_eq!(chunks, threads.min(points.len()), "threads = {}", threads);
+        }
+
+        let points: Vec<Point<E>> = points
+            .into_iter()
+            .map(|p| Point::from_raw(p).unwrap())
+            .collect();
+        let scalars: Vec<Scalar<E>> = scalars.into_iter().map(Scalar::from_raw).collect();
+        assert_eq!(Point::multi_scalar_mul(&points, &scalars).as_raw(), &serial);
+    }
+
+    test_for_all_curves!(point_hex_round_trip);
+    fn point_hex_round_trip<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        assert_eq!(Point::<E>::from_str(&g.to_string()).unwrap(), g);
+        assert_eq!(g.to_string(), hex::encode(&*g.to_bytes(true)));
+
+        let p = Point::<E>::generator() * Scalar::random();
+        assert_eq!(Point::<E>::from_str(&p.to_string()).unwrap(), p);
+        let uncompressed = hex::encode(&*p.to_bytes(false));
+        assert_eq!(Point::<E>::from_str(&uncompressed).unwrap(), p);
+
+        let zero = Point::<E>::zero();
+        assert_eq!(Point::<E>::from_str(&zero.to_string()).unwrap(), zero);
+
+        assert!(matches!(
+            Point::<E>::from_str("not a hex"),
+            Err(PointFromHexError::InvalidHex)
+        ));
+        assert!(matches!(
+            Point::<E>::from_str(&p.to_string()[1..]),
+            Err(PointFromHexError::InvalidHex)
+        ));
+        assert!(matches!(
+            Point::<E>::from_str("0102"),
+            Err(PointFromHexError::InvalidEncoding(_))
+        ));
+    }
+}
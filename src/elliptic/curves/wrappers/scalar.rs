@@ -1,8 +1,11 @@
-use std::{fmt, iter};
+use std::{fmt, iter, str::FromStr};
+
+use rand::{CryptoRng, RngCore};
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::elliptic::curves::traits::{Curve, ECScalar};
 use crate::elliptic::curves::wrappers::encoded_scalar::EncodedScalar;
-use crate::elliptic::curves::{DeserializationError, ZeroScalarError};
+use crate::elliptic::curves::{DeserializationError, Point, ScalarFromHexError, ZeroScalarError};
 use crate::BigInt;
 
 /// Scalar value in a prime field
@@ -57,11 +60,28 @@ impl<E: Curve> Scalar<E> {
         }
     }
 
+    /// Samples a random nonzero scalar using given source of randomness
+    ///
+    /// Seeded `rng` makes sampling reproducible, which is useful for test vectors.
+    pub fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            let s = E::Scalar::random_with_rng(rng);
+            if !s.is_zero() {
+                break Scalar::from_raw(s);
+            }
+        }
+    }
+
     /// Constructs zero scalar
     pub fn zero() -> Self {
         Self::from_raw(E::Scalar::zero())
     }
 
+    /// Constructs one scalar
+    pub fn one() -> Self {
+        Self::from_raw(E::Scalar::one())
+    }
+
     /// Checks if a scalar is zero
     pub fn is_zero(&self) -> bool {
         self.as_raw().is_zero()
@@ -73,20 +93,60 @@ impl<E: Curve> Scalar<E> {
     }
 
     /// Constructs a scalar `n % curve_order` from given `n`
+    ///
+    /// Any integer is accepted and reduced, including negative integers and integers exceeding
+    /// group order. The same applies to `From<BigInt>` and `From<&BigInt>`.
     pub fn from_bigint(n: &BigInt) -> Self {
         Self::from_raw(E::Scalar::from_bigint(n))
     }
 
     /// Serializes a scalar to bytes
+    ///
+    /// Output has fixed size, see [ECScalar::serialize] for byte order used by every curve.
     pub fn to_bytes(&self) -> EncodedScalar<E> {
         EncodedScalar::from(self)
     }
 
     /// Constructs a scalar from bytes
+    ///
+    /// Returns error if bytes don't represent an integer in `[0; group_order)` range. See
+    /// [from_bytes_reduced](Self::from_bytes_reduced) if you need to accept any integer.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
         ECScalar::deserialize(bytes).map(Self::from_raw)
     }
 
+    /// Constructs a scalar `n % group_order` from bytes encoding integer `n`
+    ///
+    /// Bytes are expected to be in the same format as [to_bytes](Self::to_bytes) output, but
+    /// encoded integer is allowed to exceed group order.
+    pub fn from_bytes_reduced(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        ECScalar::deserialize_reduced(bytes).map(Self::from_raw)
+    }
+
+    /// Derives a scalar from a hash of `data` with domain separation tag `dst`
+    ///
+    /// Suitable for Fiat-Shamir challenges. See [ECScalar::from_hash] for details.
+    pub fn from_hash(data: &[&[u8]], dst: &[u8]) -> Self {
+        Self::from_raw(E::Scalar::from_hash(data, dst))
+    }
+
+    /// Computes public key `x·G` corresponding to secret `x = self`
+    ///
+    /// Same as `Point::generator() * self`: multiplication is done via
+    /// [ECPoint::generator_mul](crate::elliptic::curves::ECPoint::generator_mul), which is
+    /// faster than multiplication of arbitrary point when curve library provides
+    /// fixed-base precomputed tables.
+    ///
+    /// ```rust
+    /// # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+    /// let sk = Scalar::<Secp256k1>::random();
+    /// let pk = sk.to_public();
+    /// assert_eq!(pk, Point::generator() * &sk);
+    /// ```
+    pub fn to_public(&self) -> Point<E> {
+        Point::generator() * self
+    }
+
     /// Returns an order of generator point
     pub fn group_order() -> &'static BigInt {
         E::Scalar::group_order()
@@ -97,6 +157,30 @@ impl<E: Curve> Scalar<E> {
         self.as_raw().invert().map(Self::from_raw)
     }
 
+    /// Inverts every scalar in the slice in place using a single field inversion
+    ///
+    /// Zero scalars are left untouched. Returns a mask where `mask[i]` is `true` if `scalars[i]`
+    /// is zero. See [ECScalar::batch_invert] for details.
+    pub fn batch_invert(scalars: &mut [Self]) -> Vec<bool> {
+        let mut raw: Vec<E::Scalar> = scalars.iter().map(|s| s.as_raw().clone()).collect();
+        let zeros = E::Scalar::batch_invert(&mut raw);
+        for (s, raw) in scalars.iter_mut().zip(raw) {
+            *s = Self::from_raw(raw);
+        }
+        zeros
+    }
+
+    /// Returns `a` if `choice == 0`, or `b` if `choice == 1`, in constant time
+    ///
+    /// See [ECScalar::conditional_select].
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::from_raw(E::Scalar::conditional_select(
+            a.as_raw(),
+            b.as_raw(),
+            choice,
+        ))
+    }
+
     /// Constructs a `Scalar<E>` from low-level [ECScalar] implementor
     ///
     /// Typically, you don't need to use this constructor. See [random](Self::random),
@@ -141,6 +225,24 @@ impl<E: Curve> fmt::Debug for Scalar<E> {
     }
 }
 
+/// Formats a scalar as hex string of its [bytes representation](Scalar::to_bytes)
+///
+/// Be careful not to print or log secret scalars.
+impl<E: Curve> fmt::Display for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&hex::encode(&*self.to_bytes()))
+    }
+}
+
+/// Parses a scalar from hex string, see [from_bytes](Scalar::from_bytes)
+impl<E: Curve> FromStr for Scalar<E> {
+    type Err = ScalarFromHexError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s).map_err(|_| ScalarFromHexError::InvalidHex)?;
+        Scalar::from_bytes(&bytes).map_err(ScalarFromHexError::InvalidEncoding)
+    }
+}
+
 impl<E: Curve> PartialEq for Scalar<E> {
     fn eq(&self, other: &Self) -> bool {
         self.as_raw().eq(other.as_raw())
@@ -149,21 +251,29 @@ impl<E: Curve> PartialEq for Scalar<E> {
 
 impl<E: Curve> Eq for Scalar<E> {}
 
+/// Constant time comparison, use it instead of `==` if any of scalars is secret
+/// (see [ECScalar::ct_eq])
+impl<E: Curve> ConstantTimeEq for Scalar<E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ECScalar::ct_eq(self.as_raw(), other.as_raw())
+    }
+}
+
 impl<E: Curve> From<u16> for Scalar<E> {
     fn from(n: u16) -> Self {
-        Self::from(&BigInt::from(n))
+        Self::from_raw(E::Scalar::from_u64(u64::from(n)))
     }
 }
 
 impl<E: Curve> From<u32> for Scalar<E> {
     fn from(n: u32) -> Self {
-        Self::from(&BigInt::from(n))
+        Self::from_raw(E::Scalar::from_u64(u64::from(n)))
     }
 }
 
 impl<E: Curve> From<u64> for Scalar<E> {
     fn from(n: u64) -> Self {
-        Self::from(&BigInt::from(n))
+        Self::from_raw(E::Scalar::from_u64(n))
     }
 }
 
@@ -199,12 +309,48 @@ impl<'s, E: Curve> iter::Sum<&'s Scalar<E>> for Scalar<E> {
 
 impl<E: Curve> iter::Product for Scalar<E> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Scalar::from(1), |acc, s| acc * s)
+        iter.fold(Scalar::one(), |acc, s| acc * s)
     }
 }
 
 impl<'s, E: Curve> iter::Product<&'s Scalar<E>> for Scalar<E> {
     fn product<I: Iterator<Item = &'s Scalar<E>>>(iter: I) -> Self {
-        iter.fold(Scalar::from(1), |acc, s| acc * s)
+        iter.fold(Scalar::one(), |acc, s| acc * s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elliptic::curves::ECPoint;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(to_public_matches_generator_multiplication);
+    fn to_public_matches_generator_multiplication<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = sk.to_public();
+        assert_eq!(pk, Point::generator() * &sk);
+        assert_eq!(pk.as_raw(), &E::Point::generator().scalar_mul(sk.as_raw()));
+        assert!(Scalar::<E>::zero().to_public().is_zero());
+    }
+
+    test_for_all_curves!(scalar_hex_round_trip);
+    fn scalar_hex_round_trip<E: Curve>() {
+        let s = Scalar::<E>::random();
+        assert_eq!(Scalar::<E>::from_str(&s.to_string()).unwrap(), s);
+        assert_eq!(s.to_string(), hex::encode(&*s.to_bytes()));
+        let zero = Scalar::<E>::zero();
+        assert_eq!(Scalar::<E>::from_str(&zero.to_string()).unwrap(), zero);
+
+        assert!(matches!(
+            Scalar::<E>::from_str("not a hex"),
+            Err(ScalarFromHexError::InvalidHex)
+        ));
+        // All-ones string exceeds group order regardless of byte order
+        let out_of_range = "ff".repeat(zero.to_bytes().len());
+        assert!(matches!(
+            Scalar::<E>::from_str(&out_of_range),
+            Err(ScalarFromHexError::InvalidEncoding(_))
+        ));
     }
 }
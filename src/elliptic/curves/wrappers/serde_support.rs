@@ -659,4 +659,34 @@ mod serde_tests {
         let deserialized_point: Point<E> = serde_json::from_str(&point_json).unwrap();
         assert_eq!(random_point, deserialized_point);
     }
+
+    test_for_all_curves!(message_with_points_roundtrips_through_serde_json);
+    fn message_with_points_roundtrips_through_serde_json<E: Curve>() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        #[serde(bound = "")]
+        struct Message<E: Curve> {
+            commitments: Vec<Point<E>>,
+            response: Scalar<E>,
+        }
+
+        let msg = Message::<E> {
+            commitments: vec![
+                Point::generator() * Scalar::random(),
+                Point::zero(),
+                Point::generator().to_point(),
+            ],
+            response: Scalar::random(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        let deserialized: Message<E> = serde_json::from_str(&json).unwrap();
+        assert_eq!(msg, deserialized);
+
+        // Points are encoded as hex of their compressed form
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value["commitments"][0]["point"],
+            hex::encode(&*msg.commitments[0].to_bytes(true))
+        );
+        assert_eq!(value["response"]["curve"], E::CURVE_NAME);
+    }
 }
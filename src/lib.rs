@@ -21,6 +21,20 @@ pub enum ErrorKey {
     InvalidPublicKey,
 }
 
+#[derive(Copy, PartialEq, Eq, Clone, Debug)]
 pub enum ErrorSS {
     VerifyShareError,
+    DuplicateIndex,
+    /// Share at given (0-based) index didn't pass [validation](cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS::validate_share)
+    InvalidShare {
+        index: u16,
+    },
+    /// Number of indices doesn't match number of shares
+    MismatchedLengths,
+    /// Not enough shares to reconstruct the secret, at least `t+1` are required
+    BelowThreshold,
+    /// Schemes being combined have different parameters
+    MismatchedParameters,
+    /// Scheme parameters are malformed, see [validate_parameters](cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS::validate_parameters)
+    InvalidParameters,
 }
@@ -41,8 +41,8 @@ impl<H: Digest + Clone> PartialEq for HashChoice<H> {
 impl<H: Digest + Clone> Eq for HashChoice<H> {}
 
 impl<H: Digest + Clone> PartialOrd for HashChoice<H> {
-    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
-        Some(Ordering::Equal)
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
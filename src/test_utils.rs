@@ -2,19 +2,19 @@
 #[macro_export]
 macro_rules! test_for_all_curves {
     (#[should_panic] $fn: ident) => {
-        crate::test_for_all_curves!([#[should_panic]] $fn);
+        $crate::test_for_all_curves!([#[should_panic]] $fn);
     };
     ($fn: ident) => {
-        crate::test_for_all_curves!([] $fn);
+        $crate::test_for_all_curves!([] $fn);
     };
     ([$($attrs:tt)*] $fn: ident) => {
-        crate::test_for_all!{[$($attrs)*] $fn =>
-            secp256k1 = crate::elliptic::curves::Secp256k1,
-            p256 = crate::elliptic::curves::Secp256r1,
-            ed25519 = crate::elliptic::curves::Ed25519,
-            ristretto = crate::elliptic::curves::Ristretto,
-            bls12_381_1 = crate::elliptic::curves::Bls12_381_1,
-            bls12_381_2 = crate::elliptic::curves::Bls12_381_2,
+        $crate::test_for_all!{[$($attrs)*] $fn =>
+            secp256k1 = $crate::elliptic::curves::Secp256k1,
+            p256 = $crate::elliptic::curves::Secp256r1,
+            ed25519 = $crate::elliptic::curves::Ed25519,
+            ristretto = $crate::elliptic::curves::Ristretto,
+            bls12_381_1 = $crate::elliptic::curves::Bls12_381_1,
+            bls12_381_2 = $crate::elliptic::curves::Bls12_381_2,
         }
     };
 }
@@ -23,13 +23,13 @@ macro_rules! test_for_all_curves {
 #[macro_export]
 macro_rules! test_for_all_hashes {
     (#[should_panic] $fn: ident) => {
-        crate::test_for_all_hashes!([#[should_panic]] $fn);
+        $crate::test_for_all_hashes!([#[should_panic]] $fn);
     };
     ($fn: ident) => {
-        crate::test_for_all_hashes!([] $fn);
+        $crate::test_for_all_hashes!([] $fn);
     };
     ([$($attrs:tt)*] $fn: ident) => {
-        crate::test_for_all!{[$($attrs)*] $fn =>
+        $crate::test_for_all!{[$($attrs)*] $fn =>
             sha256 = sha2::Sha256,
             sha512 = sha2::Sha512,
             sha3_256 = sha3::Sha3_256,
@@ -52,7 +52,7 @@ macro_rules! test_for_all {
                 $fn::<$inst>()
             }
         }
-        crate::test_for_all!([$($attrs)*] $fn => $($rest)*);
+        $crate::test_for_all!([$($attrs)*] $fn => $($rest)*);
     };
 }
 
@@ -60,28 +60,28 @@ macro_rules! test_for_all {
 #[macro_export]
 macro_rules! test_for_all_curves_and_hashes {
     (#[should_panic] $fn: ident) => {
-        crate::test_for_all_curves_and_hashes!([#[should_panic]] $fn);
+        $crate::test_for_all_curves_and_hashes!([#[should_panic]] $fn);
     };
     ($fn: ident) => {
-        crate::test_for_all_curves_and_hashes!([] $fn);
+        $crate::test_for_all_curves_and_hashes!([] $fn);
     };
     ([$($attrs:tt)*] $fn: ident) => {
-        crate::test_for_all_curves_and_hashes!{compose: [$($attrs)*] $fn =>
-            secp256k1 = crate::elliptic::curves::Secp256k1,
-            p256 = crate::elliptic::curves::Secp256r1,
-            ed25519 = crate::elliptic::curves::Ed25519,
-            ristretto = crate::elliptic::curves::Ristretto,
-            bls12_381_1 = crate::elliptic::curves::Bls12_381_1,
-            bls12_381_2 = crate::elliptic::curves::Bls12_381_2,
+        $crate::test_for_all_curves_and_hashes!{compose: [$($attrs)*] $fn =>
+            secp256k1 = $crate::elliptic::curves::Secp256k1,
+            p256 = $crate::elliptic::curves::Secp256r1,
+            ed25519 = $crate::elliptic::curves::Ed25519,
+            ristretto = $crate::elliptic::curves::Ristretto,
+            bls12_381_1 = $crate::elliptic::curves::Bls12_381_1,
+            bls12_381_2 = $crate::elliptic::curves::Bls12_381_2,
         }
     };
     (compose: [$($attrs:tt)*] $fn: ident =>) => {};
     (compose: [$($attrs:tt)*] $fn: ident => $inst_name: ident = $inst:path, $($rest: tt)*) => {
-        crate::test_for_all_curves_and_hashes!{private: [$($attrs)*] $fn =>
+        $crate::test_for_all_curves_and_hashes!{private: [$($attrs)*] $fn =>
             $inst_name = $inst | sha256 = sha2::Sha256,
             $inst_name = $inst | sha512 = sha2::Sha512,
         }
-        crate::test_for_all_curves_and_hashes!(compose: [$($attrs)*] $fn => $($rest)*);
+        $crate::test_for_all_curves_and_hashes!(compose: [$($attrs)*] $fn => $($rest)*);
     };
     (private: [$($attrs:tt)*] $fn: ident =>) => {};
     (private: [$($attrs:tt)*] $fn:ident => $inst_name1:ident = $inst1: path | $inst_name2:ident = $inst2:path, $($rest: tt)*) => {
@@ -92,6 +92,6 @@ macro_rules! test_for_all_curves_and_hashes {
                 $fn::<$inst1, $inst2>()
             }
         }
-        crate::test_for_all_curves_and_hashes!(private: [$($attrs)*] $fn => $($rest)*);
+        $crate::test_for_all_curves_and_hashes!(private: [$($attrs)*] $fn => $($rest)*);
     };
 }
@@ -0,0 +1,127 @@
+//! Test vectors of Feldman VSS
+//!
+//! Vectors in `tests/vectors/` pin polynomial sampling, share evaluation and encoding of
+//! commitments and shares: for every `(seed, t, n, secret)` sharing via
+//! [VerifiableSS::share_with_rng] seeded with `ChaCha20Rng::seed_from_u64(seed)` must produce
+//! exactly the same commitments (compressed points) and shares (scalars in curve's native byte
+//! order).
+//!
+//! Vectors must never be changed unless it's an intended breaking change. To regenerate them:
+//! cargo test --test vss_vectors -- --ignored
+
+use std::fs;
+use std::path::PathBuf;
+
+use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use curv::elliptic::curves::{Curve, Point, Ristretto, Scalar, Secp256k1};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+#[derive(Serialize, Deserialize)]
+struct Vectors {
+    curve: String,
+    vectors: Vec<Vector>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Vector {
+    seed: u64,
+    t: u16,
+    n: u16,
+    secret: String,
+    commitments: Vec<String>,
+    shares: Vec<String>,
+}
+
+/// Parameters `(seed, t, n)` of generated vectors
+const PARAMS: &[(u64, u16, u16)] = &[(0, 1, 3), (1, 2, 5), (2, 4, 7), (3, 0, 1)];
+
+fn vectors_path<E: Curve>() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/vectors")
+        .join(format!("vss_{}.json", E::CURVE_NAME))
+}
+
+fn share<E: Curve>(seed: u64, t: u16, n: u16, secret: &Scalar<E>) -> Vector {
+    let mut rng = ChaCha20Rng::seed_from_u64(seed);
+    let (vss, shares) = VerifiableSS::<E, Sha256>::share_with_rng(t, n, secret, &mut rng);
+    Vector {
+        seed,
+        t,
+        n,
+        secret: hex::encode(&*secret.to_bytes()),
+        commitments: vss
+            .commitments
+            .iter()
+            .map(|c| hex::encode(c.to_bytes(true)))
+            .collect(),
+        shares: shares.iter().map(|s| hex::encode(&*s.to_bytes())).collect(),
+    }
+}
+
+fn check_vectors<E: Curve>() {
+    let path = vectors_path::<E>();
+    let json = fs::read_to_string(&path).unwrap_or_else(|e| panic!("read {:?}: {}", path, e));
+    let vectors: Vectors = serde_json::from_str(&json).unwrap();
+    assert_eq!(vectors.curve, E::CURVE_NAME);
+    assert!(!vectors.vectors.is_empty());
+
+    for vector in &vectors.vectors {
+        let secret = Scalar::<E>::from_bytes(&hex::decode(&vector.secret).unwrap()).unwrap();
+        let actual = share(vector.seed, vector.t, vector.n, &secret);
+        assert_eq!(
+            actual.commitments, vector.commitments,
+            "seed {}",
+            vector.seed
+        );
+        assert_eq!(actual.shares, vector.shares, "seed {}", vector.seed);
+
+        // Encoded values are decodable and consistent with each other
+        let vss = VerifiableSS::<E, Sha256>::share(vector.t, vector.n, &secret).0;
+        let commitments = vector
+            .commitments
+            .iter()
+            .map(|c| Point::<E>::from_bytes(&hex::decode(c).unwrap()).unwrap())
+            .collect();
+        let vss = VerifiableSS { commitments, ..vss };
+        for (i, s) in (1..).zip(&vector.shares) {
+            let share = Scalar::<E>::from_bytes(&hex::decode(s).unwrap()).unwrap();
+            assert!(vss.validate_share(&share, i).is_ok());
+        }
+    }
+}
+
+fn generate_vectors<E: Curve>() {
+    let vectors = Vectors {
+        curve: E::CURVE_NAME.to_owned(),
+        vectors: PARAMS
+            .iter()
+            .map(|&(seed, t, n)| {
+                // Secret is sampled from a different stream than the polynomial
+                let mut rng = ChaCha20Rng::seed_from_u64(u64::MAX - seed);
+                share::<E>(seed, t, n, &Scalar::random_with_rng(&mut rng))
+            })
+            .collect(),
+    };
+    let json = serde_json::to_string_pretty(&vectors).unwrap();
+    fs::write(vectors_path::<E>(), json + "\n").unwrap();
+}
+
+#[test]
+fn secp256k1_vectors() {
+    check_vectors::<Secp256k1>()
+}
+
+#[test]
+fn ristretto_vectors() {
+    check_vectors::<Ristretto>()
+}
+
+#[test]
+#[ignore]
+fn regenerate_vectors() {
+    generate_vectors::<Secp256k1>();
+    generate_vectors::<Ristretto>();
+}